@@ -6,15 +6,18 @@ use std::borrow::Cow;
 
 use mangadex_api_types::error::schema::MangaDexErrorResponse;
 use mangadex_api_types::error::Error;
-use mangadex_api_types::{RelationshipType, ResponseType, ResultType};
+use mangadex_api_types::{ContentRating, RelationshipType, ResponseType, ResultType};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
-use crate::v5::Relationship;
+use crate::v5::Relationships;
 
 pub trait UrlSerdeQS {
     fn query_qs<T: Serialize>(self, query: &T) -> Self;
+
+    /// Append the serialized query to the existing query string instead of replacing it.
+    fn append_query_qs<T: Serialize>(self, query: &T) -> Self;
 }
 
 impl UrlSerdeQS for url::Url {
@@ -24,12 +27,27 @@ impl UrlSerdeQS for url::Url {
         ));
         self
     }
+
+    fn append_query_qs<T: Serialize>(mut self, query: &T) -> Self {
+        let appended = serde_qs::to_string(query).expect("failed to encode query string");
+        let query = match self.query() {
+            Some(existing) if !existing.is_empty() => format!("{existing}&{appended}"),
+            _ => appended,
+        };
+        self.set_query(Some(&query));
+        self
+    }
 }
 
 pub trait FromResponse: Sized {
     type Response;
 
     fn from_response(res: Self::Response) -> Self;
+
+    /// Record the HTTP status code of the response in the API errors, if there are any.
+    fn with_status_code(self, _status_code: u16) -> Self {
+        self
+    }
 }
 
 pub trait Endpoint {
@@ -58,6 +76,28 @@ pub trait Endpoint {
     fn multipart(&self) -> Option<reqwest::multipart::Form> {
         None
     }
+
+    /// Get the content rating filter of the request.
+    ///
+    /// `None` means the endpoint doesn't filter by content rating, while an empty slice means
+    /// no filter was set, so the client's default content rating can be applied.
+    fn content_rating(&self) -> Option<&[ContentRating]> {
+        None
+    }
+
+    /// Get the key sent in the `Idempotency-Key` header, if any.
+    ///
+    /// The key is stored in the request, so re-sending the same request reuses it.
+    fn idempotency_key(&self) -> Option<Uuid> {
+        None
+    }
+
+    /// Get the type and ID of the single resource requested, if any.
+    ///
+    /// A `404 Not Found` response to such a request is returned as `Error::NotFound`.
+    fn requested_resource(&self) -> Option<(&'static str, Option<Uuid>)> {
+        None
+    }
 }
 
 #[derive(Deserialize)]
@@ -86,6 +126,8 @@ impl<T, E> ApiResult<T, E> {
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct ApiData<T> {
+    /// Defaults to `ok` when the `result` key was already read as the tag of [`ApiResult`].
+    #[serde(default)]
     pub result : ResultType,
     pub response: ResponseType,
     pub data: T,
@@ -99,7 +141,8 @@ pub struct ApiObject<A, T = RelationshipType> {
     pub id: Uuid,
     pub type_: T,
     pub attributes: A,
-    pub relationships: Vec<Relationship>,
+    #[serde(default)]
+    pub relationships: Relationships,
 }
 
 impl<A, T> FromResponse for ApiObject<A, T> {
@@ -149,6 +192,7 @@ impl<A, T> FromResponse for ApiObjectNoRelationships<A, T> {
 /// }
 #[derive(Debug, Default, Deserialize, Clone, Hash, PartialEq, Eq)]
 pub struct NoData{
+    #[serde(default)]
     result : ResultType
 }
 
@@ -158,6 +202,16 @@ impl<T> FromResponse for Result<T, Error> {
     fn from_response(value: Self::Response) -> Self {
         value.into_result().map_err(|e| e.into())
     }
+
+    fn with_status_code(self, status_code: u16) -> Self {
+        self.map_err(|e| match e {
+            Error::Api(mut e) => {
+                e.status_code = Some(status_code);
+                Error::Api(e)
+            }
+            e => e,
+        })
+    }
 }
 
 impl<T> FromResponse for Vec<Result<T, Error>> {
@@ -169,6 +223,12 @@ impl<T> FromResponse for Vec<Result<T, Error>> {
             .map(|r| r.into_result().map_err(|e| e.into()))
             .collect()
     }
+
+    fn with_status_code(self, status_code: u16) -> Self {
+        self.into_iter()
+            .map(|r| r.with_status_code(status_code))
+            .collect()
+    }
 }
 
 pub(crate) fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -17,7 +17,9 @@ pub mod manga_read_markers;
 pub mod manga_reading_status;
 pub mod manga_reading_statuses;
 pub mod manga_relation;
+pub mod pagination;
 pub mod ratings;
+pub mod reading_history;
 pub mod refresh_token_response;
 pub mod report;
 pub mod scanlation_group;
@@ -34,7 +36,7 @@ pub use self::exports_types::*;
 use std::collections::HashMap;
 
 use mangadex_api_types as types;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use types::{Language, MangaRelation, RelationshipType, ResponseType, ResultType};
@@ -92,10 +94,112 @@ pub struct Relationship {
     pub attributes: Option<RelatedAttributes>,
 }
 
+/// The `relationships` of an entity.
+///
+/// This dereferences to the underlying `Vec` so it can be read and modified like a regular list,
+/// and adds helpers to get the relationships of a given type.
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(transparent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct Relationships(Vec<Relationship>);
+
+impl Relationships {
+    /// Get the relationships of the given type, in the order MangaDex returned them.
+    pub fn of_type(&self, type_: RelationshipType) -> impl Iterator<Item = &Relationship> {
+        self.0
+            .iter()
+            .filter(move |relationship| relationship.type_ == type_)
+    }
+
+    /// Get the first relationship of the given type.
+    pub fn first_of_type(&self, type_: RelationshipType) -> Option<&Relationship> {
+        self.of_type(type_).next()
+    }
+
+    /// Get the cover art relationship.
+    pub fn cover_art(&self) -> Option<&Relationship> {
+        self.first_of_type(RelationshipType::CoverArt)
+    }
+
+    /// Get the first author relationship.
+    ///
+    /// Use [`Self::of_type()`] to get every author.
+    pub fn author(&self) -> Option<&Relationship> {
+        self.first_of_type(RelationshipType::Author)
+    }
+
+    /// Get the first artist relationship.
+    ///
+    /// Use [`Self::of_type()`] to get every artist.
+    pub fn artist(&self) -> Option<&Relationship> {
+        self.first_of_type(RelationshipType::Artist)
+    }
+
+    /// Get the first scanlation group relationship.
+    ///
+    /// Use [`Self::of_type()`] to get every scanlation group.
+    pub fn scanlation_group(&self) -> Option<&Relationship> {
+        self.first_of_type(RelationshipType::ScanlationGroup)
+    }
+
+    /// Get the first manga relationship.
+    ///
+    /// On a chapter, this is the manga the chapter belongs to.
+    pub fn manga(&self) -> Option<&Relationship> {
+        self.first_of_type(RelationshipType::Manga)
+    }
+
+    /// Get the underlying list of relationships.
+    pub fn into_inner(self) -> Vec<Relationship> {
+        self.0
+    }
+}
+
+impl From<Vec<Relationship>> for Relationships {
+    fn from(relationships: Vec<Relationship>) -> Self {
+        Self(relationships)
+    }
+}
+
+impl std::ops::Deref for Relationships {
+    type Target = Vec<Relationship>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Relationships {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl IntoIterator for Relationships {
+    type Item = Relationship;
+    type IntoIter = std::vec::IntoIter<Relationship>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Relationships {
+    type Item = &'a Relationship;
+    type IntoIter = std::slice::Iter<'a, Relationship>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct Results<T> {
+    /// Defaults to `ok` when the `result` key was already read as the tag of [`ApiResult`](crate::ApiResult).
+    #[serde(default)]
     pub result: ResultType,
     pub response: ResponseType,
     pub data: Vec<T>,
@@ -104,7 +208,71 @@ pub struct Results<T> {
     pub total: u32,
 }
 
-pub type LocalizedString = HashMap<Language, String>;
+/// Strings localized in multiple languages, such as a manga title.
+///
+/// This dereferences to the underlying `HashMap` so it can be read like a regular map.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct LocalizedString(HashMap<Language, String>);
+
+impl LocalizedString {
+    /// Get the string for the first available language in the preference list.
+    ///
+    /// If none of the preferred languages are available, English is tried, then any other
+    /// language. `None` is only returned when there are no localized strings at all.
+    pub fn get_or_fallback(&self, preferred: &[Language]) -> Option<&str> {
+        preferred
+            .iter()
+            .chain(std::iter::once(&Language::English))
+            .find_map(|language| self.0.get(language))
+            .or_else(|| {
+                // Pick the smallest language code so the fallback is deterministic.
+                self.0
+                    .iter()
+                    .min_by_key(|(language, _)| language.code2().to_string())
+                    .map(|(_, value)| value)
+            })
+            .map(String::as_str)
+    }
+
+    /// Get the underlying map of the localized strings.
+    pub fn into_inner(self) -> HashMap<Language, String> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for LocalizedString {
+    type Target = HashMap<Language, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for LocalizedString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<HashMap<Language, String>> for LocalizedString {
+    fn from(value: HashMap<Language, String>) -> Self {
+        Self(value)
+    }
+}
+
+impl FromIterator<(Language, String)> for LocalizedString {
+    fn from_iter<I: IntoIterator<Item = (Language, String)>>(iter: I) -> Self {
+        Self(HashMap::from_iter(iter))
+    }
+}
+
+impl Extend<(Language, String)> for LocalizedString {
+    fn extend<I: IntoIterator<Item = (Language, String)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
 
 /// Originally a Deserializer helper to handle JSON array or object types.
 ///
@@ -112,8 +280,6 @@ pub type LocalizedString = HashMap<Language, String>;
 /// 
 /// The Serializer was added in 0.2.0 for pratical and necessities reason
 pub(crate) mod localizedstring_array_or_map {
-    use std::collections::HashMap;
-
     use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
     #[cfg(feature = "serialize")]
     use serde::ser::{Serialize, Serializer};
@@ -136,7 +302,7 @@ pub(crate) mod localizedstring_array_or_map {
             where
                 A: SeqAccess<'de>,
             {
-                Ok(HashMap::new())
+                Ok(LocalizedString::default())
             }
 
             fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
@@ -1,19 +1,90 @@
-use mangadex_api_types::ResultType;
+use std::time::Duration;
+
+use mangadex_api_types::{Clock, MangaDexDateTime, Quality, ResultType, SystemClock};
 use serde::Deserialize;
 use url::Url;
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+/// Duration a MangaDex@Home server is valid for after it was fetched.
+pub const AT_HOME_SERVER_TTL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct AtHomeServer {
-    pub result : ResultType,
+    /// Defaults to `ok` when the `result` key was already read as the tag of
+    /// [`ApiResult`](crate::ApiResult).
+    #[serde(default)]
+    pub result: ResultType,
     /// The base URL to construct final image URLs from.
     /// The URL returned is valid for the requested chapter only, and for a duration of 15 minutes
     /// from the time of the response.
     pub base_url: Url,
     pub chapter: ChapterData,
+    /// When the response was received.
+    #[serde(skip, default = "fetched_now")]
+    #[cfg_attr(feature = "specta", specta(skip))]
+    pub fetched_at: MangaDexDateTime,
+}
+
+fn fetched_now() -> MangaDexDateTime {
+    SystemClock.now()
+}
+
+/// `fetched_at` is ignored, so the same response received at different times is equal.
+impl PartialEq for AtHomeServer {
+    fn eq(&self, other: &Self) -> bool {
+        self.result == other.result
+            && self.base_url == other.base_url
+            && self.chapter == other.chapter
+    }
+}
+
+impl AtHomeServer {
+    /// Get the time after which the base URL is no longer valid.
+    pub fn expires_at(&self) -> MangaDexDateTime {
+        MangaDexDateTime::new(&(*self.fetched_at.as_ref() + AT_HOME_SERVER_TTL))
+    }
+
+    /// Check if the base URL is no longer valid.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_with_clock(SystemClock)
+    }
+
+    /// Check if the base URL is no longer valid at the time of `clock`.
+    pub fn is_expired_with_clock(&self, clock: impl Clock) -> bool {
+        self.expires_within_with_clock(Duration::ZERO, clock)
+    }
+
+    /// Check if the base URL is no longer valid or will expire within the given duration.
+    pub fn expires_within(&self, duration: Duration) -> bool {
+        self.expires_within_with_clock(duration, SystemClock)
+    }
+
+    /// Check if the base URL is no longer valid or will expire within the given duration of the
+    /// time of `clock`.
+    pub fn expires_within_with_clock(&self, duration: Duration, clock: impl Clock) -> bool {
+        *self.expires_at().as_ref() <= *clock.now().as_ref() + duration
+    }
+
+    /// Get the URL of the page at the given 0-based index, in reading order.
+    ///
+    /// `None` is returned if the chapter has no such page in the requested quality.
+    pub fn page_url(&self, index: usize, quality: Quality) -> Option<Url> {
+        let filename = self.chapter.filenames(quality).get(index)?;
+
+        self.base_url
+            .join(&format!("/{}/{}/{}", quality, self.chapter.hash, filename))
+            .ok()
+    }
+
+    /// Get the URLs of all the pages, in reading order.
+    pub fn page_urls(&self, quality: Quality) -> Vec<Url> {
+        (0..self.chapter.filenames(quality).len())
+            .filter_map(|index| self.page_url(index, quality))
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -28,3 +99,13 @@ pub struct ChapterData {
     /// Compressed quality filenames.
     pub data_saver: Vec<String>,
 }
+
+impl ChapterData {
+    /// Get the page filenames for the given quality.
+    pub fn filenames(&self, quality: Quality) -> &[String] {
+        match quality {
+            Quality::Data => &self.data,
+            Quality::DataSaver => &self.data_saver,
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use mangadex_api_types::{Clock, MangaDexDateTime};
 use serde::Deserialize;
+use time::OffsetDateTime;
 
 /// JWT that must be included with requests that require Bearer authentication.
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -10,3 +14,39 @@ pub struct AuthTokens {
     /// Token that lives for 1 month; allows for refreshing without re-authenticating.
     pub refresh: String,
 }
+
+impl AuthTokens {
+    /// Get the time the session token expires at, from the `exp` claim of the JWT.
+    ///
+    /// The token is decoded without verifying its signature. `None` is returned if the token
+    /// isn't a JWT with an `exp` claim.
+    pub fn session_expiry(&self) -> Option<MangaDexDateTime> {
+        jwt_expiry(&self.session)
+    }
+
+    /// Check if the session token is expired at the current time of the clock.
+    ///
+    /// A session token without a readable expiry is considered expired, so that it is refreshed
+    /// rather than sent.
+    pub fn is_session_expired(&self, clock: impl Clock) -> bool {
+        match self.session_expiry() {
+            Some(expiry) => expiry.as_ref() <= clock.now().as_ref(),
+            None => true,
+        }
+    }
+}
+
+/// Read the `exp` claim of a JWT without verifying it.
+fn jwt_expiry(token: &str) -> Option<MangaDexDateTime> {
+    #[derive(Deserialize)]
+    struct Claims {
+        exp: i64,
+    }
+
+    let payload = token.split('.').nth(1)?;
+    let payload = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    let claims: Claims = serde_json::from_slice(&payload).ok()?;
+    let expiry = OffsetDateTime::from_unix_timestamp(claims.exp).ok()?;
+
+    Some(MangaDexDateTime::new(&expiry))
+}
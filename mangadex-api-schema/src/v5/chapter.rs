@@ -3,7 +3,8 @@ use url::Url;
 use uuid::Uuid;
 
 use crate::deserialize_null_default;
-use mangadex_api_types::{Language, MangaDexDateTime};
+use crate::{ApiData, ApiObject};
+use mangadex_api_types::{Clock, Language, MangaDexDateTime};
 
 /// General chapter information.
 /// More details at https://api.mangadex.org/docs/swagger.html#model-ChapterAttributes
@@ -40,3 +41,37 @@ pub struct ChapterAttributes {
     #[cfg_attr(feature = "specta", specta(type = String))]
     pub readable_at: MangaDexDateTime,
 }
+
+impl ApiObject<ChapterAttributes> {
+    /// Check if the chapter is published at the current time of the clock.
+    ///
+    /// Delayed releases have a `publishAt` in the future and are not readable until then.
+    /// Pass a [`SystemClock`](mangadex_api_types::SystemClock) to check against the system time,
+    /// or a `MangaDexDateTime` to check against a given instant.
+    pub fn is_published(&self, clock: impl Clock) -> bool {
+        self.attributes.publish_at.as_ref() <= clock.now().as_ref()
+    }
+
+    /// Check if the chapter is hosted on an external site.
+    ///
+    /// External chapters have no pages on MangaDex@Home and must be read at the `externalUrl`.
+    pub fn is_external(&self) -> bool {
+        self.attributes.external_url.is_some()
+    }
+}
+
+impl ApiData<ApiObject<ChapterAttributes>> {
+    /// Check if the chapter is published at the current time of the clock.
+    ///
+    /// See [`ApiObject::is_published()`](crate::ApiObject#method.is_published).
+    pub fn is_published(&self, clock: impl Clock) -> bool {
+        self.data.is_published(clock)
+    }
+
+    /// Check if the chapter is hosted on an external site.
+    ///
+    /// See [`ApiObject::is_external()`](crate::ApiObject#method.is_external).
+    pub fn is_external(&self) -> bool {
+        self.data.is_external()
+    }
+}
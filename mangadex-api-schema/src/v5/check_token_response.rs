@@ -6,8 +6,11 @@ use serde::Deserialize;
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct CheckTokenResponse {
+    #[serde(default)]
     pub result : ResultType,
     pub is_authenticated: bool,
+    #[serde(default)]
     pub roles: Vec<UserRole>, 
+    #[serde(default)]
     pub permissions: Vec<String>, // TODO: Deserialize the strings into `UserPermission` enum.
 }
@@ -1,5 +1,5 @@
 use crate::{ApiData, ApiObject, ApiObjectNoRelationships};
-pub use super::at_home_server::AtHomeServer;
+pub use super::at_home_server::{AtHomeServer, AT_HOME_SERVER_TTL};
 pub use super::auth_tokens::AuthTokens;
 pub use super::author::AuthorAttributes;
 pub use super::chapter::ChapterAttributes;
@@ -15,9 +15,11 @@ pub use super::manga_aggregate::MangaAggregate;
 pub use super::manga_links::MangaLinks;
 pub use super::manga_read_markers::{MangaReadMarkers, UngroupedMangaReadMarkers};
 pub use super::manga_reading_status::MangaReadingStatus;
-pub use super::manga_reading_statuses::MangaReadingStatuses;
+pub use super::manga_reading_statuses::{MangaReadingStatuses, ReadingStatusDiff};
 pub use super::manga_relation::MangaRelationAttributes;
+pub use super::pagination::{CollectionResponse, Pagination};
 pub use super::ratings::RatingsList;
+pub use super::reading_history::{ReadingHistory, ReadingHistoryEntry};
 pub use super::refresh_token_response::RefreshTokenResponse;
 pub use super::report::ReportReasonAttributes;
 pub use super::scanlation_group::ScanlationGroupAttributes;
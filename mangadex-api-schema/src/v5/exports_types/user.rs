@@ -1,4 +1,4 @@
-use super::{ApiData, ApiObject, Result, Results, UserAttributes};
+use super::{ApiData, ApiObject, ReadingHistory, Result, Results, UserAttributes};
 
 pub type UserObject = ApiObject<UserAttributes>;
 pub type UserData = ApiData<UserObject>;
@@ -7,3 +7,5 @@ pub type UserResponse = Result<UserData>;
 
 pub type UserCollection = Results<UserObject>;
 pub type UserListResponse = Result<UserCollection>;
+
+pub type ReadingHistoryResponse = Result<ReadingHistory>;
@@ -1,13 +1,19 @@
 use mangadex_api_types::{
-    ContentRating, Demographic, Language, MangaDexDateTime, MangaState, MangaStatus,
+    ContentRating, CoverSize, Demographic, Language, MangaDexDateTime, MangaState, MangaStatus,
+    RelationshipType,
 };
 use serde::Deserialize;
+use url::Url;
 use uuid::Uuid;
 
 use crate::v5::{
     language_array_or_skip_null, localizedstring_array_or_map, manga_links_array_or_struct,
-    ApiObject, LocalizedString, MangaLinks, TagAttributes,
+    ApiObject, LocalizedString, MangaLinks, RelatedAttributes, TagAttributes,
 };
+use crate::ApiData;
+
+/// Base URL of the cover art images on the MangaDex CDN.
+const COVER_BASE_URL: &str = "https://uploads.mangadex.org/covers";
 
 /// General manga information.
 #[derive(Debug, Deserialize, Clone)]
@@ -17,6 +23,7 @@ use crate::v5::{
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct MangaAttributes {
     pub title: LocalizedString,
+    #[serde(default)]
     pub alt_titles: Vec<LocalizedString>,
     #[serde(with = "localizedstring_array_or_map")]
     pub description: LocalizedString,
@@ -33,15 +40,23 @@ pub struct MangaAttributes {
     pub publication_demographic: Option<Demographic>,
     pub status: MangaStatus,
     pub year: Option<u16>,
+    // Known issue: Older manga entries don't always have this field, so it is `None` when it isn't
+    // returned or is `null`.
     pub content_rating: Option<ContentRating>,
     // Known issue: This field isn't always returned, so default to `false` when it isn't.
     // TODO: Remove the default when MangaDex always returns this field.
     #[serde(default)]
     pub chapter_numbers_reset_on_new_volume: bool,
+    /// ID of the most recently uploaded chapter of the manga.
     pub latest_uploaded_chapter : Option<Uuid>,
+    /// Languages that the manga has chapters translated into.
+    ///
+    /// This avoids fetching the manga feed just to know which languages are available.
     // Known issue: MangaDex sometimes returns `null` as an element value, which doesn't match a possible language.
+    #[serde(default)]
     #[serde(with = "language_array_or_skip_null")]
     pub available_translated_languages: Vec<Language>,
+    #[serde(default)]
     pub tags: Vec<ApiObject<TagAttributes>>,
     /// The staff approval status of the manga.
     ///
@@ -59,3 +74,94 @@ pub struct MangaAttributes {
     pub updated_at: Option<MangaDexDateTime>,
     pub version: u32,
 }
+
+impl ApiObject<MangaAttributes> {
+    /// Get the URL of the manga cover art from the first expanded cover relationship.
+    ///
+    /// `None` is returned if the `cover_art` relationship wasn't included in the request.
+    pub fn cover_url(&self, size: CoverSize) -> Option<Url> {
+        self.relationships.iter().find_map(|relationship| {
+            match (&relationship.type_, &relationship.attributes) {
+                (RelationshipType::CoverArt, Some(RelatedAttributes::CoverArt(cover))) => {
+                    Url::parse(&format!(
+                        "{COVER_BASE_URL}/{}/{}{}",
+                        self.id,
+                        cover.file_name,
+                        size.suffix()
+                    ))
+                    .ok()
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Score how well the query matches the title or alternative titles of the manga, in any
+    /// language and ignoring case.
+    ///
+    /// The score is between `0.0` and `1.0`: `1.0` for an exact match, then prefix matches, then
+    /// matches anywhere in a title, each ranked higher the more of the title they cover. `0.0` is
+    /// returned if no title contains the query.
+    pub fn title_match_score(&self, query: &str) -> f32 {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return 0.0;
+        }
+
+        std::iter::once(&self.attributes.title)
+            .chain(&self.attributes.alt_titles)
+            .flat_map(|titles| titles.values())
+            .map(|title| title_match_score(&title.trim().to_lowercase(), &query))
+            .fold(0.0, f32::max)
+    }
+
+    /// Get the content rating of the manga, or [`ContentRating::Unknown`] for the older entries
+    /// that don't have one.
+    ///
+    /// These entries aren't reported as [`ContentRating::Safe`], so that they can still be
+    /// filtered out along with the explicit content.
+    pub fn content_rating_or_unknown(&self) -> ContentRating {
+        self.attributes
+            .content_rating
+            .unwrap_or(ContentRating::Unknown)
+    }
+}
+
+/// Score a lowercase title against a lowercase, non-empty query.
+fn title_match_score(title: &str, query: &str) -> f32 {
+    let coverage = query.chars().count() as f32 / title.chars().count().max(1) as f32;
+
+    if title == query {
+        1.0
+    } else if title.starts_with(query) {
+        0.6 + 0.2 * coverage
+    } else if title.contains(query) {
+        0.3 + 0.2 * coverage
+    } else {
+        0.0
+    }
+}
+
+impl ApiData<ApiObject<MangaAttributes>> {
+    /// Get the URL of the manga cover art from the first expanded cover relationship.
+    ///
+    /// `None` is returned if the `cover_art` relationship wasn't included in the request.
+    pub fn cover_url(&self, size: CoverSize) -> Option<Url> {
+        self.data.cover_url(size)
+    }
+
+    /// Score how well the query matches the titles of the manga.
+    ///
+    /// See [`ApiObject::title_match_score()`].
+    pub fn title_match_score(&self, query: &str) -> f32 {
+        self.data.title_match_score(query)
+    }
+
+    /// Get the content rating of the manga, or [`ContentRating::Unknown`] for the older entries
+    /// that don't have one.
+    ///
+    /// See [`ApiObject::content_rating_or_unknown()`].
+    pub fn content_rating_or_unknown(&self) -> ContentRating {
+        self.data.content_rating_or_unknown()
+    }
+}
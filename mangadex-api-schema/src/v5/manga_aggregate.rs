@@ -15,12 +15,28 @@ use crate::v5::{chapter_aggregate_array_or_map, volume_aggregate_array_or_map};
 #[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct MangaAggregate {
+    #[serde(default)]
     pub result : ResultType,
     /// Object with (volume_number, volume) key-value pairs.
     #[serde(with = "volume_aggregate_array_or_map")]
     pub volumes: Vec<VolumeAggregate>,
 }
 
+impl MangaAggregate {
+    /// Get the IDs of every translation of the given chapter number, across all volumes.
+    ///
+    /// Each chapter's own ID comes before its [`others`](ChapterAggregate::others), which lets a
+    /// reader switch to another language of the same chapter.
+    pub fn chapter_ids(&self, chapter: &str) -> Vec<Uuid> {
+        self.volumes
+            .iter()
+            .flat_map(|volume| &volume.chapters)
+            .filter(|aggregate| aggregate.chapter == chapter)
+            .flat_map(ChapterAggregate::ids)
+            .collect()
+    }
+}
+
 #[cfg(feature = "serialize")]
 #[allow(clippy::from_over_into)]
 impl Into<MangaAggregatSer> for MangaAggregate {
@@ -96,8 +112,17 @@ pub struct ChapterAggregate {
     /// Chapter number.
     pub chapter: String,
     pub id: Uuid,
-    // TODO: Add docblock explaining what this field represents.
+    /// IDs of the other translations of the chapter, such as in other languages or by other
+    /// groups.
+    #[serde(default)]
     pub others: Vec<Uuid>,
     /// Number of translations for the chapter.
     pub count: u32,
 }
+
+impl ChapterAggregate {
+    /// Get the chapter ID followed by the IDs of the other translations.
+    pub fn ids(&self) -> impl Iterator<Item = Uuid> + '_ {
+        std::iter::once(self.id).chain(self.others.iter().copied())
+    }
+}
@@ -170,6 +170,65 @@ impl MangaLinks {
             && self.novel_updates.is_none()
             && self.raw.is_none()
     }
+
+    /// Get the AniList page URL.
+    pub fn anilist_url(&self) -> Option<Url> {
+        self.anilist
+            .as_ref()
+            .and_then(|id| Url::parse(&format!("https://anilist.co/manga/{id}")).ok())
+    }
+
+    /// Get the Anime-Planet page URL.
+    pub fn anime_planet_url(&self) -> Option<Url> {
+        self.anime_planet
+            .as_ref()
+            .and_then(|slug| Url::parse(&format!("https://www.anime-planet.com/manga/{slug}")).ok())
+    }
+
+    /// Get the BookWalker page URL.
+    pub fn book_walker_url(&self) -> Option<Url> {
+        self.book_walker
+            .as_ref()
+            .and_then(|book_walker| Url::parse(&book_walker.to_string()).ok())
+    }
+
+    /// Get the Kitsu API URL.
+    ///
+    /// The ID version of the URL is used for numeric values, and the slug version otherwise.
+    pub fn kitsu_url(&self) -> Option<Url> {
+        self.kitsu.as_ref().and_then(|kitsu| {
+            if kitsu.parse::<u64>().is_ok() {
+                Url::parse(&format!("https://kitsu.io/api/edge/manga/{kitsu}")).ok()
+            } else {
+                Url::parse_with_params(
+                    "https://kitsu.io/api/edge/manga",
+                    &[("filter[slug]", kitsu)],
+                )
+                .ok()
+            }
+        })
+    }
+
+    /// Get the MangaUpdates page URL.
+    pub fn manga_updates_url(&self) -> Option<Url> {
+        self.manga_updates
+            .as_ref()
+            .and_then(|manga_updates| Url::parse(&manga_updates.to_string()).ok())
+    }
+
+    /// Get the MyAnimeList page URL.
+    pub fn my_anime_list_url(&self) -> Option<Url> {
+        self.my_anime_list
+            .as_ref()
+            .and_then(|my_anime_list| Url::parse(&my_anime_list.to_string()).ok())
+    }
+
+    /// Get the NovelUpdates page URL.
+    pub fn novel_updates_url(&self) -> Option<Url> {
+        self.novel_updates
+            .as_ref()
+            .and_then(|novel_updates| Url::parse(&novel_updates.to_string()).ok())
+    }
 }
 
 /// BookWalker URI.
@@ -10,7 +10,57 @@ use uuid::Uuid;
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct MangaReadingStatuses {
+    #[serde(default)]
     pub result : ResultType,
     /// Mapping of manga ID to reading status.
     pub statuses: HashMap<Uuid, ReadingStatus>,
 }
+
+/// Changes needed to bring a remote reading status map in line with a local one.
+///
+/// Each entry can be applied with the per-manga reading status endpoint: set the status of the
+/// `added` and `changed` manga, and clear the status of the `removed` ones.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReadingStatusDiff {
+    /// Manga that only have a local status, with that status.
+    pub added: HashMap<Uuid, ReadingStatus>,
+    /// Manga whose local status differs from the remote one, with the local status.
+    pub changed: HashMap<Uuid, ReadingStatus>,
+    /// Manga that only have a remote status, with that status.
+    pub removed: HashMap<Uuid, ReadingStatus>,
+}
+
+impl ReadingStatusDiff {
+    /// Compare the `local` statuses against the `remote` ones.
+    pub fn compute(
+        local: &HashMap<Uuid, ReadingStatus>,
+        remote: &HashMap<Uuid, ReadingStatus>,
+    ) -> Self {
+        let mut diff = Self::default();
+
+        for (manga_id, status) in local {
+            match remote.get(manga_id) {
+                None => {
+                    diff.added.insert(*manga_id, *status);
+                }
+                Some(remote_status) if remote_status != status => {
+                    diff.changed.insert(*manga_id, *status);
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (manga_id, status) in remote {
+            if !local.contains_key(manga_id) {
+                diff.removed.insert(*manga_id, *status);
+            }
+        }
+
+        diff
+    }
+
+    /// Check if the local and remote statuses are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
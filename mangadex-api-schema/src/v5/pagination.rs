@@ -0,0 +1,92 @@
+//! Pagination metadata shared by every collection response.
+
+use serde::Deserialize;
+
+use crate::v5::Results;
+
+/// Pagination metadata returned alongside a collection of entities.
+///
+/// The values are the ones the server applied, which can differ from the ones requested.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct Pagination {
+    pub limit: u32,
+    pub offset: u32,
+    pub total: u32,
+}
+
+impl Pagination {
+    /// Get the number of pages needed to go through all `total` results, `limit` at a time.
+    ///
+    /// This is `0` when `limit` is `0`.
+    pub fn total_pages(&self) -> u32 {
+        if self.limit == 0 {
+            return 0;
+        }
+
+        self.total.div_ceil(self.limit)
+    }
+
+    /// Get the 1-based number of the page starting at `offset`.
+    ///
+    /// This is `0` when `limit` is `0`.
+    pub fn current_page(&self) -> u32 {
+        if self.limit == 0 {
+            return 0;
+        }
+
+        self.offset / self.limit + 1
+    }
+
+    /// Check if the server applied a lower `limit` than the one requested.
+    ///
+    /// MangaDex caps the limit of the list endpoints, usually at 100, without reporting an error.
+    pub fn was_clamped(&self, requested_limit: u32) -> bool {
+        self.limit < requested_limit
+    }
+}
+
+/// Common interface over the paginated `*Collection` responses.
+///
+/// This allows the same code to read the results of any list endpoint.
+pub trait CollectionResponse<T> {
+    /// Get the `limit`, `offset` and `total` values of the response.
+    fn pagination(&self) -> Pagination;
+
+    /// Get the entities returned in this page.
+    fn data(&self) -> &[T];
+}
+
+impl<T> CollectionResponse<T> for Results<T> {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            limit: self.limit,
+            offset: self.offset,
+            total: self.total,
+        }
+    }
+
+    fn data(&self) -> &[T] {
+        &self.data
+    }
+}
+
+/// Iterate over the entities of the page, so that `for user in users { ... }` works.
+impl<T> IntoIterator for Results<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Results<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
@@ -12,7 +12,8 @@ use uuid::Uuid;
 #[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct RatingsList {
-    pub result : ResultType,
+    #[serde(default)]
+    pub result: ResultType,
     pub ratings: HashMap<Uuid, Rating>,
 }
 
@@ -0,0 +1,29 @@
+//! Reading history from a response body.
+
+use mangadex_api_types::{MangaDexDateTime, ResultType};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ReadingHistory {
+    #[serde(default)]
+    pub result: ResultType,
+    /// Most recently read chapters.
+    pub data: Vec<ReadingHistoryEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ReadingHistoryEntry {
+    pub chapter_id: Uuid,
+    /// Datetime in `YYYY-MM-DDTHH:MM:SS+HH:MM` format.
+    #[cfg_attr(feature = "specta", specta(type = String))]
+    pub read_date: MangaDexDateTime,
+}
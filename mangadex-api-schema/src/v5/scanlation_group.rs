@@ -12,6 +12,7 @@ use crate::v5::LocalizedString;
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct ScanlationGroupAttributes {
     pub name: String,
+    #[serde(default)]
     pub alt_names: Vec<LocalizedString>,
     pub website: Option<String>,
     pub irc_server: Option<String>,
@@ -54,3 +55,38 @@ pub struct ScanlationGroupAttributes {
     /// Datetime in `YYYY-MM-DDTHH:MM:SS+HH:MM` format.
     pub updated_at: MangaDexDateTime,
 }
+
+impl ScanlationGroupAttributes {
+    /// Get the languages the group focuses on, which is empty if the group didn't set any.
+    pub fn focused_languages(&self) -> &[Language] {
+        self.focused_languages.as_deref().unwrap_or_default()
+    }
+
+    /// Check if the group is still active and verified by MangaDex.
+    pub fn is_active_and_verified(&self) -> bool {
+        !self.inactive && self.verified
+    }
+
+    /// Get the contact links and handles the group has set, as `(kind, value)` pairs.
+    ///
+    /// The kinds are `website`, `discord`, `email`, `irc`, `twitter` and `mangaUpdates`.
+    pub fn contacts(&self) -> Vec<(&'static str, String)> {
+        let irc = match (&self.irc_server, &self.irc_channel) {
+            (Some(server), Some(channel)) => Some(format!("{channel}@{server}")),
+            (Some(server), None) => Some(server.clone()),
+            (None, channel) => channel.clone(),
+        };
+
+        [
+            ("website", self.website.clone()),
+            ("discord", self.discord.clone()),
+            ("email", self.contact_email.clone()),
+            ("irc", irc),
+            ("twitter", self.twitter.as_ref().map(Url::to_string)),
+            ("mangaUpdates", self.manga_updates.as_ref().map(Url::to_string)),
+        ]
+        .into_iter()
+        .filter_map(|(kind, value)| value.map(|value| (kind, value)))
+        .collect()
+    }
+}
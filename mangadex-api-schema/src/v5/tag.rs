@@ -2,6 +2,7 @@ use mangadex_api_types::TagGroup;
 use serde::Deserialize;
 
 use crate::v5::{localizedstring_array_or_map, LocalizedString};
+use crate::{ApiData, ApiObject};
 
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -15,3 +16,17 @@ pub struct TagAttributes {
     pub group: TagGroup,
     pub version: u32,
 }
+
+impl ApiObject<TagAttributes> {
+    /// Get the group the tag belongs to.
+    pub fn group(&self) -> TagGroup {
+        self.attributes.group
+    }
+}
+
+impl ApiData<ApiObject<TagAttributes>> {
+    /// Get the group the tag belongs to.
+    pub fn group(&self) -> TagGroup {
+        self.data.group()
+    }
+}
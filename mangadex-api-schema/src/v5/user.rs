@@ -1,6 +1,8 @@
 use mangadex_api_types::UserRole;
 use serde::Deserialize;
 
+use crate::{ApiData, ApiObject};
+
 /// General user information.
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
@@ -8,6 +10,21 @@ use serde::Deserialize;
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct UserAttributes {
     pub username: String,
+    #[serde(default)]
     pub roles: Vec<UserRole>,
     pub version: u32,
 }
+
+impl ApiObject<UserAttributes> {
+    /// Check if the user has the given role.
+    pub fn has_role(&self, role: UserRole) -> bool {
+        self.attributes.roles.contains(&role)
+    }
+}
+
+impl ApiData<ApiObject<UserAttributes>> {
+    /// Check if the user has the given role.
+    pub fn has_role(&self, role: UserRole) -> bool {
+        self.data.has_role(role)
+    }
+}
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use mangadex_api_schema_rust::v5::AtHomeServer;
+use mangadex_api_types::{FixedClock, Quality};
+use url::Url;
+
+const AT_HOME_SERVER_RESPONSE: &str = r#"{
+    "result": "ok",
+    "baseUrl": "https://cmdxd98sb0x3yprd.mangadex.network",
+    "chapter": {
+        "hash": "3303dd03ac8d27452cce3f2a882e94b2",
+        "data": [
+            "1-f7a76de10d346de7ba01786762ebbedc666b412ad0d4b73baa330a2a392dbcdd.png",
+            "2-f6a23b4b1b8b8b9a8b6e6e8d2f7e8d3c4b5a69788f7e6d5c4b3a2918f7e6d5c4.png"
+        ],
+        "dataSaver": [
+            "1-27b8e4ab1ae4d4dec4ab9aa0f8d05c6a9bb0b6a2c1d0e9f8a7b6c5d4e3f2a1b0.jpg",
+            "2-0e9f8a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f.jpg"
+        ]
+    }
+}"#;
+
+fn at_home_server() -> AtHomeServer {
+    serde_json::from_str(AT_HOME_SERVER_RESPONSE).unwrap()
+}
+
+#[test]
+fn at_home_server_page_url() {
+    let server = at_home_server();
+
+    assert_eq!(
+        server.page_url(0, Quality::Data),
+        Some(
+            Url::parse(
+                "https://cmdxd98sb0x3yprd.mangadex.network/data/3303dd03ac8d27452cce3f2a882e94b2/\
+                 1-f7a76de10d346de7ba01786762ebbedc666b412ad0d4b73baa330a2a392dbcdd.png"
+            )
+            .unwrap()
+        )
+    );
+    assert_eq!(
+        server.page_url(1, Quality::DataSaver),
+        Some(
+            Url::parse(
+                "https://cmdxd98sb0x3yprd.mangadex.network/data-saver/3303dd03ac8d27452cce3f2a882e94b2/\
+                 2-0e9f8a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f.jpg"
+            )
+            .unwrap()
+        )
+    );
+}
+
+#[test]
+fn at_home_server_page_url_out_of_range() {
+    let server = at_home_server();
+
+    assert_eq!(server.page_url(2, Quality::Data), None);
+}
+
+#[test]
+fn at_home_server_page_urls() {
+    let server = at_home_server();
+
+    let urls = server.page_urls(Quality::DataSaver);
+
+    assert_eq!(urls.len(), 2);
+    assert!(urls.iter().all(|url| url
+        .path()
+        .starts_with("/data-saver/3303dd03ac8d27452cce3f2a882e94b2/")));
+    assert!(urls[0]
+        .path()
+        .ends_with("/1-27b8e4ab1ae4d4dec4ab9aa0f8d05c6a9bb0b6a2c1d0e9f8a7b6c5d4e3f2a1b0.jpg"));
+}
+
+#[test]
+fn at_home_server_expires_after_its_ttl() {
+    let mut server = at_home_server();
+    server.fetched_at = serde_json::from_str(r#""2023-01-01T12:00:00+00:00""#).unwrap();
+
+    let clock = |datetime: &str| -> FixedClock {
+        FixedClock::new(serde_json::from_value(serde_json::Value::from(datetime)).unwrap())
+    };
+
+    assert!(!server.is_expired_with_clock(clock("2023-01-01T12:14:59+00:00")));
+    assert!(server.is_expired_with_clock(clock("2023-01-01T12:15:00+00:00")));
+    assert!(server
+        .expires_within_with_clock(Duration::from_secs(60), clock("2023-01-01T12:14:00+00:00")));
+    assert!(!server
+        .expires_within_with_clock(Duration::from_secs(60), clock("2023-01-01T12:13:59+00:00")));
+}
+
+#[test]
+fn at_home_server_equality_ignores_when_it_was_fetched() {
+    let mut server = at_home_server();
+    server.fetched_at = serde_json::from_str(r#""2023-01-01T12:00:00+00:00""#).unwrap();
+
+    assert_eq!(server, at_home_server());
+}
@@ -1,4 +1,6 @@
-use mangadex_api_schema_rust::v5::ChapterCollection;
+use mangadex_api_schema_rust::v5::{ChapterCollection, ChapterData};
+use mangadex_api_types::{FixedClock, MangaDexDateTime};
+use time::{Duration, OffsetDateTime};
 use std::{fs::File, io::{Write, Read}};
 
 use reqwest::Client;
@@ -44,3 +46,108 @@ async fn test_des_and_ser(){
     assert_eq!(serde_json::to_string(&mangadata).unwrap(), file1_data);
 }
 
+
+fn chapter_data_with_publish_at(publish_at: &MangaDexDateTime) -> ChapterData {
+    serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "250f091f-4166-4831-9f45-89ff54bf433b",
+            "type": "chapter",
+            "attributes": {
+                "title": "Delayed",
+                "volume": null,
+                "chapter": "13",
+                "pages": 20,
+                "translatedLanguage": "en",
+                "externalUrl": null,
+                "version": 1,
+                "createdAt": "2023-01-01T00:00:00+00:00",
+                "updatedAt": "2023-01-01T00:00:00+00:00",
+                "publishAt": publish_at.to_string(),
+                "readableAt": publish_at.to_string()
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn chapter_with_future_publish_at_is_not_published() {
+    let now = OffsetDateTime::now_utc();
+    let chapter = chapter_data_with_publish_at(&MangaDexDateTime::new(&(now + Duration::days(7))));
+
+    assert!(!chapter.is_published(MangaDexDateTime::new(&now)));
+    assert!(!chapter.data.is_published(MangaDexDateTime::new(&now)));
+}
+
+#[test]
+fn chapter_with_past_publish_at_is_published() {
+    let now = OffsetDateTime::now_utc();
+    let chapter = chapter_data_with_publish_at(&MangaDexDateTime::new(&(now - Duration::days(7))));
+
+    assert!(chapter.is_published(MangaDexDateTime::new(&now)));
+    assert_eq!(chapter.data.attributes.created_at.to_string(), "2023-01-01T00:00:00+00:00");
+}
+
+#[test]
+fn chapter_is_published_once_a_fixed_clock_reaches_publish_at() {
+    let publish_at = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+    let chapter = chapter_data_with_publish_at(&MangaDexDateTime::new(&publish_at));
+
+    let mut clock = FixedClock::new(MangaDexDateTime::new(&(publish_at - Duration::seconds(1))));
+    assert!(!chapter.is_published(&clock));
+
+    clock.set(MangaDexDateTime::new(&publish_at));
+    assert!(chapter.is_published(&clock));
+    assert!(chapter.data.is_published(clock));
+}
+
+fn chapter_data_with_external_url(pages: u32, external_url: Option<&str>) -> ChapterData {
+    serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "250f091f-4166-4831-9f45-89ff54bf433b",
+            "type": "chapter",
+            "attributes": {
+                "title": "",
+                "volume": "1",
+                "chapter": "1",
+                "pages": pages,
+                "translatedLanguage": "en",
+                "externalUrl": external_url,
+                "version": 1,
+                "createdAt": "2023-01-01T00:00:00+00:00",
+                "updatedAt": "2023-01-01T00:00:00+00:00",
+                "publishAt": "2023-01-01T00:00:00+00:00",
+                "readableAt": "2023-01-01T00:00:00+00:00"
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn internal_chapter_has_pages_and_no_external_url() {
+    let chapter = chapter_data_with_external_url(24, None);
+
+    assert_eq!(chapter.data.attributes.pages, 24);
+    assert_eq!(chapter.data.attributes.external_url, None);
+    assert!(!chapter.is_external());
+}
+
+#[test]
+fn external_chapter_has_an_external_url_and_no_pages() {
+    let chapter = chapter_data_with_external_url(0, Some("https://mangaplus.shueisha.co.jp/viewer/1000486"));
+
+    assert_eq!(chapter.data.attributes.pages, 0);
+    assert_eq!(
+        chapter.data.attributes.external_url,
+        Some(Url::parse("https://mangaplus.shueisha.co.jp/viewer/1000486").unwrap())
+    );
+    assert!(chapter.is_external());
+    assert!(chapter.data.is_external());
+}
@@ -1,4 +1,5 @@
-use mangadex_api_schema_rust::v5::GroupCollection;
+use mangadex_api_schema_rust::v5::{GroupCollection, GroupData};
+use mangadex_api_types::Language;
 use std::{fs::File, io::{Write, Read}};
 
 use reqwest::Client;
@@ -43,3 +44,92 @@ async fn test_des_and_ser(){
     let mangadata : GroupCollection = serde_json::from_str(file1_data.as_str()).unwrap();
     assert_eq!(serde_json::to_string(&mangadata).unwrap(), file1_data);
 }
+
+#[test]
+fn group_attributes_expose_focused_languages_flags_and_contacts() {
+    let group: GroupData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "0b870e54-c75f-4d2e-8068-c40f939135fd",
+            "type": "scanlation_group",
+            "attributes": {
+                "name": "Kredim",
+                "altNames": [],
+                "website": "https://kredim.example.org",
+                "ircServer": "irc.example.org",
+                "ircChannel": "#kredim",
+                "discord": "kredim",
+                "contactEmail": "contact@kredim.example.org",
+                "description": null,
+                "twitter": "https://twitter.com/kredim",
+                "mangaUpdates": null,
+                "focusedLanguages": ["en", "fr"],
+                "locked": true,
+                "official": false,
+                "verified": true,
+                "inactive": false,
+                "publishDelay": null,
+                "version": 3,
+                "createdAt": "2021-04-19T21:45:59+00:00",
+                "updatedAt": "2022-03-14T13:21:35+00:00"
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap();
+    let attributes = &group.data.attributes;
+
+    assert_eq!(attributes.focused_languages(), &[Language::English, Language::French]);
+    assert!(attributes.locked);
+    assert!(!attributes.official);
+    assert!(attributes.is_active_and_verified());
+    assert_eq!(
+        attributes.contacts(),
+        vec![
+            ("website", "https://kredim.example.org".to_string()),
+            ("discord", "kredim".to_string()),
+            ("email", "contact@kredim.example.org".to_string()),
+            ("irc", "#kredim@irc.example.org".to_string()),
+            ("twitter", "https://twitter.com/kredim".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn group_without_focused_languages_has_none() {
+    let group: GroupData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "0b870e54-c75f-4d2e-8068-c40f939135fd",
+            "type": "scanlation_group",
+            "attributes": {
+                "name": "Kredim",
+                "website": null,
+                "ircServer": null,
+                "ircChannel": null,
+                "discord": null,
+                "contactEmail": null,
+                "description": null,
+                "twitter": null,
+                "mangaUpdates": null,
+                "focusedLanguages": null,
+                "locked": false,
+                "official": false,
+                "verified": false,
+                "inactive": true,
+                "publishDelay": null,
+                "version": 1,
+                "createdAt": "2021-04-19T21:45:59+00:00",
+                "updatedAt": "2021-04-19T21:45:59+00:00"
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap();
+
+    assert!(group.data.attributes.focused_languages().is_empty());
+    assert!(!group.data.attributes.is_active_and_verified());
+    assert!(group.data.attributes.contacts().is_empty());
+}
@@ -2,7 +2,10 @@ use std::{fs::File, io::{Write, Read}};
 
 use reqwest::Client;
 use url::Url;
-use mangadex_api_schema_rust::v5::MangaData;
+use mangadex_api_schema_rust::v5::{LocalizedString, MangaData, MangaLinks};
+use mangadex_api_types::{
+    ContentRating, CoverSize, Language, MangaRelation, MangaState, RelationshipType,
+};
 
 #[tokio::test]
 async fn test_manga_serialization(){
@@ -44,3 +47,572 @@ async fn test_des_and_ser(){
     assert_eq!(serde_json::to_string(&mangadata).unwrap(), file1_data);
 }
 
+
+#[test]
+fn localized_string_prefers_the_requested_language() {
+    let title: LocalizedString = serde_json::from_value(serde_json::json!({
+        "en": "The Grim Reaper Falls In Love With A Human",
+        "ja": "死神は人間に恋をする",
+        "fr": "La Faucheuse tombe amoureuse d'un humain"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        title.get_or_fallback(&[Language::Japanese, Language::English]),
+        Some("死神は人間に恋をする")
+    );
+    assert_eq!(
+        title.get_or_fallback(&[Language::SpanishCastilian, Language::French]),
+        Some("La Faucheuse tombe amoureuse d'un humain")
+    );
+}
+
+#[test]
+fn localized_string_falls_back_to_english_then_any() {
+    let title: LocalizedString = [
+        (Language::English, "English title".to_string()),
+        (Language::Japanese, "Japanese title".to_string()),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(title.get_or_fallback(&[Language::SpanishCastilian]), Some("English title"));
+    assert_eq!(title.get_or_fallback(&[]), Some("English title"));
+
+    let title: LocalizedString = [(Language::Japanese, "Japanese title".to_string())]
+        .into_iter()
+        .collect();
+
+    assert_eq!(title.get_or_fallback(&[Language::SpanishCastilian]), Some("Japanese title"));
+}
+
+#[test]
+fn localized_string_empty_has_no_fallback() {
+    let title: LocalizedString = serde_json::from_value(serde_json::json!({})).unwrap();
+
+    assert!(title.is_empty());
+    assert_eq!(title.get_or_fallback(&[Language::English]), None);
+}
+
+#[test]
+fn manga_available_translated_languages_and_latest_uploaded_chapter() {
+    let manga: MangaData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "be2efc56-1669-4e42-9f27-3bd232bca8ea",
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": "The Grim Reaper Falls In Love With A Human"
+                },
+                "altTitles": [],
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": null,
+                "lastChapter": null,
+                "publicationDemographic": null,
+                "status": "ongoing",
+                "year": null,
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": false,
+                "latestUploadedChapter": "32b229f6-e9bf-41a0-9694-63c11191704c",
+                "availableTranslatedLanguages": ["en", null, "fr"],
+                "tags": [],
+                "state": "published",
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        manga.data.attributes.available_translated_languages,
+        vec![Language::English, Language::French]
+    );
+    assert_eq!(
+        manga.data.attributes.latest_uploaded_chapter,
+        Some(uuid::Uuid::parse_str("32b229f6-e9bf-41a0-9694-63c11191704c").unwrap())
+    );
+}
+
+#[test]
+fn manga_links_deserialize_and_build_urls() {
+    let links: MangaLinks = serde_json::from_value(serde_json::json!({
+        "al": "112847",
+        "ap": "under-grand-hotel",
+        "bw": "series/289459",
+        "kt": "23040",
+        "mu": "157722",
+        "amz": "https://www.amazon.co.jp/gp/product/B074CHFLT2",
+        "mal": "12648",
+        "raw": "https://manga.bilibili.com/m/detail/mc29443",
+        "engtl": "https://kodanshacomics.com/series/we-must-never-fall-in-love/"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        links.anilist_url().unwrap().as_str(),
+        "https://anilist.co/manga/112847"
+    );
+    assert_eq!(
+        links.anime_planet_url().unwrap().as_str(),
+        "https://www.anime-planet.com/manga/under-grand-hotel"
+    );
+    assert_eq!(
+        links.book_walker_url().unwrap().as_str(),
+        "https://bookwalker.jp/series/289459"
+    );
+    assert_eq!(
+        links.kitsu_url().unwrap().as_str(),
+        "https://kitsu.io/api/edge/manga/23040"
+    );
+    assert_eq!(
+        links.manga_updates_url().unwrap().as_str(),
+        "https://www.mangaupdates.com/series.html?id=157722"
+    );
+    assert_eq!(
+        links.my_anime_list_url().unwrap().as_str(),
+        "https://myanimelist.net/manga/12648"
+    );
+    assert!(links.novel_updates_url().is_none());
+    assert_eq!(
+        links.raw.unwrap().as_str(),
+        "https://manga.bilibili.com/m/detail/mc29443"
+    );
+    assert_eq!(
+        links.english_translation.as_deref(),
+        Some("https://kodanshacomics.com/series/we-must-never-fall-in-love/")
+    );
+}
+
+#[test]
+fn manga_links_kitsu_slug_uses_the_filter_url() {
+    let links: MangaLinks = serde_json::from_value(serde_json::json!({
+        "kt": "yotsuba"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        links.kitsu_url().unwrap().as_str(),
+        "https://kitsu.io/api/edge/manga?filter%5Bslug%5D=yotsuba"
+    );
+}
+
+#[test]
+fn manga_cover_url_uses_the_expanded_cover_relationship() {
+    let manga: MangaData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "be2efc56-1669-4e42-9f27-3bd232bca8ea",
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": "The Grim Reaper Falls In Love With A Human"
+                },
+                "altTitles": [],
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": null,
+                "lastChapter": null,
+                "publicationDemographic": null,
+                "status": "ongoing",
+                "year": null,
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": false,
+                "latestUploadedChapter": null,
+                "availableTranslatedLanguages": ["en"],
+                "tags": [],
+                "state": "published",
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1
+            },
+            "relationships": [
+                {
+                    "id": "4d2c5d2e-4e5a-4b0b-8a2f-3c7f5c8b9a10",
+                    "type": "author"
+                },
+                {
+                    "id": "c4e8d6f2-1b3a-4f5e-9d7c-2a1b3c4d5e6f",
+                    "type": "cover_art",
+                    "attributes": {
+                        "description": "",
+                        "volume": "1",
+                        "fileName": "cover.jpg",
+                        "locale": "ja",
+                        "createdAt": "2022-01-01T00:00:00+00:00",
+                        "updatedAt": "2022-01-01T00:00:00+00:00",
+                        "version": 1
+                    }
+                }
+            ]
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        manga.cover_url(CoverSize::Original).unwrap().as_str(),
+        "https://uploads.mangadex.org/covers/be2efc56-1669-4e42-9f27-3bd232bca8ea/cover.jpg"
+    );
+    assert_eq!(
+        manga.cover_url(CoverSize::Small).unwrap().as_str(),
+        "https://uploads.mangadex.org/covers/be2efc56-1669-4e42-9f27-3bd232bca8ea/cover.jpg.256.jpg"
+    );
+    assert_eq!(
+        manga.data.cover_url(CoverSize::Medium).unwrap().as_str(),
+        "https://uploads.mangadex.org/covers/be2efc56-1669-4e42-9f27-3bd232bca8ea/cover.jpg.512.jpg"
+    );
+}
+
+#[test]
+fn manga_cover_url_is_none_without_an_expanded_cover() {
+    let manga: MangaData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "be2efc56-1669-4e42-9f27-3bd232bca8ea",
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": "The Grim Reaper Falls In Love With A Human"
+                },
+                "altTitles": [],
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": null,
+                "lastChapter": null,
+                "publicationDemographic": null,
+                "status": "ongoing",
+                "year": null,
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": false,
+                "latestUploadedChapter": null,
+                "availableTranslatedLanguages": ["en"],
+                "tags": [],
+                "state": "published",
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1
+            },
+            "relationships": [
+                {
+                    "id": "c4e8d6f2-1b3a-4f5e-9d7c-2a1b3c4d5e6f",
+                    "type": "cover_art"
+                }
+            ]
+        }
+    }))
+    .unwrap();
+
+    assert!(manga.cover_url(CoverSize::Original).is_none());
+}
+
+#[test]
+fn manga_tolerates_unknown_fields_and_missing_collections() {
+    let manga: MangaData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "someNewTopLevelField": {"nested": true},
+        "data": {
+            "id": "be2efc56-1669-4e42-9f27-3bd232bca8ea",
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": "The Grim Reaper Falls In Love With A Human"
+                },
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": null,
+                "lastChapter": null,
+                "publicationDemographic": null,
+                "status": "ongoing",
+                "year": null,
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": false,
+                "latestUploadedChapter": null,
+                "state": "published",
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1,
+                "someNewAttribute": 42
+            }
+        }
+    }))
+    .unwrap();
+
+    assert!(manga.data.attributes.alt_titles.is_empty());
+    assert!(manga.data.attributes.available_translated_languages.is_empty());
+    assert!(manga.data.attributes.tags.is_empty());
+    assert!(manga.data.relationships.is_empty());
+}
+
+#[test]
+fn manga_relationships_getters_filter_by_type() {
+    let author_ids = [
+        "c5b84e3c-6a0f-4b6e-9e0a-0f6f3a1d8c11",
+        "5e2c4b1a-3f0d-4c8e-9a6b-7d1e2f3a4b52",
+    ];
+    let manga: MangaData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "be2efc56-1669-4e42-9f27-3bd232bca8ea",
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": "The Grim Reaper Falls In Love With A Human"
+                },
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": null,
+                "lastChapter": null,
+                "publicationDemographic": null,
+                "status": "ongoing",
+                "year": null,
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": false,
+                "state": "published",
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1
+            },
+            "relationships": [
+                {
+                    "id": author_ids[0],
+                    "type": "author"
+                },
+                {
+                    "id": "0b1a2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d",
+                    "type": "artist"
+                },
+                {
+                    "id": author_ids[1],
+                    "type": "author"
+                },
+                {
+                    "id": "9f8e7d6c-5b4a-4c3d-8e2f-1a0b9c8d7e6f",
+                    "type": "cover_art"
+                },
+                {
+                    "id": "11111111-2222-4333-8444-555555555555",
+                    "type": "manga",
+                    "related": "sequel"
+                }
+            ]
+        }
+    }))
+    .unwrap();
+    let relationships = &manga.data.relationships;
+
+    let authors: Vec<String> = relationships
+        .of_type(RelationshipType::Author)
+        .map(|relationship| relationship.id.to_string())
+        .collect();
+    assert_eq!(authors, author_ids);
+    assert_eq!(
+        relationships.author().unwrap().id.to_string(),
+        author_ids[0]
+    );
+    assert_eq!(
+        relationships.artist().unwrap().id.to_string(),
+        "0b1a2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d"
+    );
+    assert_eq!(
+        relationships.cover_art().unwrap().id.to_string(),
+        "9f8e7d6c-5b4a-4c3d-8e2f-1a0b9c8d7e6f"
+    );
+    assert!(relationships.scanlation_group().is_none());
+    assert_eq!(
+        relationships
+            .first_of_type(RelationshipType::Manga)
+            .unwrap()
+            .related,
+        Some(MangaRelation::Sequel)
+    );
+    // Nothing is dropped from the underlying list.
+    assert_eq!(relationships.len(), 5);
+}
+
+fn manga_with_publication_fields(
+    year: serde_json::Value,
+    last_volume: serde_json::Value,
+    last_chapter: serde_json::Value,
+    state: &str,
+    chapter_numbers_reset_on_new_volume: bool,
+) -> MangaData {
+    serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "be2efc56-1669-4e42-9f27-3bd232bca8ea",
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": "The Grim Reaper Falls In Love With A Human"
+                },
+                "altTitles": [],
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": last_volume,
+                "lastChapter": last_chapter,
+                "publicationDemographic": null,
+                "status": "completed",
+                "year": year,
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": chapter_numbers_reset_on_new_volume,
+                "availableTranslatedLanguages": [],
+                "tags": [],
+                "state": state,
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn manga_deserializes_the_publication_fields() {
+    let manga = manga_with_publication_fields(
+        serde_json::json!(2019),
+        serde_json::json!("12"),
+        serde_json::json!("104.5"),
+        "published",
+        true,
+    );
+    let attributes = &manga.data.attributes;
+
+    assert_eq!(attributes.year, Some(2019));
+    assert_eq!(attributes.last_volume.as_deref(), Some("12"));
+    assert_eq!(attributes.last_chapter.as_deref(), Some("104.5"));
+    assert_eq!(attributes.state, MangaState::Published);
+    assert!(attributes.chapter_numbers_reset_on_new_volume);
+}
+
+#[test]
+fn manga_deserializes_null_publication_fields() {
+    let manga = manga_with_publication_fields(
+        serde_json::Value::Null,
+        serde_json::Value::Null,
+        serde_json::Value::Null,
+        "draft",
+        false,
+    );
+    let attributes = &manga.data.attributes;
+
+    assert_eq!(attributes.year, None);
+    assert_eq!(attributes.last_volume, None);
+    assert_eq!(attributes.last_chapter, None);
+    assert_eq!(attributes.state, MangaState::Draft);
+    assert!(!attributes.chapter_numbers_reset_on_new_volume);
+}
+
+#[test]
+fn manga_without_a_content_rating_deserializes() {
+    let manga: MangaData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "be2efc56-1669-4e42-9f27-3bd232bca8ea",
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": "The Grim Reaper Falls In Love With A Human"
+                },
+                "altTitles": [],
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": null,
+                "lastChapter": null,
+                "publicationDemographic": null,
+                "status": "completed",
+                "year": null,
+                "availableTranslatedLanguages": [],
+                "tags": [],
+                "state": "published",
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(manga.data.attributes.content_rating, None);
+    assert_eq!(manga.content_rating_or_unknown(), ContentRating::Unknown);
+}
+
+fn manga_titled(title: serde_json::Value, alt_titles: serde_json::Value) -> MangaData {
+    let mut manga = manga_with_publication_fields(
+        serde_json::Value::Null,
+        serde_json::Value::Null,
+        serde_json::Value::Null,
+        "published",
+        false,
+    );
+    manga.data.attributes.title = serde_json::from_value(title).unwrap();
+    manga.data.attributes.alt_titles = serde_json::from_value(alt_titles).unwrap();
+
+    manga
+}
+
+#[test]
+fn title_match_score_ranks_exact_then_prefix_then_substring_matches() {
+    let manga = manga_titled(
+        serde_json::json!({ "en": "Lycoris Recoil" }),
+        serde_json::json!([{ "ja": "リコリス・リコイル" }]),
+    );
+
+    let exact = manga.title_match_score("lycoris recoil");
+    let prefix = manga.title_match_score("Lycoris");
+    let substring = manga.title_match_score("Recoil");
+
+    assert_eq!(exact, 1.0);
+    assert!(prefix < exact && prefix > substring, "{prefix}");
+    assert!(substring > 0.0, "{substring}");
+    assert_eq!(manga.title_match_score("Bocchi"), 0.0);
+    assert_eq!(manga.title_match_score("  "), 0.0);
+}
+
+#[test]
+fn title_match_score_checks_the_alternative_titles() {
+    let manga = manga_titled(
+        serde_json::json!({ "en": "Lycoris Recoil" }),
+        serde_json::json!([{ "ja": "リコリス・リコイル" }]),
+    );
+
+    assert_eq!(manga.title_match_score("リコリス・リコイル"), 1.0);
+    assert!(manga.title_match_score("リコリス") > 0.0);
+}
+
+#[test]
+fn title_match_score_ranks_longer_prefix_matches_higher() {
+    let manga = manga_titled(
+        serde_json::json!({ "en": "Lycoris Recoil" }),
+        serde_json::json!([]),
+    );
+
+    assert!(manga.title_match_score("Lycoris Rec") > manga.title_match_score("Lyc"));
+}
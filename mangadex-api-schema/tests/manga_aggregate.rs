@@ -0,0 +1,50 @@
+use mangadex_api_schema_rust::v5::manga_aggregate::MangaAggregate;
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn aggregate_keeps_the_other_translations_of_a_chapter() {
+    let english_id = Uuid::new_v4();
+    let french_id = Uuid::new_v4();
+    let spanish_id = Uuid::new_v4();
+    let next_id = Uuid::new_v4();
+
+    let aggregate: MangaAggregate = serde_json::from_value(json!({
+        "result": "ok",
+        "volumes": {
+            "1": {
+                "volume": "1",
+                "count": 4,
+                "chapters": {
+                    "1": {
+                        "chapter": "1",
+                        "id": english_id,
+                        "others": [french_id, spanish_id],
+                        "count": 3
+                    },
+                    "2": {
+                        "chapter": "2",
+                        "id": next_id,
+                        "others": [],
+                        "count": 1
+                    }
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    let chapter = aggregate.volumes[0]
+        .chapters
+        .iter()
+        .find(|chapter| chapter.chapter == "1")
+        .unwrap();
+    assert_eq!(chapter.others, vec![french_id, spanish_id]);
+
+    assert_eq!(
+        aggregate.chapter_ids("1"),
+        vec![english_id, french_id, spanish_id]
+    );
+    assert_eq!(aggregate.chapter_ids("2"), vec![next_id]);
+    assert!(aggregate.chapter_ids("3").is_empty());
+}
@@ -0,0 +1,56 @@
+use mangadex_api_schema_rust::v5::Pagination;
+
+fn pagination(limit: u32, offset: u32, total: u32) -> Pagination {
+    Pagination {
+        limit,
+        offset,
+        total,
+    }
+}
+
+#[test]
+fn total_pages_of_an_exact_multiple() {
+    assert_eq!(pagination(10, 0, 30).total_pages(), 3);
+}
+
+#[test]
+fn total_pages_counts_the_remainder_as_a_page() {
+    assert_eq!(pagination(10, 0, 31).total_pages(), 4);
+    assert_eq!(pagination(10, 0, 9).total_pages(), 1);
+}
+
+#[test]
+fn total_pages_of_an_empty_collection() {
+    assert_eq!(pagination(10, 0, 0).total_pages(), 0);
+}
+
+#[test]
+fn current_page_is_one_based() {
+    assert_eq!(pagination(10, 0, 31).current_page(), 1);
+    assert_eq!(pagination(10, 10, 31).current_page(), 2);
+    assert_eq!(pagination(10, 30, 31).current_page(), 4);
+}
+
+#[test]
+fn current_page_of_an_offset_inside_a_page() {
+    assert_eq!(pagination(10, 15, 31).current_page(), 2);
+}
+
+#[test]
+fn zero_limit_does_not_panic() {
+    let pagination = pagination(0, 20, 31);
+
+    assert_eq!(pagination.total_pages(), 0);
+    assert_eq!(pagination.current_page(), 0);
+}
+
+#[test]
+fn was_clamped_when_the_applied_limit_is_lower() {
+    assert!(pagination(100, 0, 500).was_clamped(200));
+}
+
+#[test]
+fn was_not_clamped_when_the_requested_limit_was_applied() {
+    assert!(!pagination(100, 0, 500).was_clamped(100));
+    assert!(!pagination(10, 0, 5).was_clamped(10));
+}
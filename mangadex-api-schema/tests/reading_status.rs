@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use mangadex_api_schema_rust::v5::ReadingStatusDiff;
+use mangadex_api_types::ReadingStatus;
+use uuid::Uuid;
+
+#[test]
+fn reading_status_diff_of_identical_maps_is_empty() {
+    let statuses = HashMap::from([
+        (Uuid::new_v4(), ReadingStatus::Reading),
+        (Uuid::new_v4(), ReadingStatus::Completed),
+    ]);
+
+    let diff = ReadingStatusDiff::compute(&statuses, &statuses);
+
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn reading_status_diff_adds_local_only_statuses() {
+    let manga_id = Uuid::new_v4();
+    let local = HashMap::from([(manga_id, ReadingStatus::PlanToRead)]);
+
+    let diff = ReadingStatusDiff::compute(&local, &HashMap::new());
+
+    assert_eq!(
+        diff.added,
+        HashMap::from([(manga_id, ReadingStatus::PlanToRead)])
+    );
+    assert!(diff.changed.is_empty());
+    assert!(diff.removed.is_empty());
+}
+
+#[test]
+fn reading_status_diff_changes_to_the_local_status() {
+    let manga_id = Uuid::new_v4();
+    let local = HashMap::from([(manga_id, ReadingStatus::Completed)]);
+    let remote = HashMap::from([(manga_id, ReadingStatus::Reading)]);
+
+    let diff = ReadingStatusDiff::compute(&local, &remote);
+
+    assert!(diff.added.is_empty());
+    assert_eq!(
+        diff.changed,
+        HashMap::from([(manga_id, ReadingStatus::Completed)])
+    );
+    assert!(diff.removed.is_empty());
+}
+
+#[test]
+fn reading_status_diff_removes_remote_only_statuses() {
+    let kept_id = Uuid::new_v4();
+    let removed_id = Uuid::new_v4();
+    let local = HashMap::from([(kept_id, ReadingStatus::Dropped)]);
+    let remote = HashMap::from([
+        (kept_id, ReadingStatus::Dropped),
+        (removed_id, ReadingStatus::OnHold),
+    ]);
+
+    let diff = ReadingStatusDiff::compute(&local, &remote);
+
+    assert!(diff.added.is_empty());
+    assert!(diff.changed.is_empty());
+    assert_eq!(
+        diff.removed,
+        HashMap::from([(removed_id, ReadingStatus::OnHold)])
+    );
+}
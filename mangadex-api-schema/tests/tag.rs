@@ -0,0 +1,46 @@
+use mangadex_api_schema_rust::v5::TagData;
+use mangadex_api_types::{Language, TagGroup};
+
+#[test]
+fn tag_data_deserializes_group_and_localized_names() {
+    let tag: TagData = serde_json::from_value(serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "391b0423-d847-456f-aff0-8b0cfc03066b",
+            "type": "tag",
+            "attributes": {
+                "name": {
+                    "en": "Action",
+                    "ja": "アクション",
+                    "fr": "Action"
+                },
+                "description": [],
+                "group": "genre",
+                "version": 1
+            },
+            "relationships": []
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(tag.group(), TagGroup::Genre);
+    assert_eq!(tag.data.attributes.version, 1);
+    assert_eq!(tag.data.attributes.name.len(), 3);
+    assert_eq!(
+        tag.data
+            .attributes
+            .name
+            .get(&Language::Japanese)
+            .map(String::as_str),
+        Some("アクション")
+    );
+    assert_eq!(
+        tag.data
+            .attributes
+            .name
+            .get_or_fallback(&[Language::French]),
+        Some("Action")
+    );
+    assert!(tag.data.attributes.description.is_empty());
+}
@@ -1,4 +1,8 @@
-use mangadex_api_schema_rust::v5::UserData;
+use mangadex_api_schema_rust::v5::{
+    CollectionResponse, Pagination, UserCollection, UserData, UserListResponse,
+};
+use mangadex_api_schema_rust::{ApiResult, FromResponse};
+use mangadex_api_types::UserRole;
 use std::{fs::File, io::{Write, Read}};
 
 use reqwest::Client;
@@ -43,3 +47,122 @@ async fn test_des_and_ser(){
     let mangadata : UserData = serde_json::from_str(file1_data.as_str()).unwrap();
     assert_eq!(serde_json::to_string(&mangadata).unwrap(), file1_data);
 }
+
+#[test]
+fn user_list_response_pagination() {
+    let response_body = serde_json::json!({
+        "result": "ok",
+        "response": "collection",
+        "data": [
+            {
+                "id": "59333f44-7b8a-48b2-8c98-90593c2d781d",
+                "type": "user",
+                "attributes": {
+                    "username": "myusername",
+                    "roles": ["ROLE_MEMBER", "ROLE_GROUP_LEADER"],
+                    "version": 1
+                },
+                "relationships": []
+            }
+        ],
+        "limit": 1,
+        "offset": 10,
+        "total": 42
+    });
+    let res: ApiResult<UserCollection> = serde_json::from_value(response_body).unwrap();
+    let res: UserListResponse = FromResponse::from_response(res);
+    let users = res.unwrap();
+
+    assert_eq!(
+        users.pagination(),
+        Pagination {
+            limit: 1,
+            offset: 10,
+            total: 42
+        }
+    );
+    assert_eq!(users.data().len(), 1);
+    assert_eq!(users.data()[0].attributes.username, "myusername");
+}
+
+#[test]
+fn user_roles_round_trip() {
+    let response_body = serde_json::json!({
+        "result": "ok",
+        "response": "entity",
+        "data": {
+            "id": "59333f44-7b8a-48b2-8c98-90593c2d781d",
+            "type": "user",
+            "attributes": {
+                "username": "myusername",
+                "roles": ["ROLE_MEMBER", "ROLE_GROUP_LEADER", "ROLE_SOMETHING_NEW"],
+                "version": 1
+            },
+            "relationships": []
+        }
+    });
+    let user: UserData = serde_json::from_value(response_body.clone()).unwrap();
+
+    assert_eq!(
+        user.data.attributes.roles,
+        vec![
+            UserRole::RoleMember,
+            UserRole::RoleGroupLeader,
+            UserRole::Unknown("ROLE_SOMETHING_NEW".to_string())
+        ]
+    );
+    assert!(user.has_role(UserRole::RoleGroupLeader));
+    assert!(!user.has_role(UserRole::RoleAdmin));
+    assert_eq!(
+        serde_json::to_value(&user).unwrap()["data"]["attributes"]["roles"],
+        response_body["data"]["attributes"]["roles"]
+    );
+}
+
+#[test]
+fn user_list_response_iterates_over_the_users() {
+    let response_body = serde_json::json!({
+        "result": "ok",
+        "response": "collection",
+        "data": [
+            {
+                "id": "59333f44-7b8a-48b2-8c98-90593c2d781d",
+                "type": "user",
+                "attributes": {
+                    "username": "first",
+                    "roles": ["ROLE_MEMBER"],
+                    "version": 1
+                },
+                "relationships": []
+            },
+            {
+                "id": "2fad5b2e-3d1f-4c7a-9a1e-7d9b1c0f6a42",
+                "type": "user",
+                "attributes": {
+                    "username": "second",
+                    "roles": ["ROLE_MEMBER"],
+                    "version": 1
+                },
+                "relationships": []
+            }
+        ],
+        "limit": 2,
+        "offset": 0,
+        "total": 2
+    });
+    let res: ApiResult<UserCollection> = serde_json::from_value(response_body).unwrap();
+    let res: UserListResponse = FromResponse::from_response(res);
+    let users = res.unwrap();
+
+    let mut borrowed = Vec::new();
+    for user in &users {
+        borrowed.push(user.attributes.username.as_str());
+    }
+    assert_eq!(borrowed, vec!["first", "second"]);
+
+    let mut owned = Vec::new();
+    for user in users {
+        owned.push(user.attributes.username);
+    }
+    assert_eq!(owned, vec!["first".to_string(), "second".to_string()]);
+}
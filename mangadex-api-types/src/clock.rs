@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::MangaDexDateTime;
+
+/// Source of the current time for helpers that compare datetimes against "now".
+///
+/// Use [`SystemClock`] in applications and [`FixedClock`] for deterministic tests.
+/// A `MangaDexDateTime` is also a clock that is always at that instant.
+pub trait Clock {
+    /// Get the current time.
+    fn now(&self) -> MangaDexDateTime;
+}
+
+/// Clock that can be kept by a helper, such as one running in a background task.
+pub type SharedClock = Arc<dyn Clock + Send + Sync>;
+
+/// Clock that reads the system time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> MangaDexDateTime {
+        MangaDexDateTime::new(&OffsetDateTime::now_utc())
+    }
+}
+
+/// Clock that is stopped at a given time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedClock(pub MangaDexDateTime);
+
+impl FixedClock {
+    /// Create a clock stopped at `now`.
+    pub fn new(now: MangaDexDateTime) -> Self {
+        Self(now)
+    }
+
+    /// Move the clock to another time.
+    pub fn set(&mut self, now: MangaDexDateTime) {
+        self.0 = now;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> MangaDexDateTime {
+        self.0.clone()
+    }
+}
+
+impl Clock for MangaDexDateTime {
+    fn now(&self) -> MangaDexDateTime {
+        self.clone()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> MangaDexDateTime {
+        (**self).now()
+    }
+}
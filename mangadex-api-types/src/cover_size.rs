@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// Size of the cover art images served by the MangaDex CDN.
+///
+/// <https://api.mangadex.org/docs/retrieving-covers/>
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum CoverSize {
+    /// Original upload size.
+    #[default]
+    Original,
+    /// Thumbnail 256 pixels wide.
+    Small,
+    /// Thumbnail 512 pixels wide.
+    Medium,
+}
+
+impl CoverSize {
+    /// Get the suffix appended to the cover filename for this size.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::Original => "",
+            Self::Small => ".256.jpg",
+            Self::Medium => ".512.jpg",
+        }
+    }
+}
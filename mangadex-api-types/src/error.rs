@@ -2,6 +2,8 @@ use std::cell::{BorrowError, BorrowMutError};
 
 use schema::MangaDexErrorResponse;
 
+use crate::ResultType;
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Debug, thiserror::Error)]
@@ -18,9 +20,10 @@ pub enum Error {
     #[error("failed to send a request to MangaDex: {0:?}")]
     RequestError(#[from] reqwest::Error),
 
-    /// Error when building the request.
-    #[error("failed to build the request: {0:?}")]
-    BuilderError(#[from] derive_builder::UninitializedFieldError),
+    /// Error when building the request, either because a required field was not set
+    /// or because a field did not pass validation.
+    #[error("failed to build the request: `{field}` {message}")]
+    BuilderError { field: String, message: String },
 
     #[error("missing auth tokens; please log in to MangaDex")]
     MissingTokens,
@@ -34,10 +37,25 @@ pub enum Error {
     #[error("an error occurred while pinging the MangaDex server")]
     PingError,
 
+    /// The request was aborted because its cancellation token was cancelled.
+    #[error("the request was cancelled")]
+    Cancelled,
+
     /// Errors returned from the MangaDex API request.
     #[error("an error occurred with the MangaDex API request: {0:?}")]
     Api(#[from] MangaDexErrorResponse),
 
+    /// The requested resource doesn't exist.
+    ///
+    /// This replaces the `404 Not Found` [`Error::Api`] of the endpoints getting a single
+    /// resource by its ID.
+    #[error("{resource} not found{}", id.map(|id| format!(": {id}")).unwrap_or_default())]
+    NotFound {
+        /// Type of the resource, such as `manga`.
+        resource: String,
+        id: Option<uuid::Uuid>,
+    },
+
     /// Error while building the request struct.
     #[error("failed to build a request: {0}")]
     RequestBuilderError(String),
@@ -45,6 +63,28 @@ pub enum Error {
     /// Error while parsing the type.
     #[error("an error occurred while parsing the type: {0}")]
     ParseError(String),
+
+    /// The response body couldn't be deserialized.
+    #[error("failed to deserialize the response body: {0}")]
+    DeserializeError(String),
+
+    /// The response body couldn't be deserialized because its `result` isn't the one the
+    /// response type expects, such as an error envelope parsed as a successful response.
+    #[error("expected a `{expected:?}` response but got `{actual:?}`: {body}")]
+    DeserializeUnexpectedResultType {
+        expected: ResultType,
+        actual: ResultType,
+        /// Raw response body.
+        body: String,
+    },
+
+    /// A value is outside of the range accepted by MangaDex.
+    #[error("value out of range: {0}")]
+    OutOfRange(String),
+
+    /// The request parameters conflict with each other.
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
     
     #[error("an error occurred when borrowing the http client")]
     BorrowError(#[from] BorrowError),
@@ -70,14 +110,20 @@ impl serde::Serialize for Error{
             Error::ParseUrlError(e) => serializer.serialize_str(e.to_string().as_str()),
             Error::ServerError(port, host) => serializer.serialize_str(format!("there was an error from the MangaDex servers (HTTP {host}): {port}").as_str()),
             Error::RequestError(e) => serializer.serialize_str(e.to_string().as_str()),
-            Error::BuilderError(e) => serializer.serialize_str(e.to_string().as_str()),
+            Error::BuilderError { .. } => serializer.serialize_str(self.to_string().as_str()),
             Error::MissingTokens => serializer.serialize_str("missing auth tokens; please log in to MangaDex"),
             Error::UsernameError(e) => serializer.serialize_str(e.to_string().as_str()),
             Error::PasswordError(e) => serializer.serialize_str(e.to_string().as_str()),
             Error::PingError => todo!(),
+            Error::Cancelled => serializer.serialize_str(self.to_string().as_str()),
             Error::Api(e) => e.serialize(serializer),
+            Error::NotFound { .. } => serializer.serialize_str(self.to_string().as_str()),
             Error::RequestBuilderError(e) => serializer.serialize_str(e.to_string().as_str()),
             Error::ParseError(e) => serializer.serialize_str(e.to_string().as_str()),
+            Error::DeserializeError(e) => serializer.serialize_str(e.to_string().as_str()),
+            Error::DeserializeUnexpectedResultType { .. } => serializer.serialize_str(self.to_string().as_str()),
+            Error::OutOfRange(e) => serializer.serialize_str(e.to_string().as_str()),
+            Error::InvalidRequest(e) => serializer.serialize_str(e.to_string().as_str()),
             Error::BorrowError(e) => serializer.serialize_str(e.to_string().as_str()),
             Error::BorrowMutError(e) => serializer.serialize_str(e.to_string().as_str()),
             Error::Io(e) => serializer.serialize_str(e.to_string().as_str()),
@@ -86,13 +132,46 @@ impl serde::Serialize for Error{
     }
 }
 
+impl Error {
+    /// Get the HTTP status code of the response that caused the error.
+    ///
+    /// This is only available for `Error::Api`, `Error::NotFound` and `Error::ServerError`.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Error::Api(e) => e.status_code,
+            Error::NotFound { .. } => Some(404),
+            Error::ServerError(status_code, _) => Some(*status_code),
+            _ => None,
+        }
+    }
+
+    /// Check if the error is a `409 Conflict` response from MangaDex.
+    ///
+    /// This is returned by the update endpoints when the `version` sent isn't the current one.
+    pub fn is_conflict(&self) -> bool {
+        match self {
+            Error::Api(e) => e.status_code == Some(409) || e.errors.iter().any(|e| e.status == 409),
+            _ => false,
+        }
+    }
+}
+
+impl From<derive_builder::UninitializedFieldError> for Error {
+    fn from(e: derive_builder::UninitializedFieldError) -> Self {
+        Self::BuilderError {
+            field: e.field_name().to_string(),
+            message: "must be initialized".to_string(),
+        }
+    }
+}
+
 #[cfg(feature = "specta")]
 impl specta::Type for Error{}
 
 pub mod schema {
     use std::collections::HashMap;
 
-    use serde::{Deserialize, Serialize};
+    use serde::{Deserialize, Deserializer, Serialize};
     use uuid::Uuid;
 
     #[derive(Debug, thiserror::Error, Deserialize, Serialize)]
@@ -101,9 +180,15 @@ pub mod schema {
     pub struct MangaDexErrorResponse {
         #[serde(default)]
         pub errors: Vec<MangaDexError>,
+        /// HTTP status code of the response.
+        ///
+        /// This is set by the client and can differ from the `status` of the individual errors.
+        #[serde(skip)]
+        pub status_code: Option<u16>,
     }
 
-    #[derive(Debug, thiserror::Error, PartialEq, Eq, Deserialize, Clone, Serialize)]
+    #[derive(Debug, thiserror::Error, Eq, Deserialize, Clone, Serialize)]
+    #[serde(try_from = "serde_json::Value")]
     #[error("API error")]
     #[cfg_attr(feature = "specta", derive(specta::Type))]
     pub struct MangaDexError {
@@ -145,8 +230,197 @@ pub mod schema {
         /// both by client ip and logged in user id. If you are logged in, you want to send the session
         /// token along, so you validate the captcha for your client ip and user id at the same time,
         /// but it is not required.
+        ///
+        /// Only the string values are kept here, the others can be read from [`raw()`](Self::raw).
         // TODO: Use enum representations once the structure of this field is known.
         // See: https://serde.rs/enum-representations.html
         pub context: Option<HashMap<String, String>>,
+        #[serde(skip_serializing)]
+        raw: serde_json::Value,
+    }
+
+    impl PartialEq for MangaDexError {
+        /// Compare the errors without the [`raw()`](Self::raw) error object.
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+                && self.status == other.status
+                && self.title == other.title
+                && self.detail == other.detail
+                && self.context == other.context
+        }
+    }
+
+    impl MangaDexError {
+        /// Get the error object as it was returned by MangaDex.
+        ///
+        /// This keeps the fields that aren't mapped to the struct, such as `context` values that
+        /// aren't strings.
+        pub fn raw(&self) -> &serde_json::Value {
+            &self.raw
+        }
+
+        /// Get the request field the error is about, if any.
+        ///
+        /// This is read from the `field` key of the `context` first, then from the `detail` of
+        /// validation errors, e.g. `Error validating /title: ...` gives `title`. Nested fields are
+        /// given as a JSON pointer without the leading slash, such as `links/al`.
+        pub fn field_hint(&self) -> Option<&str> {
+            let from_context = self
+                .raw
+                .get("context")
+                .and_then(|context| context.get("field"))
+                .and_then(serde_json::Value::as_str);
+            let from_detail = || {
+                let (field, _) = self
+                    .detail
+                    .as_deref()?
+                    .strip_prefix("Error validating ")?
+                    .split_once(':')?;
+                Some(field)
+            };
+
+            from_context
+                .or_else(from_detail)
+                .map(|field| field.trim().trim_start_matches('/'))
+                .filter(|field| !field.is_empty())
+        }
+    }
+
+    /// Fields of [`MangaDexError`] read from the error object.
+    #[derive(Deserialize)]
+    struct MangaDexErrorFields {
+        id: Uuid,
+        status: u16,
+        title: Option<String>,
+        detail: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_string_entries")]
+        context: Option<HashMap<String, String>>,
+    }
+
+    /// Keep the string values of an object, or give `None` if it isn't an object.
+    fn deserialize_string_entries<'de, D>(
+        deserializer: D,
+    ) -> Result<Option<HashMap<String, String>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entries = match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Object(entries) => entries,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(
+            entries
+                .into_iter()
+                .filter_map(|(key, value)| match value {
+                    serde_json::Value::String(value) => Some((key, value)),
+                    _ => None,
+                })
+                .collect(),
+        ))
+    }
+
+    impl TryFrom<serde_json::Value> for MangaDexError {
+        type Error = serde_json::Error;
+
+        fn try_from(raw: serde_json::Value) -> Result<Self, Self::Error> {
+            let fields = MangaDexErrorFields::deserialize(&raw)?;
+
+            Ok(Self {
+                id: fields.id,
+                status: fields.status,
+                title: fields.title,
+                detail: fields.detail,
+                context: fields.context,
+                raw,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::schema::MangaDexErrorResponse;
+
+    #[test]
+    fn validation_errors_give_the_field_they_are_about() {
+        let error_response: MangaDexErrorResponse = serde_json::from_value(json!({
+            "result": "error",
+            "errors": [
+                {
+                    "id": "3d0e3c3a-4a4b-4b4c-8d8e-9f9a0b1c2d3e",
+                    "status": 400,
+                    "title": "Validation exception",
+                    "detail": "Error validating /title: String value found, but an object is required",
+                    "context": null
+                },
+                {
+                    "id": "4e1f4d4b-5b5c-4c5d-9e9f-0a0b1c2d3e4f",
+                    "status": 400,
+                    "title": "Validation exception",
+                    "detail": "Error validating /links/al: Must be a string",
+                    "context": null
+                },
+                {
+                    "id": "5f2a5e5c-6c6d-4d6e-8f0a-1b1c2d3e4f5a",
+                    "status": 400,
+                    "title": "Validation exception",
+                    "detail": "The value is invalid",
+                    "context": {"field": "/originalLanguage"}
+                },
+                {
+                    "id": "6a3b6f6d-7d7e-4e7f-9a1b-2c2d3e4f5a6b",
+                    "status": 400,
+                    "title": "Bad request",
+                    "detail": "Something went wrong",
+                    "context": null,
+                    "extra": 1
+                },
+                {
+                    "id": "7b4c7a7e-8e8f-4f8a-8b2c-3d3e4f5a6b7c",
+                    "status": 400,
+                    "title": "Validation exception",
+                    "detail": "The value is invalid",
+                    "context": {"field": "/year", "min": 1, "max": null, "allowed": [1, 2]}
+                },
+                {
+                    "id": "8c5d8b8f-9f9a-4a9b-9c3d-4e4f5a6b7c8d",
+                    "status": 400,
+                    "title": "Validation exception",
+                    "detail": "Error validating /status: Must be a string",
+                    "context": 1
+                }
+            ]
+        }))
+        .unwrap();
+
+        let hints: Vec<_> = error_response
+            .errors
+            .iter()
+            .map(|error| error.field_hint())
+            .collect();
+
+        assert_eq!(
+            hints,
+            vec![
+                Some("title"),
+                Some("links/al"),
+                Some("originalLanguage"),
+                None,
+                Some("year"),
+                Some("status")
+            ]
+        );
+        assert_eq!(error_response.errors[3].raw()["extra"], json!(1));
+        assert_eq!(
+            error_response.errors[4].context,
+            Some(HashMap::from([("field".to_string(), "/year".to_string())]))
+        );
+        assert_eq!(error_response.errors[4].raw()["context"]["min"], json!(1));
+        assert_eq!(error_response.errors[5].context, None);
     }
 }
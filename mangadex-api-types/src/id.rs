@@ -0,0 +1,131 @@
+//! Typed IDs, so that the ID of one kind of entity can't be passed where another is expected.
+//!
+//! They convert from and into [`Uuid`], so they can be given to the request builders directly,
+//! and they (de)serialize as bare UUIDs.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::Error;
+
+macro_rules! ids {
+    (
+        $(
+            $( #[$meta:meta] )*
+            $name:ident => $entity:literal,
+        )*
+    ) => {
+        $(
+            $( #[$meta] )*
+            #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+            #[serde(transparent)]
+            #[cfg_attr(feature = "specta", derive(specta::Type))]
+            pub struct $name(pub Uuid);
+
+            impl $name {
+                /// Get the inner UUID.
+                pub fn as_uuid(&self) -> &Uuid {
+                    &self.0
+                }
+            }
+
+            impl From<Uuid> for $name {
+                fn from(id: Uuid) -> Self {
+                    Self(id)
+                }
+            }
+
+            impl From<$name> for Uuid {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+
+            impl fmt::Display for $name {
+                /// Write the UUID in its hyphenated form.
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0.hyphenated(), f)
+                }
+            }
+
+            impl FromStr for $name {
+                type Err = Error;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    Uuid::parse_str(value).map(Self).map_err(|e| {
+                        Error::ParseError(format!(concat!("invalid ", $entity, " ID {:?}: {}"), value, e))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+ids! {
+    /// ID of a manga.
+    MangaId => "manga",
+    /// ID of a chapter.
+    ChapterId => "chapter",
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uuid::Uuid;
+
+    use super::{ChapterId, MangaId};
+    use crate::error::Error;
+
+    const ID: &str = "f9c33607-9180-4ba6-b85c-e4b5faee7192";
+
+    #[test]
+    fn id_round_trips_through_from_str_and_display() {
+        let manga_id = MangaId::from_str(ID).unwrap();
+
+        assert_eq!(manga_id.0, Uuid::parse_str(ID).unwrap());
+        assert_eq!(manga_id.to_string(), ID);
+
+        let chapter_id: ChapterId = ID.parse().unwrap();
+
+        assert_eq!(
+            chapter_id.to_string().parse::<ChapterId>().unwrap(),
+            chapter_id
+        );
+    }
+
+    #[test]
+    fn id_displays_the_hyphenated_form() {
+        let manga_id = MangaId::from_str("F9C3360791804BA6B85CE4B5FAEE7192").unwrap();
+
+        assert_eq!(manga_id.to_string(), ID);
+    }
+
+    #[test]
+    fn invalid_id_is_a_parse_error() {
+        let res = MangaId::from_str("not-a-uuid");
+
+        assert!(matches!(res, Err(Error::ParseError(message)) if message.contains("manga")));
+    }
+
+    #[test]
+    fn id_serializes_as_a_bare_uuid() {
+        let chapter_id = ChapterId::from_str(ID).unwrap();
+
+        let json = serde_json::to_string(&chapter_id).unwrap();
+        assert_eq!(json, format!("\"{ID}\""));
+
+        let deserialized: ChapterId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, chapter_id);
+    }
+
+    #[test]
+    fn id_converts_into_a_uuid() {
+        let uuid = Uuid::parse_str(ID).unwrap();
+
+        assert_eq!(Uuid::from(MangaId::from(uuid)), uuid);
+    }
+}
@@ -1,18 +1,6 @@
-use serde::{Deserialize, Serialize};
+use crate::include_flag::include_flag;
 
-/// Flag to include future updates in the results.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, Hash, PartialEq, Eq)]
-#[cfg_attr(feature = "specta", derive(specta::Type))]
-pub enum IncludeExternalUrl {
-    Include = 0,
-    Exclude = 1,
-}
-
-impl std::fmt::Display for IncludeExternalUrl {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        fmt.write_str(match self {
-            Self::Include => "Include",
-            Self::Exclude => "Exclude",
-        })
-    }
+include_flag! {
+    /// Flag to include chapters that link to an external site in the results.
+    IncludeExternalUrl
 }
@@ -0,0 +1,64 @@
+/// Generate a flag enum that MangaDex expects as `1` (include) or `0` (exclude).
+///
+/// The enum can be created from a `bool` and is (de)serialized as the number.
+macro_rules! include_flag {
+    (
+        $(#[$meta:meta])*
+        $name:ident
+    ) => {
+        $(#[$meta])*
+        ///
+        /// MangaDex expects the flag as `1` (include) or `0` (exclude).
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+        #[cfg_attr(feature = "specta", derive(specta::Type))]
+        pub enum $name {
+            Include = 1,
+            Exclude = 0,
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+                fmt.write_str(match self {
+                    Self::Include => "Include",
+                    Self::Exclude => "Exclude",
+                })
+            }
+        }
+
+        impl From<bool> for $name {
+            fn from(include: bool) -> Self {
+                if include {
+                    Self::Include
+                } else {
+                    Self::Exclude
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_u8(*self as u8)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                match <u8 as serde::Deserialize>::deserialize(deserializer)? {
+                    0 => Ok(Self::Exclude),
+                    1 => Ok(Self::Include),
+                    other => Err(serde::de::Error::custom(format!(
+                        "expected 0 or 1, got {other}"
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use include_flag;
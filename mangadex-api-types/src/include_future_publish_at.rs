@@ -1,18 +1,6 @@
-use serde::{Deserialize, Serialize};
+use crate::include_flag::include_flag;
 
-/// Flag to include future updates in the results.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, Hash, PartialEq, Eq)]
-#[cfg_attr(feature = "specta", derive(specta::Type))]
-pub enum IncludeFuturePublishAt {
-    Include = 0,
-    Exclude = 1,
-}
-
-impl std::fmt::Display for IncludeFuturePublishAt {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        fmt.write_str(match self {
-            Self::Include => "Include",
-            Self::Exclude => "Exclude",
-        })
-    }
+include_flag! {
+    /// Flag to include chapters with a future publish date in the results.
+    IncludeFuturePublishAt
 }
@@ -1,18 +1,29 @@
-use serde::{Deserialize, Serialize};
+use crate::include_flag::include_flag;
 
-/// Flag to include future updates in the results.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, Hash, PartialEq, Eq)]
-#[cfg_attr(feature = "specta", derive(specta::Type))]
-pub enum IncludeFutureUpdates {
-    Include = 0,
-    Exclude = 1,
+include_flag! {
+    /// Flag to include future updates in the results.
+    IncludeFutureUpdates
 }
 
-impl std::fmt::Display for IncludeFutureUpdates {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        fmt.write_str(match self {
-            Self::Include => "Include",
-            Self::Exclude => "Exclude",
-        })
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn include_future_updates_round_trips_as_a_number() {
+        assert_eq!(
+            serde_json::to_value(IncludeFutureUpdates::Include).unwrap(),
+            json!(1)
+        );
+        assert_eq!(
+            serde_json::to_value(IncludeFutureUpdates::Exclude).unwrap(),
+            json!(0)
+        );
+
+        let flag: IncludeFutureUpdates = serde_json::from_value(json!(1)).unwrap();
+        assert_eq!(flag, IncludeFutureUpdates::Include);
+        assert!(serde_json::from_value::<IncludeFutureUpdates>(json!(2)).is_err());
     }
 }
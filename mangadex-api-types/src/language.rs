@@ -3,6 +3,8 @@ use std::string::ParseError;
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
+
 macro_rules! languages {
     (
         $(
@@ -133,6 +135,24 @@ languages! {
     Vietnamese => "vi",
 }
 
+impl Language {
+    /// Check that none of the languages is [`Language::Unknown`], which is what unknown language
+    /// codes are converted to.
+    ///
+    /// The request builders filtering by language use this so that a typo in a language code
+    /// fails the build instead of silently matching nothing. The error names `field`.
+    pub fn validate_known(field: &str, languages: &[Language]) -> Result<()> {
+        if languages.contains(&Language::Unknown) {
+            return Err(Error::BuilderError {
+                field: field.to_string(),
+                message: "contains an unknown language code".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +177,13 @@ mod tests {
             assert_eq!(lang, Language::Unknown);
         }
     }
+
+    #[test]
+    fn validate_known_rejects_unknown_languages() {
+        assert!(Language::validate_known("languages", &[Language::English]).is_ok());
+        assert!(matches!(
+            Language::validate_known("languages", &[Language::English, Language::from("foo")]),
+            Err(Error::BuilderError { field, .. }) if field == "languages"
+        ));
+    }
 }
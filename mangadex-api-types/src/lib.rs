@@ -1,4 +1,8 @@
+pub mod clock;
+pub mod cover_size;
 pub mod error;
+pub mod id;
+mod include_flag;
 pub mod include_future_updates;
 pub mod language;
 pub mod legacy_mapping_type;
@@ -8,6 +12,7 @@ pub mod mangadex_datetime;
 pub mod mangadex_duration;
 pub mod order_direction;
 pub mod password;
+pub mod quality;
 pub mod report_category;
 pub mod report_status;
 pub mod sort_order;
@@ -22,6 +27,9 @@ pub mod include_future_publish_at;
 pub mod include_external_url;
 pub mod result;
 
+pub use clock::{Clock, FixedClock, SharedClock, SystemClock};
+pub use cover_size::CoverSize;
+pub use id::{ChapterId, MangaId};
 pub use include_future_updates::IncludeFutureUpdates;
 pub use language::Language;
 pub use legacy_mapping_type::LegacyMappingType;
@@ -31,6 +39,7 @@ pub use mangadex_datetime::MangaDexDateTime;
 pub use mangadex_duration::MangaDexDuration;
 pub use order_direction::OrderDirection;
 pub use password::Password;
+pub use quality::Quality;
 pub use report_category::ReportCategory;
 pub use report_status::ReportStatus;
 pub use sort_order::*;
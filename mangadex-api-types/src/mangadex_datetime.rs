@@ -1,23 +1,70 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use time::{format_description, OffsetDateTime};
+use time::format_description::well_known::Rfc3339;
+use time::{format_description, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::error::{Error, Result};
 
 pub(crate) const MANGADEX_DATETIME_FORMAT: &str =
     "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]";
 
+/// Format of the datetime query parameters, such as `createdAtSince`, which don't accept an offset.
+pub(crate) const MANGADEX_QUERY_DATETIME_FORMAT: &str =
+    "[year]-[month]-[day]T[hour]:[minute]:[second]";
+
 /// Newtype struct for handling datetime fields in MangaDex.
+///
+/// The datetime is always normalized to UTC.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct MangaDexDateTime(OffsetDateTime);
 
 impl MangaDexDateTime {
     pub fn new(datetime: &OffsetDateTime) -> Self {
-        Self(*datetime)
+        Self(datetime.to_offset(UtcOffset::UTC))
+    }
+
+    /// Format the datetime in the `YYYY-MM-DDTHH:MM:SS+00:00` form used by the MangaDex API.
+    ///
+    /// Sub-second precision is dropped as MangaDex doesn't accept it in request bodies.
+    pub fn to_rfc3339(&self) -> String {
+        self.to_string()
+    }
+
+    /// Format the datetime in the `YYYY-MM-DDTHH:MM:SS` form, in UTC, required by the MangaDex
+    /// query parameters such as `createdAtSince`.
+    pub fn to_query_string(&self) -> Result<String> {
+        let format = format_description::parse(MANGADEX_QUERY_DATETIME_FORMAT)
+            .map_err(|e| Error::ParseError(e.to_string()))?;
+
+        self.as_ref()
+            .format(&format)
+            .map_err(|e| Error::ParseError(e.to_string()))
+    }
+}
+
+/// Serialize an optional datetime with [`MangaDexDateTime::to_query_string()`].
+///
+/// This is meant for `#[serde(serialize_with = "...")]` on query parameters that reject an offset.
+pub fn serialize_query_datetime<S>(
+    datetime: &Option<MangaDexDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match datetime {
+        Some(datetime) => serializer.serialize_str(
+            &datetime
+                .to_query_string()
+                .map_err(serde::ser::Error::custom)?,
+        ),
+        None => serializer.serialize_none(),
     }
 }
 
 impl From<OffsetDateTime> for MangaDexDateTime {
     fn from(datetime: OffsetDateTime) -> Self {
-        Self(datetime)
+        Self::new(&datetime)
     }
 }
 
@@ -47,9 +94,19 @@ impl<'de> Deserialize<'de> for MangaDexDateTime {
 
         let format = format_description::parse(MANGADEX_DATETIME_FORMAT).unwrap();
 
-        let datetime = OffsetDateTime::parse(&s, &format).unwrap();
+        let query_format = format_description::parse(MANGADEX_QUERY_DATETIME_FORMAT).unwrap();
+
+        // MangaDex mostly sends `+00:00` offsets but other RFC3339 forms, such as `Z` or
+        // sub-second precision, should also be accepted, as well as the query form without an
+        // offset, which is in UTC.
+        let datetime = OffsetDateTime::parse(&s, &format)
+            .or_else(|_| OffsetDateTime::parse(&s, &Rfc3339))
+            .or_else(|_| {
+                PrimitiveDateTime::parse(&s, &query_format).map(PrimitiveDateTime::assume_utc)
+            })
+            .map_err(serde::de::Error::custom)?;
 
-        Ok(MangaDexDateTime(datetime))
+        Ok(MangaDexDateTime::new(&datetime))
     }
 }
 
@@ -60,3 +117,71 @@ impl std::fmt::Display for MangaDexDateTime {
         fmt.write_str(&self.as_ref().format(&format).unwrap())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_explicit_offset() {
+        let datetime: MangaDexDateTime =
+            serde_json::from_value(json!("2021-04-19T21:45:59+00:00")).unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2021-04-19T21:45:59+00:00");
+        assert_eq!(
+            serde_json::to_value(&datetime).unwrap(),
+            json!("2021-04-19T21:45:59+00:00")
+        );
+    }
+
+    #[test]
+    fn deserialize_zulu_offset() {
+        let datetime: MangaDexDateTime =
+            serde_json::from_value(json!("2021-04-19T21:45:59Z")).unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2021-04-19T21:45:59+00:00");
+    }
+
+    #[test]
+    fn deserialize_normalizes_to_utc() {
+        let datetime: MangaDexDateTime =
+            serde_json::from_value(json!("2021-04-20T00:45:59+03:00")).unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2021-04-19T21:45:59+00:00");
+        assert_eq!(datetime.as_ref().offset(), UtcOffset::UTC);
+    }
+
+    #[test]
+    fn deserialize_sub_second_precision() {
+        let datetime: MangaDexDateTime =
+            serde_json::from_value(json!("2021-04-19T21:45:59.123456Z")).unwrap();
+
+        assert_eq!(datetime.as_ref().millisecond(), 123);
+        assert_eq!(datetime.to_rfc3339(), "2021-04-19T21:45:59+00:00");
+
+        let round_trip: MangaDexDateTime =
+            serde_json::from_value(serde_json::to_value(&datetime).unwrap()).unwrap();
+        assert_eq!(round_trip.to_rfc3339(), datetime.to_rfc3339());
+    }
+
+    #[test]
+    fn query_string_has_no_offset_or_sub_second_precision() {
+        let datetime: MangaDexDateTime =
+            serde_json::from_value(json!("2021-04-20T00:45:59.123+03:00")).unwrap();
+
+        assert_eq!(datetime.to_query_string().unwrap(), "2021-04-19T21:45:59");
+
+        let round_trip: MangaDexDateTime =
+            serde_json::from_value(json!(datetime.to_query_string().unwrap())).unwrap();
+        assert_eq!(round_trip.to_rfc3339(), "2021-04-19T21:45:59+00:00");
+    }
+
+    #[test]
+    fn deserialize_invalid_datetime_errors() {
+        let res = serde_json::from_value::<MangaDexDateTime>(json!("not a datetime"));
+
+        assert!(res.is_err());
+    }
+}
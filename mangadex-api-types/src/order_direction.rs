@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-/// "Order by" directions for manga results.
+/// "Order by" direction shared by every sort order type, such as [`UserSortOrder`](crate::UserSortOrder).
 #[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum OrderDirection {
@@ -19,3 +19,37 @@ impl std::fmt::Display for OrderDirection {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{MangaSortOrder, UserSortOrder};
+
+    #[test]
+    fn order_direction_round_trips_as_asc_and_desc() {
+        for (direction, value) in [
+            (OrderDirection::Ascending, json!("asc")),
+            (OrderDirection::Descending, json!("desc")),
+        ] {
+            assert_eq!(serde_json::to_value(direction).unwrap(), value);
+            assert_eq!(
+                serde_json::from_value::<OrderDirection>(value).unwrap(),
+                direction
+            );
+        }
+    }
+
+    #[test]
+    fn sort_orders_serialize_the_shared_direction() {
+        assert_eq!(
+            serde_json::to_value(UserSortOrder::Username(OrderDirection::Descending)).unwrap(),
+            json!({"username": "desc"})
+        );
+        assert_eq!(
+            serde_json::to_value(MangaSortOrder::FollowedCount(OrderDirection::Ascending)).unwrap(),
+            json!({"followedCount": "asc"})
+        );
+    }
+}
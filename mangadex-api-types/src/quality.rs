@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// Image quality of the chapter pages served by MangaDex@Home.
+///
+/// <https://api.mangadex.org/docs/retrieving-chapter/>
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum Quality {
+    /// Original upload quality.
+    #[default]
+    #[serde(rename = "data")]
+    Data,
+    /// Compressed quality.
+    #[serde(rename = "data-saver")]
+    DataSaver,
+}
+
+impl Quality {
+    /// Get the path segment used in MangaDex@Home page URLs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Data => "data",
+            Self::DataSaver => "data-saver",
+        }
+    }
+}
+
+impl std::fmt::Display for Quality {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
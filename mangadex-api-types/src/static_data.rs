@@ -10,4 +10,45 @@ pub(crate) mod manga_status;
 pub(crate) mod reading_status;
 pub(crate) mod reference_expansion_resource;
 pub(crate) mod relationship_type;
-pub(crate) mod response_type;
\ No newline at end of file
+pub(crate) mod response_type;
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::content_rating::ContentRating;
+    use super::demographic::Demographic;
+    use super::manga_relation::MangaRelation;
+    use super::manga_status::MangaStatus;
+
+    #[test]
+    fn known_variants_still_deserialize() {
+        assert_eq!(
+            serde_json::from_value::<ContentRating>(json!("suggestive")).unwrap(),
+            ContentRating::Suggestive
+        );
+        assert_eq!(
+            serde_json::from_value::<MangaRelation>(json!("spin_off")).unwrap(),
+            MangaRelation::SpinOff
+        );
+    }
+
+    #[test]
+    fn unknown_variants_deserialize_to_unknown() {
+        assert_eq!(
+            serde_json::from_value::<ContentRating>(json!("brand_new_rating")).unwrap(),
+            ContentRating::Unknown
+        );
+        assert_eq!(
+            serde_json::from_value::<Demographic>(json!("kodomo")).unwrap(),
+            Demographic::Unknown
+        );
+        assert_eq!(
+            serde_json::from_value::<MangaStatus>(json!("abandoned")).unwrap(),
+            MangaStatus::Unknown
+        );
+        assert_eq!(
+            serde_json::from_value::<MangaRelation>(json!("remake")).unwrap(),
+            MangaRelation::Unknown
+        );
+    }
+}
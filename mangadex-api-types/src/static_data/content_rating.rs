@@ -8,6 +8,9 @@ pub enum ContentRating {
     Pornographic,
     Safe,
     Suggestive,
+    /// A value that isn't known by this crate yet.
+    #[serde(other)]
+    Unknown,
 }
 
 impl Default for ContentRating{
@@ -35,6 +38,7 @@ impl std::fmt::Display for ContentRating {
             Self::Pornographic => "Pornographic",
             Self::Safe => "Safe",
             Self::Suggestive => "Suggestive",
+            Self::Unknown => "Unknown",
         })
     }
 }
@@ -10,6 +10,9 @@ pub enum Demographic {
     Seinen,
     Josei,
     None,
+    /// A value that isn't known by this crate yet.
+    #[serde(other)]
+    Unknown,
 }
 
 impl From<String> for Demographic {
@@ -32,6 +35,7 @@ impl std::fmt::Display for Demographic {
             Self::Seinen => "seinen",
             Self::Josei => "josei",
             Self::None => "none",
+            Self::Unknown => "unknown",
         };
         fmt.write_str(name)
     }
@@ -39,6 +39,9 @@ pub enum MangaRelation {
     SideStory,
     /// An official derivative work based on this manga.
     SpinOff,
+    /// A value that isn't known by this crate yet.
+    #[serde(other)]
+    Unknown,
 }
 
 impl Default for MangaRelation{
@@ -66,6 +69,7 @@ impl std::fmt::Display for MangaRelation {
             Self::SharedUniverse => "Shared Universe",
             Self::SideStory => "Side Story",
             Self::SpinOff => "Spin Off",
+            Self::Unknown => "Unknown",
         };
         fmt.write_str(name)
     }
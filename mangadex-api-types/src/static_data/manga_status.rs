@@ -12,6 +12,9 @@ pub enum MangaStatus {
     Hiatus,
     /// Manga has been cancelled.
     Cancelled,
+    /// A value that isn't known by this crate yet.
+    #[serde(other)]
+    Unknown,
 }
 
 impl Default for MangaStatus {
@@ -27,6 +30,7 @@ impl std::fmt::Display for MangaStatus {
             Self::Completed => "Completed",
             Self::Hiatus => "Hiatus",
             Self::Cancelled => "Cancelled",
+            Self::Unknown => "Unknown",
         };
         fmt.write_str(name)
     }
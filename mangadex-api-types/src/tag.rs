@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use crate::error::Error;
 
-#[derive(Debug, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum TagGroup {
@@ -11,6 +11,9 @@ pub enum TagGroup {
     Format,
     Genre,
     Theme,
+    /// A value that isn't known by this crate yet.
+    #[serde(other)]
+    Unknown,
 }
 
 macro_rules! tags {
@@ -224,4 +227,11 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn unknown_tag_group_deserializes_to_unknown() {
+        let group: TagGroup = serde_json::from_value(serde_json::json!("mood")).unwrap();
+
+        assert_eq!(group, TagGroup::Unknown);
+    }
 }
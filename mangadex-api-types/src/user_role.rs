@@ -1,48 +1,144 @@
-use serde::{Deserialize, Serialize};
-
-/// User roles that define what a user has permission to do.
-/// More details at : https://api.mangadex.org/docs/static-data/#user-roles-enum
-#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-#[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
-#[cfg_attr(feature = "specta", derive(specta::Type))]
-pub enum UserRole {
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! user_roles {
+    (
+        $(
+            $( #[$meta:meta] )*
+            $role:ident => $code:literal,
+        )*
+    ) => {
+        /// User roles that define what a user has permission to do.
+        /// More details at : https://api.mangadex.org/docs/static-data/#user-roles-enum
+        #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+        #[cfg_attr(feature = "non_exhaustive", non_exhaustive)]
+        #[cfg_attr(feature = "specta", derive(specta::Type))]
+        pub enum UserRole {
+            $(
+                $( #[$meta] )*
+                $role,
+            )*
+            /// A role that isn't known by this crate yet.
+            ///
+            /// The original `ROLE_*` string is kept so that it can be sent back as-is.
+            Unknown(String),
+        }
+
+        impl UserRole {
+            /// Get the `ROLE_*` string representation used by MangaDex.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(
+                        Self::$role => $code,
+                    )*
+                    Self::Unknown(role) => role.as_str(),
+                }
+            }
+        }
+
+        impl From<&str> for UserRole {
+            /// Parse a `UserRole` type from a `ROLE_*` string.
+            fn from(value: &str) -> Self {
+                match value {
+                    $(
+                        $code => Self::$role,
+                    )*
+                    _ => Self::Unknown(value.to_string()),
+                }
+            }
+        }
+    };
+}
+
+user_roles! {
     /// MangaDex admins
-    RoleAdmin,
+    RoleAdmin => "ROLE_ADMIN",
     /// Banned
-    RoleBanned,
+    RoleBanned => "ROLE_BANNED",
     /// Helpers contributing by filling in missing information (Description, External Links) on Manga pages on MangaDex
-    RoleContributor,
+    RoleContributor => "ROLE_CONTRIBUTOR",
     /// Designer
-    RoleDesigner,
+    RoleDesigner => "ROLE_DESIGNER",
     /// MangaDex site developers
-    RoleDeveloper,
+    RoleDeveloper => "ROLE_DEVELOPER",
     /// Moderates the forum
-    RoleForumModerator,
+    RoleForumModerator => "ROLE_FORUM_MODERATOR",
 
-    RoleGlobalModerator,
+    RoleGlobalModerator => "ROLE_GLOBAL_MODERATOR",
     /// Leaders of active groups on MangaDex
-    RoleGroupLeader,
+    RoleGroupLeader => "ROLE_GROUP_LEADER",
     /// Member of a group
-    RoleGroupMember,
+    RoleGroupMember => "ROLE_GROUP_MEMBER",
     /// Users viewing the site without being logged in
-    RoleGuest,
+    RoleGuest => "ROLE_GUEST",
     /// Member of a group
-    RoleMember,
+    RoleMember => "ROLE_MEMBER",
     /// Involved with the [MangaDex@Home](mailto:MangaDex@Home) project
-    RoleMdAtHome,
+    RoleMdAtHome => "ROLE_MD_AT_HOME",
     /// Uploaded 500 or more chapters to MangaDex
-    RolePowerUploader,
+    RolePowerUploader => "ROLE_POWER_UPLOADER",
     ///Manages social media
-    RolePublicRelations,
+    RolePublicRelations => "ROLE_PUBLIC_RELATIONS",
     /// Staff
-    RoleStaff,
+    RoleStaff => "ROLE_STAFF",
     /// Accounts that haven't had their email address verified yet
-    RoleUnverified,
+    RoleUnverified => "ROLE_UNVERIFIED",
     /// A normal account
-    RoleUser,
+    RoleUser => "ROLE_USER",
     /// Important people that in one way or another helped MangaDex
-    RoleVip,
-    #[serde(other)]
-    Unknown
+    RoleVip => "ROLE_VIP",
+}
+
+impl std::fmt::Display for UserRole {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl Serialize for UserRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserRole {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let role: String = Deserialize::deserialize(deserializer)?;
+
+        Ok(Self::from(role.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_known_role() {
+        let role: UserRole = serde_json::from_value(json!("ROLE_GROUP_LEADER")).unwrap();
+
+        assert_eq!(role, UserRole::RoleGroupLeader);
+        assert_eq!(
+            serde_json::to_value(&role).unwrap(),
+            json!("ROLE_GROUP_LEADER")
+        );
+    }
+
+    #[test]
+    fn deserialize_unknown_role_keeps_the_original_value() {
+        let role: UserRole = serde_json::from_value(json!("ROLE_NEW_THING")).unwrap();
+
+        assert_eq!(role, UserRole::Unknown("ROLE_NEW_THING".to_string()));
+        assert_eq!(
+            serde_json::to_value(&role).unwrap(),
+            json!("ROLE_NEW_THING")
+        );
+    }
 }
@@ -5,4 +5,6 @@ pub const CDN_URL: &str = "https://uploads.mangadex.org";
 /// 
 /// Disclaimer : I don't know if it's good to use api.mangadex.dev 
 /// but i'll put this here if someone is interested
-pub const API_DEV_URL: &str = "https://api.mangadex.dev";
\ No newline at end of file
+pub const API_DEV_URL: &str = "https://api.mangadex.dev";
+/// Maximum number of results `collect_all()` gathers before returning an error.
+pub const MAX_COLLECT_ALL_ITEMS: usize = 10_000;
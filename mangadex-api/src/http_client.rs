@@ -2,18 +2,24 @@
 use std::cell::RefCell;
 #[cfg(not(feature = "multi-thread"))]
 use std::rc::Rc;
-#[cfg(feature = "multi-thread")]
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use bytes::Bytes;
 use derive_builder::Builder;
 #[cfg(feature = "multi-thread")]
 use futures::lock::Mutex;
-use mangadex_api_schema::{Endpoint, FromResponse, UrlSerdeQS};
+use mangadex_api_schema::v5::TagObject;
+use mangadex_api_schema::{ApiResult, Endpoint, FromResponse, UrlSerdeQS};
 use mangadex_api_types::error::Error;
-use reqwest::Client;
+use mangadex_api_types::{ContentRating, ResultType};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 use url::Url;
 
+use crate::json_codec::{DefaultJsonCodec, JsonCodec};
 use crate::v5::AuthTokens;
 use crate::{API_URL, API_DEV_URL};
 use mangadex_api_types::error::Result;
@@ -23,13 +29,382 @@ pub type HttpClientRef = Rc<RefCell<HttpClient>>;
 #[cfg(feature = "multi-thread")]
 pub type HttpClientRef = Arc<Mutex<HttpClient>>;
 
-#[derive(Debug, Builder, Clone)]
-#[builder(setter(into, strip_option), default)]
+/// Parts of an endpoint request that a request hook can inspect and modify.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestParts {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+}
+
+/// Request that an endpoint would send, built by `dry_run()` without sending it.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DryRunResult {
+    pub method: Method,
+    pub url: Url,
+    /// JSON body of the request, if the endpoint has one.
+    pub body: Option<serde_json::Value>,
+}
+
+/// Parts of an endpoint response that a response hook can inspect.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponseParts {
+    pub method: Method,
+    pub url: Url,
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    /// Time between sending the request and receiving the response headers.
+    pub elapsed: Duration,
+}
+
+/// Hook called with every endpoint request right before it is sent.
+///
+/// Hooks are called on the task sending the request, so they must not block.
+pub type RequestHook = Arc<dyn Fn(&mut RequestParts) + Send + Sync>;
+
+/// Hook called with every endpoint response right after it is received, before the body is read.
+///
+/// Hooks are called on the task sending the request, so they must not block.
+pub type ResponseHook = Arc<dyn Fn(&ResponseParts) + Send + Sync>;
+
+/// Predicate deciding whether an endpoint request is sent again, given the request and the
+/// result of the attempt.
+#[cfg(feature = "retry")]
+pub type RetryPredicate = Arc<dyn Fn(&RequestParts, &Result<Response>) -> bool + Send + Sync>;
+
+/// How failed endpoint requests are sent again.
+///
+/// By default, only the idempotent requests (`GET`, `HEAD`, `PUT`, `DELETE` and `OPTIONS`) and the
+/// requests with an `Idempotency-Key` header are retried, when they can't be sent or get a
+/// `429 Too Many Requests` or `5xx` response. Requests with a streamed body, such as file uploads,
+/// are never retried.
+///
+/// The wait before a retry is the `Retry-After` of the response, in seconds, when it has one.
+/// Either way, it is never longer than the maximum backoff.
+#[cfg(feature = "retry")]
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+    predicate: Option<RetryPredicate>,
+}
+
+#[cfg(feature = "retry")]
+impl RetryPolicy {
+    /// Retry up to `max_retries` times with the default rules.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    /// Retry whenever the predicate returns `true`, instead of following the default rules.
+    ///
+    /// The predicate is called after every attempt, so it can retry on any method, path, status
+    /// or error.
+    pub fn with_predicate(predicate: RetryPredicate) -> Self {
+        Self {
+            predicate: Some(predicate),
+            ..Default::default()
+        }
+    }
+
+    /// Set the maximum number of times a request is sent again.
+    ///
+    /// Defaults to 3.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the wait before the first retry, which doubles after every retry.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Set the longest wait before a retry, including waits asked for with `Retry-After`.
+    ///
+    /// Defaults to 60 seconds.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    fn should_retry(&self, parts: &RequestParts, res: &Result<Response>) -> bool {
+        if let Some(predicate) = &self.predicate {
+            return predicate(parts, res);
+        }
+
+        let is_idempotent = matches!(
+            parts.method,
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+        ) || parts.headers.contains_key("Idempotency-Key");
+        let is_transient = match res {
+            Ok(res) => {
+                res.status() == StatusCode::TOO_MANY_REQUESTS || res.status().is_server_error()
+            }
+            Err(Error::RequestError(e)) => e.is_connect() || e.is_timeout(),
+            Err(_) => false,
+        };
+
+        is_idempotent && is_transient
+    }
+}
+
+#[cfg(feature = "retry")]
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(60),
+            predicate: None,
+        }
+    }
+}
+
+#[cfg(feature = "retry")]
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("backoff", &self.backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("predicate", &self.predicate.is_some())
+            .finish()
+    }
+}
+
+#[derive(Builder, Clone)]
+#[builder(
+    setter(into, strip_option),
+    default,
+    build_fn(
+        private,
+        name = "fallible_build",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 pub struct HttpClient {
     pub client: Client,
     pub base_url: Url,
     auth_tokens: Option<AuthTokens>,
     captcha: Option<String>,
+    /// Content rating filter applied to the search and feed endpoints that don't set their own.
+    default_content_rating: Option<Vec<ContentRating>>,
+    /// Hook to add headers, sign or log endpoint requests.
+    on_request: Option<RequestHook>,
+    /// Hook to record metrics or log endpoint responses.
+    on_response: Option<ResponseHook>,
+    /// Tags used instead of requesting `GET /manga/tag`, such as a list bundled with the app.
+    ///
+    /// The catalog is shared, so it can be set on several clients without copying the tags.
+    tag_catalog: Option<Arc<[TagObject]>>,
+    /// How failed endpoint requests are sent again. Requests aren't retried by default.
+    #[cfg(feature = "retry")]
+    retry_policy: Option<RetryPolicy>,
+    /// Settings the reqwest client was built with by the builder helpers.
+    #[builder(private)]
+    client_options: ClientOptions,
+}
+
+impl std::fmt::Debug for HttpClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut f = f.debug_struct("HttpClient");
+        f.field("client", &self.client)
+            .field("base_url", &self.base_url)
+            .field("auth_tokens", &self.auth_tokens)
+            .field("captcha", &self.captcha)
+            .field("default_content_rating", &self.default_content_rating)
+            .field("on_request", &self.on_request.is_some())
+            .field("on_response", &self.on_response.is_some())
+            .field(
+                "tag_catalog",
+                &self.tag_catalog.as_ref().map(|tags| tags.len()),
+            );
+        #[cfg(feature = "retry")]
+        f.field("retry_policy", &self.retry_policy);
+
+        f.finish()
+    }
+}
+
+/// How the reqwest client follows redirects.
+///
+/// The `Authorization` and `Cookie` headers are removed when a redirect goes to another host,
+/// so the session token isn't leaked to an image host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedirectPolicy {
+    /// Return the redirect response instead of following it.
+    None,
+    /// Follow up to this many redirects, then fail the request.
+    Limited(usize),
+}
+
+impl Default for RedirectPolicy {
+    /// Follow up to 10 redirects.
+    fn default() -> Self {
+        Self::Limited(10)
+    }
+}
+
+impl From<RedirectPolicy> for reqwest::redirect::Policy {
+    fn from(policy: RedirectPolicy) -> Self {
+        match policy {
+            RedirectPolicy::None => Self::none(),
+            // reqwest fails once the number of redirects reaches the limit.
+            RedirectPolicy::Limited(max) => Self::limited(max.saturating_add(1)),
+        }
+    }
+}
+
+/// Settings the reqwest client is built with by the [`HttpClientBuilder`] helpers.
+///
+/// The unset options keep the reqwest defaults.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct ClientOptions {
+    redirect_policy: RedirectPolicy,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+    timeout: Option<Duration>,
+    user_agent: Option<HeaderValue>,
+}
+
+impl ClientOptions {
+    fn build_client(&self) -> Result<Client> {
+        let mut builder = Client::builder().redirect(self.redirect_policy.into());
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.clone());
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+impl HttpClientBuilder {
+    /// Set how redirects are followed.
+    ///
+    /// The options set with this and the other reqwest client helpers are applied when the
+    /// reqwest client is built by [`build()`](Self::build), so they can't be combined with a
+    /// client set with [`client()`](Self::client).
+    pub fn redirect_policy(&mut self, policy: RedirectPolicy) -> &mut Self {
+        self.with_client_options(|options| options.redirect_policy = policy)
+    }
+
+    /// Set the maximum number of idle connections kept open per host.
+    ///
+    /// Defaults to no limit.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) -> &mut Self {
+        self.with_client_options(|options| options.pool_max_idle_per_host = Some(max))
+    }
+
+    /// Set how long an idle connection is kept open.
+    ///
+    /// Defaults to 90 seconds.
+    pub fn pool_idle_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.with_client_options(|options| options.pool_idle_timeout = Some(timeout))
+    }
+
+    /// Only use HTTP/2, without negotiating the protocol first.
+    ///
+    /// Defaults to `false`.
+    pub fn http2_prior_knowledge(&mut self, enabled: bool) -> &mut Self {
+        self.with_client_options(|options| options.http2_prior_knowledge = enabled)
+    }
+
+    /// Set the timeout of a whole request, from connecting until the body is received.
+    ///
+    /// Defaults to no timeout.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.with_client_options(|options| options.timeout = Some(timeout))
+    }
+
+    /// Set the `User-Agent` header sent with every request.
+    ///
+    /// Defaults to no `User-Agent`.
+    pub fn user_agent(&mut self, user_agent: HeaderValue) -> &mut Self {
+        self.with_client_options(|options| options.user_agent = Some(user_agent))
+    }
+
+    /// Update the options the reqwest client is built with.
+    fn with_client_options(&mut self, update: impl FnOnce(&mut ClientOptions)) -> &mut Self {
+        let options = self.client_options.get_or_insert_with(Default::default);
+        update(options);
+
+        self
+    }
+
+    /// Build the `HttpClient`.
+    ///
+    /// If any of the reqwest client helpers such as [`timeout()`](Self::timeout) was used, the
+    /// reqwest client is built with their options.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the reqwest client helpers are combined with a client set with
+    /// [`client()`](Self::client), as the options can't be applied to an existing client, or if
+    /// the reqwest client can't be built.
+    pub fn build(&self) -> Result<HttpClient> {
+        let mut http_client = self.fallible_build()?;
+        if let Some(options) = &self.client_options {
+            if self.client.is_some() {
+                return Err(Error::BuilderError {
+                    field: "client".to_string(),
+                    message: "can't be combined with the reqwest client options".to_string(),
+                });
+            }
+            http_client.client = options.build_client()?;
+            http_client.client_options = options.clone();
+        }
+
+        Ok(http_client)
+    }
+}
+
+/// Query string used to apply the default content rating filter.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DefaultContentRating<'a> {
+    content_rating: &'a [ContentRating],
+}
+
+/// Request built by [`HttpClient::prepare_request()`], with what is needed to send it.
+///
+/// It doesn't borrow the `HttpClient`, so the client can be released (or unlocked with the
+/// `multi-thread` feature) while waiting for the response, letting requests run concurrently.
+pub(crate) struct PreparedRequest {
+    request: reqwest::Request,
+    sender: RequestSender,
+}
+
+/// Settings of the `HttpClient` used to send a [`PreparedRequest`].
+#[derive(Clone)]
+struct RequestSender {
+    client: Client,
+    on_response: Option<ResponseHook>,
+    #[cfg(feature = "retry")]
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl Default for HttpClient {
@@ -39,6 +414,13 @@ impl Default for HttpClient {
             base_url: Url::parse(API_URL).expect("error parsing the base url"),
             auth_tokens: None,
             captcha: None,
+            default_content_rating: None,
+            on_request: None,
+            on_response: None,
+            tag_catalog: None,
+            #[cfg(feature = "retry")]
+            retry_policy: None,
+            client_options: ClientOptions::default(),
         }
     }
 }
@@ -74,14 +456,10 @@ impl HttpClient {
         HttpClientBuilder::default()
     }
 
-    /// Send the request to the endpoint but don't deserialize the response.
+    /// Build the request to the endpoint as it would be sent.
     ///
-    /// This is useful to handle things such as response header data for more control over areas
-    /// such as rate limiting.
-    pub(crate) async fn send_request_without_deserializing<E>(
-        &self,
-        endpoint: &E,
-    ) -> Result<reqwest::Response>
+    /// The authentication tokens, captcha solution and request hook are applied.
+    pub(crate) fn build_request<E>(&self, endpoint: &E) -> Result<reqwest::Request>
     where
         E: Endpoint,
     {
@@ -89,8 +467,21 @@ impl HttpClient {
         if let Some(query) = endpoint.query() {
             endpoint_url = endpoint_url.query_qs(query);
         }
+        if let (Some(content_rating), Some([])) =
+            (&self.default_content_rating, endpoint.content_rating())
+        {
+            endpoint_url = endpoint_url.append_query_qs(&DefaultContentRating { content_rating });
+        }
+
+        if endpoint.require_auth() && self.get_tokens().is_none() {
+            return Err(Error::MissingTokens);
+        }
 
-        let mut req = self.client.request(endpoint.method(), endpoint_url);
+        let mut req = self.request(endpoint.method(), endpoint_url);
+
+        if let Some(idempotency_key) = endpoint.idempotency_key() {
+            req = req.header("Idempotency-Key", idempotency_key.to_string());
+        }
 
         if let Some(body) = endpoint.body() {
             req = req.json(body);
@@ -100,38 +491,169 @@ impl HttpClient {
             req = req.multipart(multipart);
         }
 
+        let mut req = req.build()?;
+
+        if let Some(on_request) = &self.on_request {
+            let mut parts = RequestParts {
+                method: req.method().clone(),
+                url: req.url().clone(),
+                headers: req.headers().clone(),
+            };
+            on_request(&mut parts);
+            *req.method_mut() = parts.method;
+            *req.url_mut() = parts.url;
+            *req.headers_mut() = parts.headers;
+        }
+
+        Ok(req)
+    }
+
+    /// Build the request to the endpoint as an `http::Request` without sending it.
+    ///
+    /// Multipart bodies are streamed by `reqwest` and can't be converted.
+    pub(crate) fn build_http_request<E>(&self, endpoint: &E) -> Result<http::Request<Bytes>>
+    where
+        E: Endpoint,
+    {
+        let req = self.build_request(endpoint)?;
+
+        let body = match req.body() {
+            Some(body) => Bytes::copy_from_slice(body.as_bytes().ok_or_else(|| {
+                Error::RequestBuilderError(
+                    "streamed bodies can't be converted to an http::Request".to_string(),
+                )
+            })?),
+            None => Bytes::new(),
+        };
+
+        let mut http_req = http::Request::builder()
+            .method(req.method().clone())
+            .uri(req.url().as_str())
+            .body(body)
+            .map_err(|e| Error::RequestBuilderError(e.to_string()))?;
+        *http_req.headers_mut() = req.headers().clone();
+
+        Ok(http_req)
+    }
+
+    /// Build the request to the endpoint and describe it without sending it.
+    ///
+    /// This runs the same client-side checks as sending the request, such as requiring the
+    /// authentication tokens.
+    pub(crate) fn build_dry_run<E>(&self, endpoint: &E) -> Result<DryRunResult>
+    where
+        E: Endpoint,
+    {
+        let req = self.build_request(endpoint)?;
+        let body = endpoint
+            .body()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::RequestBuilderError(e.to_string()))?;
+
+        Ok(DryRunResult {
+            method: req.method().clone(),
+            url: req.url().clone(),
+            body,
+        })
+    }
+
+    /// Build the request to the endpoint as a `curl` command without sending it.
+    ///
+    /// The bearer token is replaced with `<redacted>` unless `with_secrets` is set.
+    pub(crate) fn build_curl_command<E>(&self, endpoint: &E, with_secrets: bool) -> Result<String>
+    where
+        E: Endpoint,
+    {
+        let req = self.build_http_request(endpoint)?;
+
+        let mut args = vec![format!(
+            "curl -X {} {}",
+            req.method(),
+            shell_quote(&req.uri().to_string())
+        )];
+        for (name, value) in req.headers() {
+            let value = if name == reqwest::header::AUTHORIZATION && !with_secrets {
+                "Bearer <redacted>".into()
+            } else {
+                String::from_utf8_lossy(value.as_bytes())
+            };
+            args.push(format!("-H {}", shell_quote(&format!("{name}: {value}"))));
+        }
+        if !req.body().is_empty() {
+            args.push(format!(
+                "--data {}",
+                shell_quote(&String::from_utf8_lossy(req.body()))
+            ));
+        }
+
+        Ok(args.join(" \\\n  "))
+    }
+
+    /// Build the request to the endpoint and copy what is needed to send it, so it can be sent
+    /// after the client is released.
+    pub(crate) fn prepare_request<E>(&self, endpoint: &E) -> Result<PreparedRequest>
+    where
+        E: Endpoint,
+    {
+        Ok(PreparedRequest {
+            request: self.build_request(endpoint)?,
+            sender: RequestSender {
+                client: self.client.clone(),
+                on_response: self.on_response.clone(),
+                #[cfg(feature = "retry")]
+                retry_policy: self.retry_policy.clone(),
+            },
+        })
+    }
+
+    /// Create a request with the authentication tokens and captcha solution attached, if any.
+    fn request(&self, method: Method, url: Url) -> RequestBuilder {
+        let mut req = self.client.request(method, url);
+
         if let Some(tokens) = self.get_tokens() {
             req = req.bearer_auth(&tokens.session)
-        } else if endpoint.require_auth() {
-            return Err(Error::MissingTokens);
         }
 
         if let Some(captcha) = self.get_captcha() {
             req = req.header("X-Captcha-Result", captcha);
         }
 
-        Ok(req.send().await?)
+        req
     }
 
-    /// Send the request to the endpoint and deserialize the response body.
-    pub(crate) async fn send_request<E>(&self, endpoint: &E) -> Result<E::Response>
+    /// Get a request builder for an arbitrary path on the API.
+    ///
+    /// The path is joined to the base URL and the authentication tokens and captcha solution are
+    /// attached like they would be for any endpoint.
+    ///
+    /// This is an escape hatch for the cases the endpoint builders don't support.
+    /// The response can be deserialized with [`HttpClient::parse_response()`].
+    pub fn raw_request(&self, method: Method, path: &str) -> Result<RequestBuilder> {
+        let url = self.base_url.join(path)?;
+
+        Ok(self.request(method, url))
+    }
+
+    /// Deserialize the body of a response with the [`DefaultJsonCodec`].
+    ///
+    /// Server errors (5xx) are returned as [`Error::ServerError`]. A body with a `result` other
+    /// than `ok` that can't be deserialized into `T` is returned as
+    /// [`Error::DeserializeUnexpectedResultType`].
+    pub async fn parse_response<T>(res: Response) -> Result<T>
     where
-        E: Endpoint,
-        <<E as Endpoint>::Response as FromResponse>::Response: DeserializeOwned,
+        T: DeserializeOwned,
     {
-        let res = self.send_request_without_deserializing(endpoint).await?;
-
         let status_code = res.status();
 
         if status_code.is_server_error() {
             return Err(Error::ServerError(status_code.as_u16(), res.text().await?));
         }
 
-        let res = res
-            .json::<<E::Response as FromResponse>::Response>()
-            .await?;
+        let body = res.bytes().await?;
 
-        Ok(FromResponse::from_response(res))
+        DefaultJsonCodec::decode(body.to_vec())
+            .map_err(|e| unexpected_result_type(&body).unwrap_or(e))
     }
 
     /// Get the authentication tokens stored in the client.
@@ -170,14 +692,243 @@ impl HttpClient {
     pub fn clear_captcha(&mut self) {
         self.captcha = None;
     }
+
+    /// Get the tags stored in the client, if any.
+    ///
+    /// The catalog can be cloned cheaply to keep it after the client is released.
+    pub fn get_tag_catalog(&self) -> Option<&Arc<[TagObject]>> {
+        self.tag_catalog.as_ref()
+    }
+
+    /// Set the tags used instead of fetching them from MangaDex.
+    ///
+    /// Tags rarely change, so a list fetched earlier or bundled with the application lets tag
+    /// lookups work without a request.
+    pub fn set_tag_catalog(&mut self, tags: impl Into<Arc<[TagObject]>>) {
+        self.tag_catalog = Some(tags.into());
+    }
+
+    /// Remove the stored tags so that they are fetched from MangaDex again.
+    pub fn clear_tag_catalog(&mut self) {
+        self.tag_catalog = None;
+    }
+
+    /// Get the content rating filter applied to requests that don't set their own.
+    pub fn get_default_content_rating(&self) -> Option<&[ContentRating]> {
+        self.default_content_rating.as_deref()
+    }
     /// Create a new client of api.mangadex.dev
     pub fn api_dev_client() -> Self{
         Self { 
             client: Client::new(), 
             base_url: Url::parse(API_DEV_URL).expect("error parsing the base url"), 
             auth_tokens: None, 
-            captcha: None 
+            captcha: None,
+            default_content_rating: None,
+            on_request: None,
+            on_response: None,
+            tag_catalog: None,
+            #[cfg(feature = "retry")]
+            retry_policy: None,
+            client_options: ClientOptions::default(),
+        }
+    }
+}
+
+impl PreparedRequest {
+    /// Copy the request so it can be sent again, unless its body is streamed.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            request: self.request.try_clone()?,
+            sender: self.sender.clone(),
+        })
+    }
+
+    /// Send the request but don't deserialize the response.
+    pub(crate) async fn send(self) -> Result<reqwest::Response> {
+        self.sender.send(self.request).await
+    }
+
+    /// Send the request and deserialize the response body, keeping the response headers.
+    pub(crate) async fn send_with_headers<R>(self) -> Result<(R, HeaderMap)>
+    where
+        R: FromResponse,
+        R::Response: DeserializeOwned,
+    {
+        let res = self.send().await?;
+        let status_code = res.status();
+        let headers = res.headers().clone();
+
+        let res = HttpClient::parse_response::<R::Response>(res).await?;
+
+        Ok((
+            R::from_response(res).with_status_code(status_code.as_u16()),
+            headers,
+        ))
+    }
+
+    /// Send the request and only check the response for errors, keeping the response headers.
+    ///
+    /// A `204 No Content` response or an empty body is treated as a success.
+    pub(crate) async fn send_discarding_result_with_headers<T>(self) -> Result<HeaderMap>
+    where
+        T: DeserializeOwned,
+    {
+        let res = self.send().await?;
+
+        let status_code = res.status();
+        let headers = res.headers().clone();
+
+        if status_code == StatusCode::NO_CONTENT
+            || (status_code.is_success() && res.content_length() == Some(0))
+        {
+            return Ok(headers);
+        }
+
+        let res = HttpClient::parse_response::<ApiResult<T>>(res).await?;
+
+        Result::<T>::from_response(res).with_status_code(status_code.as_u16())?;
+
+        Ok(headers)
+    }
+}
+
+impl RequestSender {
+    /// Send a built request, retrying it if the client has a retry policy.
+    async fn send(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        #[cfg(feature = "retry")]
+        if let Some(retry_policy) = &self.retry_policy {
+            return self.execute_with_retries(req, retry_policy).await;
+        }
+
+        self.execute(req).await
+    }
+
+    /// Send a built request and call the response hook.
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        let method = req.method().clone();
+        let started_at = Instant::now();
+        let res = self.client.execute(req).await?;
+
+        if let Some(on_response) = &self.on_response {
+            on_response(&ResponseParts {
+                method,
+                url: res.url().clone(),
+                status: res.status(),
+                headers: res.headers().clone(),
+                elapsed: started_at.elapsed(),
+            });
+        }
+
+        Ok(res)
+    }
+
+    /// Send a built request, sending it again for as long as the retry policy allows.
+    #[cfg(feature = "retry")]
+    async fn execute_with_retries(
+        &self,
+        mut req: reqwest::Request,
+        retry_policy: &RetryPolicy,
+    ) -> Result<reqwest::Response> {
+        let mut backoff = retry_policy.backoff;
+        let mut retries = 0;
+
+        loop {
+            // Streamed bodies can't be cloned, so those requests are only sent once.
+            let next_req = req.try_clone();
+            let parts = RequestParts {
+                method: req.method().clone(),
+                url: req.url().clone(),
+                headers: req.headers().clone(),
+            };
+            let res = self.execute(req).await;
+
+            match next_req {
+                Some(next_req)
+                    if retries < retry_policy.max_retries
+                        && retry_policy.should_retry(&parts, &res) =>
+                {
+                    let wait = retry_after(&res).unwrap_or(backoff);
+                    tokio::time::sleep(wait.min(retry_policy.max_backoff)).await;
+                    backoff *= 2;
+                    retries += 1;
+                    req = next_req;
+                }
+                _ => return res,
+            }
+        }
+    }
+}
+
+/// Get the wait requested by the `Retry-After` header of a response, if it is in seconds.
+#[cfg(feature = "retry")]
+fn retry_after(res: &Result<Response>) -> Option<Duration> {
+    let seconds = res
+        .as_ref()
+        .ok()?
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Get the error for a body that couldn't be deserialized because it isn't a successful
+/// response, if that is the case.
+fn unexpected_result_type(body: &[u8]) -> Option<Error> {
+    #[derive(serde::Deserialize)]
+    struct Envelope {
+        result: ResultType,
+    }
+
+    let envelope: Envelope = serde_json::from_slice(body).ok()?;
+    match envelope.result {
+        ResultType::Ok => None,
+        actual => Some(Error::DeserializeUnexpectedResultType {
+            expected: ResultType::Ok,
+            actual,
+            body: String::from_utf8_lossy(body).into_owned(),
+        }),
+    }
+}
+
+/// Quote a string for POSIX shells.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Unwrap the `Result` returned by the endpoints with the `flatten_result` attribute.
+#[doc(hidden)]
+pub trait FlattenedResponse {
+    type Ok;
+
+    fn into_result(self) -> Result<Self::Ok>;
+}
+
+impl<T> FlattenedResponse for Result<T> {
+    type Ok = T;
+
+    fn into_result(self) -> Result<T> {
+        self
+    }
+}
+
+/// Replace a `404 Not Found` API error with [`Error::NotFound`] if the endpoint requests a
+/// single resource.
+#[doc(hidden)]
+pub fn not_found_error<E: Endpoint>(endpoint: &E, error: Error) -> Error {
+    match (endpoint.requested_resource(), &error) {
+        (Some((resource, id)), Error::Api(_)) if error.status_code() == Some(404) => {
+            Error::NotFound {
+                resource: resource.to_string(),
+                id,
+            }
         }
+        _ => error,
     }
 }
 
@@ -212,6 +963,10 @@ impl HttpClient {
 /// - `body`: The input structure will be serialized as a JSON body.
 /// - `no_data`: No data will be sent with the request.
 /// - `auth`: If this is included, the request will not be made if the user is not authenticated.
+/// - `content_rating`: The input structure has a `content_rating` filter. When it is empty,
+///   the client's default content rating is sent instead.
+/// - `idempotency_key`: The input structure has an `idempotency_key: Option<Uuid>` field that is
+///   sent as the `Idempotency-Key` header.
 ///
 /// Some examples of valid tags are:
 ///
@@ -219,6 +974,8 @@ impl HttpClient {
 /// #[query] QueryReq
 /// #[body] BodyReq
 /// #[query auth] QueryReq
+/// #[query auth content_rating] QueryReq
+/// #[body auth idempotency_key] BodyReq
 /// #[no_data] QueryStruct
 /// ```
 ///
@@ -230,8 +987,23 @@ impl HttpClient {
 /// - \<no tag\>: `send()` will simply return `Result<Output>`.
 /// - `flatten_result`: If `Output = Result<T>`, the return type will be simplified to `Result<T>`.
 /// - `discard_result`: If `Output = Result<T>`, discard `T`, and return `Result<()>`.
+///   A `204 No Content` response or an empty body is treated as a success.
 /// - `no_send`: Do not implement a `send()` function.
 ///
+/// An optional fourth argument names the single resource the request gets, with the field holding
+/// its ID. A `404 Not Found` response is then returned as `Error::NotFound` by the
+/// `flatten_result` `send()` methods:
+///
+/// ```rust, ignore
+/// #[not_found("manga", manga_id)]
+/// ```
+///
+/// A `to_http_request()` method that builds the request without sending it is always
+/// implemented.
+///
+/// With the `cancellation` feature, a `send_with_cancel()` method that takes a
+/// `tokio_util::sync::CancellationToken` is implemented alongside `send()`.
+///
 /// # Examples
 ///
 /// ```rust, ignore
@@ -244,8 +1016,9 @@ impl HttpClient {
 macro_rules! endpoint {
     {
         $method:ident $path:tt,
-        #[$payload:ident $($auth:ident)?] $typ:ty,
+        #[$payload:ident $($flag:ident)*] $typ:ty,
         $(#[$out_res:ident])? $out:ty
+        $(, #[not_found($resource:literal, $id:ident)])?
     } => {
         impl mangadex_api_schema::Endpoint for $typ {
             /// The response type.
@@ -258,11 +1031,13 @@ macro_rules! endpoint {
 
             endpoint! { @path $path }
             endpoint! { @payload $payload }
-            // If the `auth` attribute is set, make the request require authentication.
-            $(endpoint! { @$auth })?
+            // Apply the optional attributes, such as `auth`.
+            $(endpoint! { @$flag })*
+            $(endpoint! { @not_found $resource, $id })?
         }
 
         endpoint! { @send $(:$out_res)?, $typ, $out }
+        endpoint! { @to_http_request $typ }
     };
 
     { @path ($path:expr, $($arg:ident),+) } => {
@@ -311,21 +1086,57 @@ macro_rules! endpoint {
         }
     };
 
+    { @content_rating } => {
+        /// Get the content rating filter of the request.
+        fn content_rating(&self) -> Option<&[mangadex_api_types::ContentRating]> {
+            Some(&self.content_rating)
+        }
+    };
+
+    { @not_found $resource:literal, $id:ident } => {
+        /// Get the type and ID of the single resource requested.
+        fn requested_resource(&self) -> Option<(&'static str, Option<uuid::Uuid>)> {
+            Some(($resource, Some(self.$id)))
+        }
+    };
+
+    { @idempotency_key } => {
+        /// Get the key sent in the `Idempotency-Key` header, if any.
+        fn idempotency_key(&self) -> Option<uuid::Uuid> {
+            self.idempotency_key
+        }
+    };
+
     // Return the response as a `Result`.
     { @send, $typ:ty, $out:ty } => {
         impl $typ {
             /// Send the request.
             pub async fn send(&self) -> mangadex_api_types::error::Result<$out> {
                 #[cfg(not(feature = "multi-thread"))]
-                {
-                    self.http_client.try_borrow()?.send_request(self).await
-                }
+                let request = self.http_client.try_borrow()?.prepare_request(self)?;
                 #[cfg(feature = "multi-thread")]
-                {
-                    self.http_client.lock().await.send_request(self).await
-                }
+                let request = self.http_client.lock().await.prepare_request(self)?;
+
+                let (res, _) = request.send_with_headers::<$out>().await?;
+
+                Ok(res)
+            }
+
+            /// Send the request and get the response headers along with the response.
+            #[allow(dead_code)]
+            pub async fn send_with_headers(
+                &self,
+            ) -> mangadex_api_types::error::Result<($out, reqwest::header::HeaderMap)> {
+                #[cfg(not(feature = "multi-thread"))]
+                let request = self.http_client.try_borrow()?.prepare_request(self)?;
+                #[cfg(feature = "multi-thread")]
+                let request = self.http_client.lock().await.prepare_request(self)?;
+
+                request.send_with_headers::<$out>().await
             }
         }
+
+        endpoint! { @send_with_cancel $typ, mangadex_api_types::error::Result<$out> }
     };
     // Return the `Result` variants, `Ok` or `Err`.
     { @send:flatten_result, $typ:ty, $out:ty } => {
@@ -334,15 +1145,36 @@ macro_rules! endpoint {
             #[allow(dead_code)]
             pub async fn send(&self) -> $out {
                 #[cfg(not(feature = "multi-thread"))]
-                {
-                    self.http_client.try_borrow()?.send_request(self).await?
-                }
+                let request = self.http_client.try_borrow()?.prepare_request(self)?;
                 #[cfg(feature = "multi-thread")]
-                {
-                    self.http_client.lock().await.send_request(self).await?
-                }
+                let request = self.http_client.lock().await.prepare_request(self)?;
+                let (res, _) = request.send_with_headers::<$out>().await?;
+
+                res.map_err(|e| crate::http_client::not_found_error(self, e))
+            }
+
+            /// Send the request and get the response headers along with the response.
+            #[allow(dead_code)]
+            pub async fn send_with_headers(
+                &self,
+            ) -> mangadex_api_types::error::Result<(
+                <$out as crate::http_client::FlattenedResponse>::Ok,
+                reqwest::header::HeaderMap,
+            )> {
+                #[cfg(not(feature = "multi-thread"))]
+                let request = self.http_client.try_borrow()?.prepare_request(self)?;
+                #[cfg(feature = "multi-thread")]
+                let request = self.http_client.lock().await.prepare_request(self)?;
+                let (res, headers) = request.send_with_headers::<$out>().await?;
+
+                let res = crate::http_client::FlattenedResponse::into_result(res)
+                    .map_err(|e| crate::http_client::not_found_error(self, e))?;
+
+                Ok((res, headers))
             }
         }
+
+        endpoint! { @send_with_cancel $typ, $out }
     };
     // Don't return any data from the response.
     { @send:discard_result, $typ:ty, $out:ty } => {
@@ -351,14 +1183,868 @@ macro_rules! endpoint {
             #[allow(dead_code)]
             pub async fn send(&self) -> mangadex_api_types::error::Result<()> {
                 #[cfg(not(feature = "multi-thread"))]
-                self.http_client.try_borrow()?.send_request(self).await??;
+                let request = self.http_client.try_borrow()?.prepare_request(self)?;
                 #[cfg(feature = "multi-thread")]
-                self.http_client.lock().await.send_request(self).await??;
+                let request = self.http_client.lock().await.prepare_request(self)?;
+
+                request
+                    .send_discarding_result_with_headers::<
+                        <$out as crate::http_client::FlattenedResponse>::Ok,
+                    >()
+                    .await?;
 
                 Ok(())
             }
+
+            /// Send the request and get the response headers.
+            #[allow(dead_code)]
+            pub async fn send_with_headers(
+                &self,
+            ) -> mangadex_api_types::error::Result<reqwest::header::HeaderMap> {
+                #[cfg(not(feature = "multi-thread"))]
+                let request = self.http_client.try_borrow()?.prepare_request(self)?;
+                #[cfg(feature = "multi-thread")]
+                let request = self.http_client.lock().await.prepare_request(self)?;
+
+                request
+                    .send_discarding_result_with_headers::<
+                        <$out as crate::http_client::FlattenedResponse>::Ok,
+                    >()
+                    .await
+            }
         }
+
+        endpoint! { @send_with_cancel $typ, mangadex_api_types::error::Result<()> }
     };
     // Don't implement `send()` and require manual implementation.
     { @send:no_send, $typ:ty, $out:ty } => { };
+
+    // Build the request without sending it.
+    //
+    // The legacy auth endpoints are deprecated, so using them here must not warn.
+    { @to_http_request $typ:ty } => {
+        #[allow(deprecated)]
+        impl $typ {
+            /// Build the request as an `http::Request` without sending it.
+            ///
+            /// The request is the same as the one `send()` would send, including the
+            /// authentication headers when the client has tokens.
+            #[allow(dead_code)]
+            pub async fn to_http_request(
+                &self,
+            ) -> mangadex_api_types::error::Result<http::Request<bytes::Bytes>> {
+                #[cfg(not(feature = "multi-thread"))]
+                {
+                    self.http_client.try_borrow()?.build_http_request(self)
+                }
+                #[cfg(feature = "multi-thread")]
+                {
+                    self.http_client.lock().await.build_http_request(self)
+                }
+            }
+
+            /// Build the request and return its method, URL and JSON body without sending it.
+            ///
+            /// This runs the same client-side checks as `send()`, which is useful to validate
+            /// mutating requests without touching the API.
+            #[allow(dead_code)]
+            pub async fn dry_run(
+                &self,
+            ) -> mangadex_api_types::error::Result<crate::http_client::DryRunResult> {
+                #[cfg(not(feature = "multi-thread"))]
+                {
+                    self.http_client.try_borrow()?.build_dry_run(self)
+                }
+                #[cfg(feature = "multi-thread")]
+                {
+                    self.http_client.lock().await.build_dry_run(self)
+                }
+            }
+
+            /// Build the request as a copy-pastable `curl` command without sending it.
+            ///
+            /// The bearer token is redacted, use `to_curl_with_secrets()` to keep it.
+            #[allow(dead_code)]
+            pub async fn to_curl(&self) -> mangadex_api_types::error::Result<String> {
+                #[cfg(not(feature = "multi-thread"))]
+                {
+                    self.http_client.try_borrow()?.build_curl_command(self, false)
+                }
+                #[cfg(feature = "multi-thread")]
+                {
+                    self.http_client.lock().await.build_curl_command(self, false)
+                }
+            }
+
+            /// Build the request as a copy-pastable `curl` command, including the bearer token,
+            /// without sending it.
+            #[allow(dead_code)]
+            pub async fn to_curl_with_secrets(&self) -> mangadex_api_types::error::Result<String> {
+                #[cfg(not(feature = "multi-thread"))]
+                {
+                    self.http_client.try_borrow()?.build_curl_command(self, true)
+                }
+                #[cfg(feature = "multi-thread")]
+                {
+                    self.http_client.lock().await.build_curl_command(self, true)
+                }
+            }
+        }
+    };
+
+    // Race `send()` against a cancellation token.
+    { @send_with_cancel $typ:ty, $ret:ty } => {
+        #[cfg(feature = "cancellation")]
+        impl $typ {
+            /// Send the request, aborting it if the token is cancelled before the response is
+            /// received.
+            ///
+            /// Returns `Error::Cancelled` when the request was aborted.
+            #[allow(dead_code)]
+            pub async fn send_with_cancel(
+                &self,
+                token: tokio_util::sync::CancellationToken,
+            ) -> $ret {
+                tokio::select! {
+                    biased;
+                    _ = token.cancelled() => Err(mangadex_api_types::error::Error::Cancelled),
+                    res = self.send() => res,
+                }
+            }
+        }
+    };
+}
+
+/// Helper macro to implement `with_limit()`, `with_offset()` and `collect_all()` on built
+/// endpoint structs that have `limit` and `offset` query parameters.
+///
+/// This is useful for pagination loops where the same request is re-sent with a different offset.
+/// The second argument is the type of the items in the paginated collection.
+///
+/// # Examples
+///
+/// ```rust, ignore
+/// paginated! { ListUser => UserObject }
+/// ```
+macro_rules! paginated {
+    { $typ:ty => $item:ty } => {
+        impl $typ {
+            /// Return the request with the `limit` query parameter replaced.
+            pub fn with_limit(mut self, limit: u32) -> Self {
+                self.limit = Some(limit);
+                self
+            }
+
+            /// Return the request with the `offset` query parameter replaced.
+            pub fn with_offset(mut self, offset: u32) -> Self {
+                self.offset = Some(offset);
+                self
+            }
+
+            /// Send the request for every page, starting at its offset, and collect the results.
+            ///
+            /// An error is returned if there are more than
+            /// [`MAX_COLLECT_ALL_ITEMS`](crate::MAX_COLLECT_ALL_ITEMS) results.
+            pub async fn collect_all(&self) -> mangadex_api_types::error::Result<Vec<$item>> {
+                let mut request = self.clone();
+                let mut items: Vec<$item> = Vec::new();
+
+                loop {
+                    #[cfg(not(feature = "multi-thread"))]
+                    let prepared = request.http_client.try_borrow()?.prepare_request(&request)?;
+                    #[cfg(feature = "multi-thread")]
+                    let prepared = request.http_client.lock().await.prepare_request(&request)?;
+                    let (page, _) = prepared
+                        .send_with_headers::<<Self as mangadex_api_schema::Endpoint>::Response>()
+                        .await?;
+                    let page = page?;
+
+                    let fetched = page.offset as usize + page.data.len();
+                    let is_last_page = page.data.is_empty() || fetched >= page.total as usize;
+                    items.extend(page.data);
+
+                    if items.len() > crate::MAX_COLLECT_ALL_ITEMS {
+                        return Err(mangadex_api_types::error::Error::OutOfRange(format!(
+                            "collect_all() stopped after {} results, the limit is {}",
+                            items.len(),
+                            crate::MAX_COLLECT_ALL_ITEMS
+                        )));
+                    }
+                    if is_last_page {
+                        return Ok(items);
+                    }
+
+                    request = request.with_offset(fetched as u32);
+                }
+            }
+
+            /// Send the request for every page, starting at its offset, and yield the pages as
+            /// they are received.
+            ///
+            /// The `total` of each page is the largest total seen so far, so it can size a
+            /// progress bar from the first page even if MangaDex reports a different total later.
+            #[cfg(feature = "futures")]
+            pub fn pages(
+                &self,
+            ) -> impl futures::Stream<
+                Item = mangadex_api_types::error::Result<mangadex_api_schema::v5::Results<$item>>,
+            > {
+                futures::stream::try_unfold(
+                    (Some(self.clone()), 0),
+                    |(request, max_total): (Option<Self>, u32)| async move {
+                        let Some(request) = request else {
+                            return Ok(None);
+                        };
+
+                        #[cfg(not(feature = "multi-thread"))]
+                        let prepared =
+                            request.http_client.try_borrow()?.prepare_request(&request)?;
+                        #[cfg(feature = "multi-thread")]
+                        let prepared =
+                            request.http_client.lock().await.prepare_request(&request)?;
+                        let (page, _) = prepared
+                            .send_with_headers::<<Self as mangadex_api_schema::Endpoint>::Response>()
+                            .await?;
+                        let mut page = page?;
+
+                        let fetched = page.offset as usize + page.data.len();
+                        let is_last_page = page.data.is_empty() || fetched >= page.total as usize;
+                        let max_total = max_total.max(page.total);
+                        page.total = max_total;
+
+                        let next_request =
+                            (!is_last_page).then(|| request.with_offset(fetched as u32));
+
+                        Ok(Some((page, (next_request, max_total))))
+                    },
+                )
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use mangadex_api_schema::v5::MangaData;
+    use mangadex_api_types::error::Error;
+    use mangadex_api_types::ResultType;
+    use reqwest::header::HeaderValue;
+    use reqwest::Method;
+    use serde_json::json;
+    use url::Url;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::ClientOptions;
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient, RedirectPolicy, RequestHook, ResponseHook};
+
+    #[tokio::test]
+    async fn hooks_can_add_headers_and_observe_the_status() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+
+        let status = Arc::new(AtomicU16::new(0));
+        let on_request: RequestHook = Arc::new(|parts| {
+            parts
+                .headers
+                .insert("X-Signature", HeaderValue::from_static("signed"));
+        });
+        let on_response: ResponseHook = {
+            let status = status.clone();
+            Arc::new(move |parts| status.store(parts.status.as_u16(), Ordering::SeqCst))
+        };
+
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .on_request(on_request)
+            .on_response(on_response)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .and(header("X-Signature", "signed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        mangadex_client.manga().list_tags().build()?.send().await?;
+
+        assert_eq!(status.load(Ordering::SeqCst), 200);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_with_headers_returns_the_response_headers() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-RateLimit-Remaining", "42")
+                    .set_body_json(response_body),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (tags, headers) = mangadex_client
+            .manga()
+            .list_tags()
+            .build()?
+            .send_with_headers()
+            .await?;
+
+        assert_eq!(tags.total, 0);
+        assert_eq!(
+            headers
+                .get("X-RateLimit-Remaining")
+                .and_then(|value| value.to_str().ok()),
+            Some("42")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn api_errors_capture_the_http_status_code() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let error_body = json!({
+            "result": "error",
+            "errors": [
+                {
+                    "id": "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d",
+                    "status": 400,
+                    "title": "Too many requests",
+                    "detail": "You have been rate limited"
+                }
+            ]
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(error_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client.manga().list_tags().build()?.send().await;
+
+        match res {
+            Err(e @ mangadex_api_types::error::Error::Api(_)) => {
+                assert_eq!(e.status_code(), Some(429));
+            }
+            other => panic!("expected an API error, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn requests_sharing_a_client_are_sent_concurrently() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+
+        // Requests are counted in flight from the request hook until the response hook.
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let on_request: RequestHook = {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            Arc::new(move |_| {
+                let count = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(count, Ordering::SeqCst);
+            })
+        };
+        let on_response: ResponseHook = {
+            let in_flight = in_flight.clone();
+            Arc::new(move |_| {
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+            })
+        };
+
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .on_request(on_request)
+            .on_response(on_response)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(response_body)
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let first = mangadex_client.manga().list_tags().build()?;
+        let second = mangadex_client.manga().list_tags().build()?;
+        let (first, second) = tokio::join!(first.send(), second.send());
+        first?;
+        second?;
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn redirects_are_followed_up_to_the_configured_limit() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", "/hop"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/hop"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", "/tags"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .mount(&mock_server)
+            .await;
+
+        for (max, follows) in [(2, true), (1, false)] {
+            let http_client = HttpClient::builder()
+                .base_url(Url::parse(&mock_server.uri())?)
+                .redirect_policy(RedirectPolicy::Limited(max))
+                .build()?;
+            let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+            let res = mangadex_client.manga().list_tags().build()?.send().await;
+
+            assert_eq!(res.is_ok(), follows, "limit of {max} redirects: {res:?}");
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn error_envelope_parsed_as_a_successful_response_is_reported() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+
+        let body = json!({
+            "result": "error",
+            "errors": [
+                {
+                    "id": "9c346772-7b14-5982-b4b6-7b5888522762",
+                    "status": 400,
+                    "title": "Bad Request",
+                    "detail": null
+                }
+            ]
+        });
+        Mock::given(method("GET"))
+            .and(path("/manga/random"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = http_client
+            .raw_request(Method::GET, "/manga/random")?
+            .send()
+            .await?;
+        let res = HttpClient::parse_response::<MangaData>(res).await;
+
+        match res {
+            Err(Error::DeserializeUnexpectedResultType {
+                expected,
+                actual,
+                body,
+            }) => {
+                assert_eq!(expected, ResultType::Ok);
+                assert_eq!(actual, ResultType::Error);
+                assert!(body.contains("Bad Request"));
+            }
+            res => panic!("expected an unexpected result type error, got {res:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn pool_and_http2_options_are_kept_together() -> anyhow::Result<()> {
+        let http_client = HttpClient::builder()
+            .pool_max_idle_per_host(8)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .http2_prior_knowledge(true)
+            .redirect_policy(RedirectPolicy::None)
+            .build()?;
+
+        assert_eq!(
+            http_client.client_options,
+            ClientOptions {
+                redirect_policy: RedirectPolicy::None,
+                pool_max_idle_per_host: Some(8),
+                pool_idle_timeout: Some(Duration::from_secs(30)),
+                http2_prior_knowledge: true,
+                ..Default::default()
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn client_options_cannot_be_combined_with_a_custom_client() {
+        let res = HttpClient::builder()
+            .client(reqwest::Client::new())
+            .timeout(Duration::from_secs(10))
+            .build();
+
+        assert!(matches!(
+            res,
+            Err(Error::BuilderError { field, .. }) if field == "client"
+        ));
+    }
+
+    #[tokio::test]
+    async fn cross_host_redirects_drop_the_authorization_header() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let other_host = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("Location", format!("{}/tags", other_host.uri()).as_str()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [],
+                "limit": 10,
+                "offset": 0,
+                "total": 0
+            })))
+            .expect(1)
+            .mount(&other_host)
+            .await;
+
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .redirect_policy(RedirectPolicy::default())
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        mangadex_client.manga().list_tags().build()?.send().await?;
+
+        let requests = other_host.received_requests().await.unwrap_or_default();
+        assert_eq!(requests.len(), 1);
+        assert!(!requests[0]
+            .headers
+            .iter()
+            .any(|(name, _)| name.as_str().eq_ignore_ascii_case("authorization")));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[tokio::test]
+    async fn compression_sends_the_accept_encoding_header() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [],
+                "limit": 10,
+                "offset": 0,
+                "total": 0
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        mangadex_client.manga().list_tags().build()?.send().await?;
+
+        let requests = mock_server.received_requests().await.unwrap_or_default();
+        let accept_encoding: Vec<&str> = requests[0]
+            .headers
+            .iter()
+            .filter(|(name, _)| name.as_str().eq_ignore_ascii_case("accept-encoding"))
+            .flat_map(|(_, values)| values.iter().map(|value| value.as_str()))
+            .collect();
+        assert!(accept_encoding.iter().any(|value| value.contains("gzip")));
+        assert!(accept_encoding.iter().any(|value| value.contains("br")));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "cancellation")]
+    #[tokio::test]
+    async fn send_with_cancel_aborts_the_request_when_the_token_is_cancelled(
+    ) -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use mangadex_api_types::error::Error;
+        use tokio_util::sync::CancellationToken;
+
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        Mock::given(method("GET"))
+            .and(path("/manga/tag"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(30)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let token = CancellationToken::new();
+        let cancel = {
+            let token = token.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                token.cancel();
+            }
+        };
+
+        let request = mangadex_client.manga().list_tags().build()?;
+        let (res, _) = tokio::join!(request.send_with_cancel(token), cancel);
+
+        assert!(matches!(res, Err(Error::Cancelled)));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "retry")]
+    #[tokio::test]
+    async fn retry_predicate_decides_which_responses_are_retried() -> anyhow::Result<()> {
+        use reqwest::StatusCode;
+
+        use crate::{RetryPolicy, RetryPredicate};
+
+        let mock_server = MockServer::start().await;
+
+        let predicate: RetryPredicate = Arc::new(|parts, res| {
+            parts.url.path() == "/user"
+                && matches!(res, Ok(res) if res.status() == StatusCode::SERVICE_UNAVAILABLE)
+        });
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .retry_policy(RetryPolicy::with_predicate(predicate).backoff(Duration::from_millis(1)))
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [],
+                "limit": 10,
+                "offset": 0,
+                "total": 0
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        // Not retried as the predicate only retries the user list.
+        Mock::given(method("GET"))
+            .and(path("/author"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let users = mangadex_client.user().list().build()?.send().await?;
+        assert_eq!(users.total, 0);
+
+        let res = mangadex_client.author().list().build()?.send().await;
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "retry")]
+    #[tokio::test]
+    async fn retry_policy_retries_requests_with_an_idempotency_key_after_retry_after(
+    ) -> anyhow::Result<()> {
+        use mangadex_api_types::ReportCategory;
+        use uuid::Uuid;
+
+        use crate::RetryPolicy;
+
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            // The test would time out if the backoff was used instead of `Retry-After`.
+            .retry_policy(RetryPolicy::new(1).backoff(Duration::from_secs(60)))
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        Mock::given(method("POST"))
+            .and(path("/report"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/report"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": "ok"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let report = |idempotency_key: Option<Uuid>| {
+            let mut builder = mangadex_client
+                .report()
+                .create()
+                .category(ReportCategory::Manga)
+                .reason(Uuid::new_v4())
+                .object_id(Uuid::new_v4());
+            if let Some(idempotency_key) = idempotency_key {
+                builder = builder.idempotency_key(idempotency_key);
+            }
+            builder.build()
+        };
+
+        // A POST without an idempotency key isn't retried.
+        let res = report(None)?.send().await;
+        assert!(res.is_err());
+
+        tokio::time::timeout(Duration::from_secs(5), report(Some(Uuid::new_v4()))?.send())
+            .await??;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "retry")]
+    #[tokio::test]
+    async fn retry_policy_caps_the_retry_after_wait_at_the_max_backoff() -> anyhow::Result<()> {
+        use crate::RetryPolicy;
+
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .retry_policy(RetryPolicy::new(1).max_backoff(Duration::from_millis(1)))
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        Mock::given(method("GET"))
+            .and(path("/author"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "86400"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/author"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [],
+                "limit": 10,
+                "offset": 0,
+                "total": 0
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let authors = tokio::time::timeout(
+            Duration::from_secs(5),
+            mangadex_client.author().list().build()?.send(),
+        )
+        .await??;
+        assert_eq!(authors.total, 0);
+
+        Ok(())
+    }
 }
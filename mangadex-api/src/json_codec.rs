@@ -0,0 +1,130 @@
+//! JSON deserialization of the response bodies.
+//!
+//! The endpoints deserialize their responses with [`DefaultJsonCodec`], which is
+//! [`SerdeJsonCodec`] unless the `simd-json` feature is enabled, in which case it is
+//! [`SimdJsonCodec`].
+//!
+//! `simd-json` is faster on large responses, such as manga or chapter lists, on CPUs with SIMD
+//! support (SSE4.2 or AVX2 on x86, NEON on ARM) and falls back to a slower implementation
+//! otherwise. Unlike `serde_json`, it relies on `unsafe` code internally, which this crate
+//! otherwise forbids for itself.
+
+use mangadex_api_types::error::{Error, Result};
+use serde::de::DeserializeOwned;
+
+/// Deserialize a response body into a type.
+pub trait JsonCodec {
+    /// Deserialize the body, which may be modified in place by the codec.
+    fn decode<T: DeserializeOwned>(body: Vec<u8>) -> Result<T>;
+}
+
+/// Codec using [`serde_json`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SerdeJsonCodec;
+
+impl JsonCodec for SerdeJsonCodec {
+    fn decode<T: DeserializeOwned>(body: Vec<u8>) -> Result<T> {
+        serde_json::from_slice(&body).map_err(|e| Error::DeserializeError(e.to_string()))
+    }
+}
+
+/// Codec using [`simd_json`].
+#[cfg(feature = "simd-json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimdJsonCodec;
+
+#[cfg(feature = "simd-json")]
+impl JsonCodec for SimdJsonCodec {
+    fn decode<T: DeserializeOwned>(mut body: Vec<u8>) -> Result<T> {
+        simd_json::serde::from_slice(&mut body).map_err(|e| Error::DeserializeError(e.to_string()))
+    }
+}
+
+/// Codec used to deserialize the endpoint responses.
+#[cfg(not(feature = "simd-json"))]
+pub type DefaultJsonCodec = SerdeJsonCodec;
+
+/// Codec used to deserialize the endpoint responses.
+#[cfg(feature = "simd-json")]
+pub type DefaultJsonCodec = SimdJsonCodec;
+
+#[cfg(test)]
+mod tests {
+    use mangadex_api_schema::v5::MangaData;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::{DefaultJsonCodec, JsonCodec, SerdeJsonCodec};
+    use mangadex_api_types::error::Error;
+
+    fn manga_body() -> Vec<u8> {
+        serde_json::to_vec(&json!({
+            "result": "ok",
+            "response": "entity",
+            "data": {
+                "id": Uuid::new_v4(),
+                "type": "manga",
+                "attributes": {
+                    "title": {
+                        "en": "Test Manga"
+                    },
+                    "altTitles": [
+                        {"ja": "テスト"}
+                    ],
+                    "description": {
+                        "en": "A \"quoted\" description\nwith escapes."
+                    },
+                    "isLocked": false,
+                    "links": null,
+                    "originalLanguage": "ja",
+                    "lastVolume": null,
+                    "lastChapter": null,
+                    "publicationDemographic": null,
+                    "status": "ongoing",
+                    "year": 2021,
+                    "contentRating": "safe",
+                    "chapterNumbersResetOnNewVolume": false,
+                    "availableTranslatedLanguages": ["en"],
+                    "tags": [],
+                    "state": "published",
+                    "createdAt": "2022-01-01T00:00:00+00:00",
+                    "updatedAt": "2022-01-01T00:00:00+00:00",
+                    "version": 1
+                },
+                "relationships": [
+                    {
+                        "id": Uuid::new_v4(),
+                        "type": "author"
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn serde_json_codec_decodes_a_manga() {
+        let manga: MangaData = SerdeJsonCodec::decode(manga_body()).unwrap();
+
+        assert_eq!(manga.data.attributes.year, Some(2021));
+        assert_eq!(manga.data.relationships.len(), 1);
+    }
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn simd_json_codec_decodes_like_serde_json() {
+        let body = manga_body();
+
+        let expected: MangaData = SerdeJsonCodec::decode(body.clone()).unwrap();
+        let actual: MangaData = super::SimdJsonCodec::decode(body).unwrap();
+
+        assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+    }
+
+    #[test]
+    fn invalid_json_is_a_deserialize_error() {
+        let res = DefaultJsonCodec::decode::<MangaData>(b"{\"result\":".to_vec());
+
+        assert!(matches!(res, Err(Error::DeserializeError(_))));
+    }
+}
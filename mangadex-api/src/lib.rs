@@ -5,11 +5,27 @@
 pub mod constants;
 #[macro_use]
 mod http_client;
+pub mod json_codec;
+#[cfg(test)]
+#[macro_use]
+mod test_utils;
 pub mod v5;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(all(feature = "token-refresher", not(feature = "multi-thread")))]
+compile_error!("the `token-refresher` feature requires the `multi-thread` feature");
+#[cfg(all(feature = "token-refresher", feature = "multi-thread"))]
+pub mod token_refresher;
 #[cfg(feature = "utils")]
 pub mod utils;
 
 pub use constants::*;
-pub use http_client::{HttpClient, HttpClientRef};
+pub use json_codec::JsonCodec;
+pub use http_client::{
+    DryRunResult, HttpClient, HttpClientRef, RedirectPolicy, RequestHook, RequestParts,
+    ResponseHook, ResponseParts,
+};
+#[cfg(feature = "retry")]
+pub use http_client::{RetryPolicy, RetryPredicate};
 pub use v5::MangaDexClient;
@@ -0,0 +1,31 @@
+//! Helpers shared by the endpoint tests.
+
+/// Assert that an endpoint's query string encodes to the expected key/value pairs.
+///
+/// The query is encoded the same way as when the request is sent, and the keys and values are
+/// compared after percent-decoding, so arrays are written as `ids[0]`, `ids[1]`, etc.
+///
+/// ```ignore
+/// assert_query_eq!(list_user, [("ids[0]", user_id.to_string()), ("limit", "10".to_string())]);
+/// ```
+macro_rules! assert_query_eq {
+    ($endpoint:expr, [$(($key:expr, $value:expr)),* $(,)?]) => {{
+        use mangadex_api_schema::{Endpoint as _, UrlSerdeQS as _};
+
+        let endpoint = &$endpoint;
+        let url = url::Url::parse("https://api.mangadex.org/").unwrap().query_qs(
+            endpoint
+                .query()
+                .expect("the endpoint should have a query string"),
+        );
+        let actual: std::collections::BTreeMap<String, String> =
+            url.query_pairs().into_owned().collect();
+        let expected: std::collections::BTreeMap<String, String> = [
+            $((String::from($key), String::from($value)),)*
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual, expected, "query string: {:?}", url.query());
+    }};
+}
@@ -0,0 +1,152 @@
+//! Stub MangaDex responses in the tests of crates that use this one.
+//!
+//! [`MockMangaDexClient`] starts a [`wiremock::MockServer`] and points a [`MangaDexClient`] at it,
+//! so the code under test can be given the client and the responses it receives can be stubbed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mangadex_api::testing::MockMangaDexClient;
+//! use serde_json::json;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let mock = MockMangaDexClient::start().await?;
+//! mock.mock_user_list(json!({
+//!     "result": "ok",
+//!     "response": "collection",
+//!     "data": [],
+//!     "limit": 10,
+//!     "offset": 0,
+//!     "total": 0
+//! }))
+//! .await;
+//!
+//! let users = mock.client().user().list().build()?.send().await?;
+//! assert_eq!(users.total, 0);
+//! # Ok(())
+//! # }
+//! ```
+
+use mangadex_api_schema::v5::AuthTokens;
+use mangadex_api_types::error::Result;
+use serde::Serialize;
+use url::Url;
+use wiremock::matchers::{method as http_method, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{HttpClient, MangaDexClient};
+
+const UUID_PATTERN: &str = "[0-9a-fA-F-]+";
+
+/// A [`MangaDexClient`] that sends its requests to a local mock server.
+///
+/// The client is logged in with placeholder tokens so that the endpoints requiring
+/// authentication can be stubbed as well.
+///
+/// Requests that don't match any stubbed response get a `404 Not Found` response.
+pub struct MockMangaDexClient {
+    server: MockServer,
+    client: MangaDexClient,
+}
+
+impl std::fmt::Debug for MockMangaDexClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockMangaDexClient")
+            .field("server", &self.server.uri())
+            .field("client", &self.client)
+            .finish()
+    }
+}
+
+impl MockMangaDexClient {
+    /// Start a mock server and create a client for it.
+    pub async fn start() -> Result<Self> {
+        let server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+
+        Ok(Self {
+            server,
+            client: MangaDexClient::new_with_http_client(http_client),
+        })
+    }
+
+    /// Get the client to pass to the code under test.
+    pub fn client(&self) -> &MangaDexClient {
+        &self.client
+    }
+
+    /// Get the mock server, to mount custom [`Mock`]s or inspect the received requests.
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Respond to the requests with the given method and path with `response`.
+    ///
+    /// `path` is a regular expression matched against the request path.
+    pub async fn mock_response(&self, method: &str, path: &str, response: ResponseTemplate) {
+        Mock::given(http_method(method))
+            .and(path_regex(path))
+            .respond_with(response)
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Respond to the requests with the given method and path with a `200 OK` JSON body.
+    ///
+    /// `path` is a regular expression matched against the request path.
+    pub async fn mock_json(&self, method: &str, path: &str, body: impl Serialize) {
+        self.mock_response(method, path, ResponseTemplate::new(200).set_body_json(body))
+            .await;
+    }
+
+    /// Respond to the requests with the given method and path with a MangaDex error body.
+    ///
+    /// `path` is a regular expression matched against the request path.
+    pub async fn mock_error(&self, method: &str, path: &str, status: u16, detail: &str) {
+        let body = serde_json::json!({
+            "result": "error",
+            "errors": [
+                {
+                    "id": uuid::Uuid::new_v4(),
+                    "status": status,
+                    "title": "Mocked error",
+                    "detail": detail
+                }
+            ]
+        });
+
+        self.mock_response(
+            method,
+            path,
+            ResponseTemplate::new(status).set_body_json(body),
+        )
+        .await;
+    }
+
+    /// Respond to `GET /user` with `body`.
+    pub async fn mock_user_list(&self, body: impl Serialize) {
+        self.mock_json("GET", "^/user$", body).await;
+    }
+
+    /// Respond to `GET /manga` with `body`.
+    pub async fn mock_manga_list(&self, body: impl Serialize) {
+        self.mock_json("GET", "^/manga$", body).await;
+    }
+
+    /// Respond to `GET /manga/{id}` with `body`.
+    pub async fn mock_manga(&self, body: impl Serialize) {
+        self.mock_json("GET", &format!("^/manga/{UUID_PATTERN}$"), body)
+            .await;
+    }
+
+    /// Respond to `GET /chapter` with `body`.
+    pub async fn mock_chapter_list(&self, body: impl Serialize) {
+        self.mock_json("GET", "^/chapter$", body).await;
+    }
+}
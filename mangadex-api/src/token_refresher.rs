@@ -0,0 +1,397 @@
+//! Background task refreshing the session token shortly before it expires.
+//!
+//! The expiry is read from the `exp` claim of the session JWT, see
+//! [`AuthTokens::session_expiry()`]. The task uses the refresh token stored in the client and
+//! stops when the [`TokenRefresherHandle`] is dropped.
+//!
+//! The session is refreshed with the deprecated `< 5.9.0` `/auth/refresh` endpoint, which is why
+//! this feature enables `legacy-auth`. It will stop working once MangaDex removes that endpoint.
+//!
+//! The task is spawned on the Tokio runtime and shares the client with the rest of the program,
+//! so the `multi-thread` feature must be enabled as well. It is not enabled implicitly, to not
+//! change the client of the whole crate behind the user's back.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use mangadex_api::token_refresher::RefreshHook;
+//! use mangadex_api::MangaDexClient;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let _refresher = client
+//!     .token_refresher()
+//!     .on_refresh(Arc::new(|tokens: &_| println!("new tokens: {tokens:?}")) as RefreshHook)
+//!     .build()?
+//!     .start();
+//!
+//! // The session is refreshed in the background while `_refresher` is alive.
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use mangadex_api_schema::v5::AuthTokens;
+use mangadex_api_types::error::{Error, Result};
+use mangadex_api_types::{SharedClock, SystemClock};
+use tokio::task::JoinHandle;
+
+use crate::v5::MangaDexClient;
+use crate::HttpClientRef;
+
+/// Default time before the session expiry that the session is refreshed.
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Default wait before checking the tokens again after a failed refresh, or when there are none.
+pub const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Lifetime of a session token issued by MangaDex.
+pub const SESSION_TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Hook called with the new tokens after every refresh, such as to persist them.
+pub type RefreshHook = Arc<dyn Fn(&AuthTokens) + Send + Sync>;
+
+/// Hook called with the error of every failed refresh.
+pub type RefreshErrorHook = Arc<dyn Fn(&Error) + Send + Sync>;
+
+#[derive(Clone, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
+pub struct TokenRefresher {
+    /// This should never be set manually as this is only for internal use.
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    /// Time before the session expiry that the session is refreshed.
+    ///
+    /// This must be shorter than [`SESSION_TOKEN_LIFETIME`].
+    /// Defaults to [`DEFAULT_REFRESH_MARGIN`].
+    #[builder(default = "DEFAULT_REFRESH_MARGIN")]
+    pub margin: Duration,
+    /// Wait before checking the tokens again after a failed refresh, or when the client has no
+    /// tokens or the session token has no readable expiry.
+    ///
+    /// Defaults to [`DEFAULT_RETRY_INTERVAL`].
+    #[builder(default = "DEFAULT_RETRY_INTERVAL")]
+    pub retry_interval: Duration,
+    /// Hook called with the new tokens after every refresh.
+    #[builder(default)]
+    pub on_refresh: Option<RefreshHook>,
+    /// Hook called with the error of every failed refresh, such as to notice that the session
+    /// can't be kept alive.
+    #[builder(default)]
+    pub on_error: Option<RefreshErrorHook>,
+    /// Clock the session expiry is compared against.
+    ///
+    /// Defaults to [`SystemClock`].
+    #[builder(default = "Arc::new(SystemClock)")]
+    pub clock: SharedClock,
+}
+
+impl TokenRefresherBuilder {
+    fn validate(&self) -> Result<()> {
+        if self
+            .margin
+            .is_some_and(|margin| margin >= SESSION_TOKEN_LIFETIME)
+        {
+            return Err(Error::BuilderError {
+                field: "margin".to_string(),
+                message: "must be shorter than the session token lifetime".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for TokenRefresher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenRefresher")
+            .field("margin", &self.margin)
+            .field("retry_interval", &self.retry_interval)
+            .field("on_refresh", &self.on_refresh.is_some())
+            .field("on_error", &self.on_error.is_some())
+            .finish()
+    }
+}
+
+/// Handle to the token refresher task.
+///
+/// The task is stopped when the handle is dropped.
+#[derive(Debug)]
+pub struct TokenRefresherHandle {
+    task: JoinHandle<()>,
+}
+
+impl TokenRefresherHandle {
+    /// Stop the task.
+    pub fn abort(&self) {
+        self.task.abort();
+    }
+
+    /// Check if the task is stopped.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for TokenRefresherHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl TokenRefresher {
+    /// Spawn the refresher task on the current Tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// This panics if it is called outside of a Tokio runtime.
+    pub fn start(self) -> TokenRefresherHandle {
+        TokenRefresherHandle {
+            task: tokio::spawn(async move { self.run().await }),
+        }
+    }
+
+    async fn run(self) {
+        loop {
+            let tokens = self.http_client.lock().await.get_tokens().cloned();
+            let Some(wait) = tokens.and_then(|tokens| self.wait_before_refresh(&tokens)) else {
+                tokio::time::sleep(self.retry_interval).await;
+                continue;
+            };
+            tokio::time::sleep(wait).await;
+
+            #[allow(deprecated)]
+            let res = MangaDexClient::new_with_http_client_ref(self.http_client.clone())
+                .auth()
+                .refresh_token()
+                .build();
+            let res = match res {
+                Ok(mut refresh_token) => refresh_token.send().await,
+                Err(e) => Err(e),
+            };
+
+            match res {
+                Ok(res) => {
+                    if let Some(on_refresh) = &self.on_refresh {
+                        on_refresh(&res.token);
+                    }
+                    // The new session is already within the margin when the local clock is
+                    // ahead of the server, so wait instead of refreshing it again right away.
+                    if self.wait_before_refresh(&res.token) == Some(Duration::ZERO) {
+                        tokio::time::sleep(self.retry_interval).await;
+                    }
+                }
+                Err(e) => {
+                    if let Some(on_error) = &self.on_error {
+                        on_error(&e);
+                    }
+                    tokio::time::sleep(self.retry_interval).await;
+                }
+            }
+        }
+    }
+
+    /// Get the time left until the session should be refreshed, if its expiry can be read.
+    fn wait_before_refresh(&self, tokens: &AuthTokens) -> Option<Duration> {
+        let refresh_at = *tokens.session_expiry()?.as_ref() - self.margin;
+
+        Some(
+            (refresh_at - *self.clock.now().as_ref())
+                .try_into()
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use tokio::sync::mpsc;
+    use url::Url;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use mangadex_api_types::error::Error;
+    use mangadex_api_types::{FixedClock, MangaDexDateTime, SharedClock};
+
+    use super::{RefreshErrorHook, RefreshHook, SESSION_TOKEN_LIFETIME};
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+
+    /// Create an unsigned JWT expiring in the given number of seconds.
+    fn session_token(expires_in: i64) -> String {
+        let claims = json!({ "exp": OffsetDateTime::now_utc().unix_timestamp() + expires_in });
+
+        format!(
+            "{}.{}.signature",
+            URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    /// Create a client using the mock server, with a session expiring in the given number of
+    /// seconds.
+    fn mock_client(mock_server: &MockServer, expires_in: i64) -> anyhow::Result<MangaDexClient> {
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: session_token(expires_in),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+
+        Ok(MangaDexClient::new_with_http_client(http_client))
+    }
+
+    #[test]
+    fn token_refresher_rejects_a_margin_longer_than_the_session() {
+        let res = MangaDexClient::default()
+            .token_refresher()
+            .margin(SESSION_TOKEN_LIFETIME)
+            .build();
+
+        assert!(matches!(res, Err(Error::BuilderError { field, .. }) if field == "margin"));
+    }
+
+    #[tokio::test]
+    async fn token_refresher_reports_failed_refreshes() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let mangadex_client = mock_client(&mock_server, 0)?;
+
+        Mock::given(method("POST"))
+            .and(path("/auth/refresh"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "result": "error",
+                "errors": [{
+                    "id": "9d6e9c9a-0a0b-4b0c-8d4e-5f5a6b7c8d9e",
+                    "status": 401,
+                    "title": "Unauthorized",
+                    "detail": "Refresh token is invalid",
+                    "context": null
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let _refresher = mangadex_client
+            .token_refresher()
+            .on_error(Arc::new(move |e: &Error| {
+                let _ = sender.send(e.to_string());
+            }) as RefreshErrorHook)
+            .build()?
+            .start();
+
+        tokio::time::timeout(Duration::from_secs(1), receiver.recv())
+            .await?
+            .expect("the refresher stopped");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn token_refresher_waits_when_the_new_session_is_already_due() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let mangadex_client = mock_client(&mock_server, 3600)?;
+
+        Mock::given(method("POST"))
+            .and(path("/auth/refresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "token": {
+                    "session": session_token(3600),
+                    "refresh": "newrefreshtoken"
+                },
+                "message": "Token refreshed!"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // The local clock is a day ahead of the server, so every session looks expired.
+        let now = OffsetDateTime::now_utc() + time::Duration::days(1);
+        let refresher = mangadex_client
+            .token_refresher()
+            .retry_interval(Duration::from_secs(60))
+            .clock(Arc::new(FixedClock::new(MangaDexDateTime::new(&now))) as SharedClock)
+            .build()?
+            .start();
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        drop(refresher);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn token_refresher_refreshes_the_session_before_it_expires() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let mangadex_client = mock_client(&mock_server, 3)?;
+
+        let new_session = session_token(3600);
+        Mock::given(method("POST"))
+            .and(path("/auth/refresh"))
+            .and(body_json(json!({"token": "refreshtoken"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "token": {
+                    "session": new_session,
+                    "refresh": "newrefreshtoken"
+                },
+                "message": "Token refreshed!"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let refresher = mangadex_client
+            .token_refresher()
+            .margin(Duration::from_secs(2))
+            .on_refresh(Arc::new(move |tokens: &AuthTokens| {
+                let _ = sender.send(tokens.clone());
+            }) as RefreshHook)
+            .build()?
+            .start();
+
+        // The session is refreshed 2 seconds before it expires, so 1 second from now.
+        let tokens = tokio::time::timeout(Duration::from_millis(2500), receiver.recv())
+            .await?
+            .expect("the refresher stopped");
+        assert_eq!(tokens.session, new_session);
+        assert_eq!(
+            mangadex_client
+                .get_http_client()
+                .lock()
+                .await
+                .get_tokens()
+                .map(|tokens| tokens.refresh.as_str()),
+            Some("newrefreshtoken")
+        );
+
+        drop(refresher);
+
+        Ok(())
+    }
+}
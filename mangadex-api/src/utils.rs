@@ -1,8 +1,17 @@
+use std::collections::{HashMap, HashSet};
+
+use mangadex_api_schema::v5::{ChapterObject, MangaObject};
+use mangadex_api_types::error::Result;
+use mangadex_api_types::ContentRating;
 use reqwest::Client;
+use uuid::Uuid;
 
-use crate::MangaDexClient;
+use crate::{HttpClientRef, MangaDexClient};
 
 pub mod download;
+pub mod optimistic_update;
+pub mod reader;
+pub mod updates;
 
 /// Gives you the `reqwest::Client` from the `MangaDexClient`
 /// Comes handy when you don't want to build a new `reqwest` Client
@@ -16,3 +25,196 @@ pub async fn get_reqwest_client(client: &MangaDexClient) -> Client {
         client.get_http_client().lock().await.client.clone()
     }
 }
+
+/// Maximum number of manga IDs fetched per manga list request.
+const MANGA_IDS_PER_REQUEST: usize = 100;
+
+/// Helpers that compose several endpoints.
+#[derive(Debug)]
+pub struct UtilsBuilder {
+    http_client: HttpClientRef,
+}
+
+impl UtilsBuilder {
+    #[doc(hidden)]
+    pub(crate) fn new(http_client: HttpClientRef) -> Self {
+        Self { http_client }
+    }
+
+    /// Fetch the manga the given chapters belong to, keyed by manga ID.
+    ///
+    /// The distinct manga IDs are read from the `manga` relationship of the chapters and fetched
+    /// with as few manga list requests as possible, instead of one request per chapter. Manga that
+    /// MangaDex doesn't return, such as deleted ones, are missing from the map.
+    pub async fn resolve_manga_for_chapters(
+        &self,
+        chapters: &[ChapterObject],
+    ) -> Result<HashMap<Uuid, MangaObject>> {
+        let mut seen = HashSet::new();
+        let manga_ids: Vec<Uuid> = chapters
+            .iter()
+            .filter_map(|chapter| chapter.relationships.manga())
+            .map(|manga| manga.id)
+            .filter(|manga_id| seen.insert(*manga_id))
+            .collect();
+
+        let client = MangaDexClient::new_with_http_client_ref(self.http_client.clone());
+        let mut manga = HashMap::with_capacity(manga_ids.len());
+        for manga_ids in manga_ids.chunks(MANGA_IDS_PER_REQUEST) {
+            let res = client
+                .manga()
+                .list()
+                .manga_ids(manga_ids.to_vec())
+                .limit(manga_ids.len() as u32)
+                // Every rating is requested so the default filter doesn't leave any manga out.
+                .content_rating(vec![
+                    ContentRating::Safe,
+                    ContentRating::Suggestive,
+                    ContentRating::Erotica,
+                    ContentRating::Pornographic,
+                ])
+                .build()?
+                .send()
+                .await?;
+
+            manga.extend(res.data.into_iter().map(|object| (object.id, object)));
+        }
+
+        Ok(manga)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_schema::v5::ChapterObject;
+
+    fn chapter(manga_id: Uuid) -> ChapterObject {
+        serde_json::from_value(json!({
+            "id": Uuid::new_v4(),
+            "type": "chapter",
+            "attributes": {
+                "title": "",
+                "volume": "1",
+                "chapter": "1",
+                "pages": 4,
+                "translatedLanguage": "en",
+                "uploader": Uuid::nil(),
+                "version": 1,
+                "createdAt": "2023-01-01T00:00:00+00:00",
+                "updatedAt": "2023-01-01T00:00:00+00:00",
+                "publishAt": "2023-01-01T00:00:00+00:00",
+                "readableAt": "2023-01-01T00:00:00+00:00",
+            },
+            "relationships": [
+                {
+                    "id": manga_id,
+                    "type": "manga"
+                }
+            ],
+        }))
+        .unwrap()
+    }
+
+    fn manga_json(manga_id: &str) -> serde_json::Value {
+        json!({
+            "id": manga_id,
+            "type": "manga",
+            "attributes": {
+                "title": {
+                    "en": format!("Manga {manga_id}")
+                },
+                "altTitles": [],
+                "description": [],
+                "isLocked": false,
+                "links": null,
+                "originalLanguage": "ja",
+                "lastVolume": null,
+                "lastChapter": null,
+                "publicationDemographic": null,
+                "status": "ongoing",
+                "year": null,
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": false,
+                "availableTranslatedLanguages": [],
+                "tags": [],
+                "state": "published",
+                "createdAt": "2022-01-01T00:00:00+00:00",
+                "updatedAt": "2022-01-01T00:00:00+00:00",
+                "version": 1
+            },
+            "relationships": []
+        })
+    }
+
+    #[tokio::test]
+    async fn resolve_manga_for_chapters_fetches_the_distinct_manga_in_one_request(
+    ) -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let first_manga_id = Uuid::new_v4();
+        let second_manga_id = Uuid::new_v4();
+        let chapters = vec![
+            chapter(first_manga_id),
+            chapter(second_manga_id),
+            chapter(first_manga_id),
+            chapter(second_manga_id),
+        ];
+
+        Mock::given(method("GET"))
+            .and(path("/manga"))
+            .respond_with(|req: &Request| {
+                let data: Vec<_> = req
+                    .url
+                    .query_pairs()
+                    .filter(|(key, _)| key.starts_with("ids["))
+                    .map(|(_, manga_id)| manga_json(&manga_id))
+                    .collect();
+
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "result": "ok",
+                    "response": "collection",
+                    "total": data.len(),
+                    "data": data,
+                    "limit": 100,
+                    "offset": 0
+                }))
+            })
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let manga = mangadex_client
+            .utils()
+            .resolve_manga_for_chapters(&chapters)
+            .await?;
+
+        assert_eq!(manga.len(), 2);
+        for manga_id in [first_manga_id, second_manga_id] {
+            assert_eq!(
+                manga[&manga_id].attributes.title.get_or_fallback(&[]),
+                Some(format!("Manga {manga_id}").as_str())
+            );
+        }
+
+        let requests = mock_server.received_requests().await.unwrap_or_default();
+        let requested_ids = requests[0]
+            .url
+            .query_pairs()
+            .filter(|(key, _)| key.starts_with("ids["))
+            .count();
+        assert_eq!(requested_ids, 2);
+
+        Ok(())
+    }
+}
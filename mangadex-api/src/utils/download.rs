@@ -1,5 +1,7 @@
+pub mod all_covers;
 pub mod chapter;
 pub mod cover;
+pub mod resumable;
 
 use bytes::Bytes;
 use uuid::Uuid;
@@ -0,0 +1,212 @@
+//! Download every cover of a manga, a few at a time.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use futures::StreamExt;
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::MangaDexClient;
+//! use mangadex_api_types::CoverSize;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! // Official Test Manga ID.
+//! let manga_id = Uuid::parse_str("f9c33607-9180-4ba6-b85c-e4b5faee7192")?;
+//!
+//! let download = client
+//!     .cover()
+//!     .download_all()
+//!     .manga_id(manga_id)
+//!     .size(CoverSize::Medium)
+//!     .concurrency(4_usize)
+//!     .build()?;
+//! let covers = download.send();
+//! futures::pin_mut!(covers);
+//!
+//! while let Some(cover) = covers.next().await {
+//!     let (cover, bytes) = cover?;
+//!     println!("{}: {} bytes", cover.attributes.file_name, bytes.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use async_stream::try_stream;
+use bytes::Bytes;
+use derive_builder::Builder;
+use futures::StreamExt;
+use mangadex_api_schema::v5::CoverObject;
+use mangadex_api_types::error::{Error, Result};
+use mangadex_api_types::CoverSize;
+use tokio_stream::Stream;
+use url::Url;
+use uuid::Uuid;
+
+use crate::utils::download::resumable::{download_resumable, DEFAULT_MAX_RETRIES};
+use crate::utils::get_reqwest_client;
+use crate::v5::cover::urls::{cover_url, COVERS_PER_PAGE};
+use crate::{HttpClientRef, MangaDexClient, CDN_URL};
+
+#[derive(Clone, Debug, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
+pub struct DownloadAllCovers {
+    /// This should never be set manually as this is only for internal use.
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    pub manga_id: Uuid,
+    #[builder(default)]
+    pub size: CoverSize,
+    /// Maximum number of covers downloaded at the same time.
+    ///
+    /// Defaults to 4. `0` is treated as 1.
+    #[builder(default = "4")]
+    pub concurrency: usize,
+    /// Host serving the cover images.
+    ///
+    /// Defaults to [`CDN_URL`].
+    #[builder(default = "Url::parse(CDN_URL).expect(\"CDN_URL is a valid URL\")")]
+    pub cdn_url: Url,
+}
+
+impl DownloadAllCovers {
+    /// List the covers of the manga, then download them concurrently.
+    ///
+    /// The covers are yielded in the order they were listed in, with the bytes of the image.
+    pub fn send(&self) -> impl Stream<Item = Result<(CoverObject, Bytes)>> + '_ {
+        try_stream! {
+            let client = MangaDexClient::new_with_http_client_ref(self.http_client.clone());
+            let covers = client
+                .cover()
+                .list()
+                .add_manga_id(self.manga_id)
+                .limit(COVERS_PER_PAGE)
+                .build()?
+                .collect_all()
+                .await?;
+            let reqwest_client = get_reqwest_client(&client).await;
+
+            let downloads = futures::stream::iter(covers)
+                .map(|cover| {
+                    let reqwest_client = reqwest_client.clone();
+
+                    async move {
+                        let url = cover_url(
+                            &self.cdn_url,
+                            self.manga_id,
+                            &cover.attributes.file_name,
+                            self.size,
+                        )?;
+                        let bytes =
+                            download_resumable(&reqwest_client, url, DEFAULT_MAX_RETRIES).await?;
+
+                        Ok::<_, Error>((cover, bytes))
+                    }
+                })
+                .buffered(self.concurrency.max(1));
+
+            for await download in downloads {
+                yield download?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::CoverSize;
+
+    fn cover_json(cover_id: Uuid, file_name: &str, volume: &str) -> serde_json::Value {
+        json!({
+            "id": cover_id,
+            "type": "cover_art",
+            "attributes": {
+                "volume": volume,
+                "fileName": file_name,
+                "description": "",
+                "locale": "ja",
+                "version": 1,
+                "createdAt": "2021-05-24T17:03:01+00:00",
+                "updatedAt": "2021-05-24T17:03:01+00:00"
+            },
+            "relationships": []
+        })
+    }
+
+    #[tokio::test]
+    async fn download_all_covers_fetches_every_listed_cover() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_id = Uuid::new_v4();
+        let first_cover_id = Uuid::new_v4();
+        let second_cover_id = Uuid::new_v4();
+
+        Mock::given(method("GET"))
+            .and(path("/cover"))
+            .and(query_param("manga[0]", manga_id.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [
+                    cover_json(first_cover_id, "first.jpg", "1"),
+                    cover_json(second_cover_id, "second.png", "2")
+                ],
+                "limit": 100,
+                "offset": 0,
+                "total": 2
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/covers/{manga_id}/first.jpg.512.jpg")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"first".to_vec()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/covers/{manga_id}/second.png.512.jpg")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"second".to_vec()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let download = mangadex_client
+            .cover()
+            .download_all()
+            .manga_id(manga_id)
+            .size(CoverSize::Medium)
+            .concurrency(2_usize)
+            .cdn_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let covers: Vec<_> = download.send().collect().await;
+        let covers = covers.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(covers.len(), 2);
+        assert_eq!(covers[0].0.id, first_cover_id);
+        assert_eq!(covers[0].1.as_ref(), b"first");
+        assert_eq!(covers[1].0.id, second_cover_id);
+        assert_eq!(covers[1].1.as_ref(), b"second");
+
+        Ok(())
+    }
+}
@@ -23,7 +23,11 @@ pub use pre_download::AtHomePreDownloadImageData;
 pub use report::AtHomeReport;
 
 #[derive(Clone, Builder)]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct ChapterDownload {
     #[doc(hidden)]
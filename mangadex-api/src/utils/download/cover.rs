@@ -6,12 +6,13 @@ use mangadex_api_schema::{
 };
 use mangadex_api_types::{
     error::{Error, Result},
-    ReferenceExpansionResource, RelationshipType, ChapterSortOrder, OrderDirection,
+    ReferenceExpansionResource, RelationshipType, CoverSortOrder, OrderDirection,
 };
 use reqwest::Client;
 use url::Url;
 use uuid::Uuid;
 
+use super::resumable::{download_resumable, DEFAULT_MAX_RETRIES};
 use super::DownloadElement;
 
 #[derive(Clone)]
@@ -59,14 +60,7 @@ pub async fn download_cover(
                 Ok(d) => d,
                 Err(e) => return Err(Error::ParseError(e.to_string())),
             };
-    let res = match client.get(cover_url).send().await {
-        Err(e) => return Err(Error::RequestError(e)),
-        Ok(d) => d,
-    };
-    let bytes = match res.bytes().await {
-        Err(e) => return Err(Error::RequestError(e)),
-        Ok(d) => d,
-    };
+    let bytes = download_resumable(client, cover_url, DEFAULT_MAX_RETRIES).await?;
     Ok((file_name, Some(bytes)))
 }
 
@@ -174,7 +168,7 @@ pub async fn download_via_manga_api_object(
         }
         // Getting the file name via the list of the manga cover ordered by volume `desc` otherwise
         None => {
-            match mangadex_api_client.cover().list().add_manga_id(&manga.id).order(ChapterSortOrder::Volume(OrderDirection::Descending)).build(){
+            match mangadex_api_client.cover().list().add_manga_id(&manga.id).order(CoverSortOrder::Volume(OrderDirection::Descending)).build(){
                 Ok(d) => match d.send().await?.data.first() {
                     None => return Err(Error::UnexpectedError(anyhow::Error::msg("can't find the first cover of this manga"))),
                     Some(cover) => cover.attributes.file_name.clone()
@@ -203,7 +197,11 @@ pub async fn download_via_manga_id(
 }
 
 #[derive(Clone, Builder)]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct CoverDownload{
     #[doc(hidden)]
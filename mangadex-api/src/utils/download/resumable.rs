@@ -0,0 +1,146 @@
+//! Download a file, resuming it with a range request when the transfer breaks.
+//!
+//! If the server advertises `Accept-Ranges: bytes`, a transfer that fails midway is continued
+//! with a `Range: bytes=<received>-` request instead of starting over. Otherwise, the download
+//! restarts from the beginning.
+
+use bytes::{Bytes, BytesMut};
+use mangadex_api_types::error::{Error, Result};
+use reqwest::header::{ACCEPT_RANGES, RANGE};
+use reqwest::{Client, StatusCode};
+use url::Url;
+
+/// Number of times a broken transfer is resumed before giving up.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Download the file at `url`, resuming the transfer up to `max_retries` times if it breaks.
+///
+/// Only errors of the transfer itself are retried; an error status is returned right away.
+pub async fn download_resumable(client: &Client, url: Url, max_retries: u32) -> Result<Bytes> {
+    let mut received = BytesMut::new();
+    let mut accepts_ranges = false;
+    let mut retries = 0;
+
+    loop {
+        match fetch_into(client, &url, &mut received, &mut accepts_ranges).await {
+            Ok(()) => return Ok(received.freeze()),
+            Err(Error::RequestError(e)) if retries < max_retries && !e.is_status() => {
+                retries += 1;
+                if !accepts_ranges {
+                    received.clear();
+                }
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Fetch the rest of the file into `received`, starting from the bytes already there.
+async fn fetch_into(
+    client: &Client,
+    url: &Url,
+    received: &mut BytesMut,
+    accepts_ranges: &mut bool,
+) -> Result<()> {
+    let mut request = client.get(url.clone());
+    if !received.is_empty() {
+        request = request.header(RANGE, format!("bytes={}-", received.len()));
+    }
+
+    let mut res = request.send().await?.error_for_status()?;
+
+    if res.status() == StatusCode::PARTIAL_CONTENT {
+        *accepts_ranges = true;
+    } else {
+        // The range was ignored, so the whole file is sent again.
+        received.clear();
+        *accepts_ranges = res
+            .headers()
+            .get(ACCEPT_RANGES)
+            .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"bytes"));
+    }
+
+    while let Some(chunk) = res.chunk().await? {
+        received.extend_from_slice(&chunk);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use url::Url;
+
+    use super::download_resumable;
+
+    const BODY: &[u8] = b"0123456789abcdefghij";
+
+    async fn read_request(stream: &mut TcpStream) -> anyhow::Result<String> {
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = stream.read(&mut buf).await?;
+            anyhow::ensure!(read > 0, "the connection was closed");
+            request.extend_from_slice(&buf[..read]);
+        }
+
+        Ok(String::from_utf8(request)?.to_lowercase())
+    }
+
+    #[tokio::test]
+    async fn download_resumable_continues_a_broken_transfer_with_a_range_request(
+    ) -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = Url::parse(&format!("http://{}/cover.jpg", listener.local_addr()?))?;
+        let half = BODY.len() / 2;
+
+        let server = tokio::spawn(async move {
+            // Send the first half of the file, then drop the connection.
+            let (mut stream, _) = listener.accept().await?;
+            let request = read_request(&mut stream).await?;
+            anyhow::ensure!(!request.contains("range:"), "unexpected range: {request}");
+            stream
+                .write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nAccept-Ranges: bytes\r\n\r\n",
+                        BODY.len()
+                    )
+                    .as_bytes(),
+                )
+                .await?;
+            stream.write_all(&BODY[..half]).await?;
+            drop(stream);
+
+            // Send the rest of the file to the range request.
+            let (mut stream, _) = listener.accept().await?;
+            let request = read_request(&mut stream).await?;
+            anyhow::ensure!(
+                request.contains(&format!("range: bytes={half}-")),
+                "expected a range request: {request}"
+            );
+            stream
+                .write_all(
+                    format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {half}-{}/{}\r\nConnection: close\r\n\r\n",
+                        BODY.len() - half,
+                        BODY.len() - 1,
+                        BODY.len()
+                    )
+                    .as_bytes(),
+                )
+                .await?;
+            stream.write_all(&BODY[half..]).await?;
+
+            Ok(())
+        });
+
+        let bytes = download_resumable(&reqwest::Client::new(), url, 1).await?;
+        server.await??;
+
+        assert_eq!(bytes.as_ref(), BODY);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+//! Retry an update when the entity was changed since it was fetched.
+//!
+//! Every update endpoint requires the current `version` of the entity, and MangaDex responds with
+//! `409 Conflict` when it doesn't match.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::utils::optimistic_update::optimistic_update;
+//! use mangadex_api::v5::MangaDexClient;
+//! use mangadex_api_types::MangaStatus;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//! let manga_id = Uuid::new_v4();
+//!
+//! let updated = optimistic_update(
+//!     || async { client.manga().get().manga_id(manga_id).build()?.send().await },
+//!     |manga| {
+//!         let client = &client;
+//!         async move {
+//!             client
+//!                 .manga()
+//!                 .update()
+//!                 .manga_id(manga_id)
+//!                 .status(MangaStatus::Completed)
+//!                 .version(manga.data.attributes.version)
+//!                 .build()?
+//!                 .send()
+//!                 .await
+//!         }
+//!     },
+//! )
+//! .await?;
+//!
+//! println!("new version: {}", updated.data.attributes.version);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use mangadex_api_types::error::Result;
+
+/// Fetch the current entity and apply the mutation, fetching and retrying once on `409 Conflict`.
+///
+/// `fetch` gets the entity with its current `version` and `mutate` sends the update built from it.
+/// Any other error, or a second conflict, is returned as-is.
+pub async fn optimistic_update<T, R, F, FFut, M, MFut>(mut fetch: F, mut mutate: M) -> Result<R>
+where
+    F: FnMut() -> FFut,
+    FFut: Future<Output = Result<T>>,
+    M: FnMut(T) -> MFut,
+    MFut: Future<Output = Result<R>>,
+{
+    let current = fetch().await?;
+
+    match mutate(current).await {
+        Err(e) if e.is_conflict() => {
+            let current = fetch().await?;
+            mutate(current).await
+        }
+        res => res,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{body_partial_json, method, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::optimistic_update;
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::MangaStatus;
+
+    fn manga_body(manga_id: Uuid, version: u32) -> serde_json::Value {
+        json!({
+            "result": "ok",
+            "response": "entity",
+            "data": {
+                "id": manga_id,
+                "type": "manga",
+                "attributes": {
+                    "title": {
+                        "en": "Test Manga"
+                    },
+                    "altTitles": [],
+                    "description": [],
+                    "isLocked": false,
+                    "links": null,
+                    "originalLanguage": "ja",
+                    "lastVolume": null,
+                    "lastChapter": null,
+                    "publicationDemographic": null,
+                    "status": "ongoing",
+                    "year": null,
+                    "contentRating": "safe",
+                    "chapterNumbersResetOnNewVolume": false,
+                    "availableTranslatedLanguages": [],
+                    "tags": [],
+                    "state": "published",
+                    "createdAt": "2022-01-01T00:00:00+00:00",
+                    "updatedAt": "2022-01-01T00:00:00+00:00",
+                    "version": version
+                },
+                "relationships": []
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn optimistic_update_refetches_and_retries_once_on_conflict() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_id = Uuid::new_v4();
+        let conflict_body = json!({
+            "result": "error",
+            "errors": [
+                {
+                    "id": Uuid::new_v4(),
+                    "status": 409,
+                    "title": "Conflict",
+                    "detail": "Version mismatch"
+                }
+            ]
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(manga_body(manga_id, 1)))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(manga_body(manga_id, 2)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+"))
+            .and(body_partial_json(json!({"version": 1})))
+            .respond_with(ResponseTemplate::new(409).set_body_json(conflict_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+"))
+            .and(body_partial_json(json!({"version": 2})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(manga_body(manga_id, 3)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let updated = optimistic_update(
+            || async {
+                mangadex_client
+                    .manga()
+                    .get()
+                    .manga_id(manga_id)
+                    .build()?
+                    .send()
+                    .await
+            },
+            |manga| {
+                let mangadex_client = &mangadex_client;
+                async move {
+                    mangadex_client
+                        .manga()
+                        .update()
+                        .manga_id(manga_id)
+                        .status(MangaStatus::Completed)
+                        .version(manga.data.attributes.version)
+                        .build()?
+                        .send()
+                        .await
+                }
+            },
+        )
+        .await?;
+
+        assert_eq!(updated.data.attributes.version, 3);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+pub mod chapter_bundle;
+pub mod download_manga;
+pub mod page_prefetcher;
+
+use std::time::Duration;
+
+use mangadex_api_schema::v5::AtHomeServer;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::Quality;
+use url::Url;
+use uuid::Uuid;
+
+use crate::{HttpClientRef, MangaDexClient};
+
+use self::chapter_bundle::ChapterBundleRequestBuilder;
+use self::download_manga::DownloadMangaRequestBuilder;
+
+/// Helpers that compose several endpoints to get everything needed to display content.
+#[derive(Debug)]
+pub struct ReaderBuilder {
+    http_client: HttpClientRef,
+}
+
+impl ReaderBuilder {
+    #[doc(hidden)]
+    pub(crate) fn new(http_client: HttpClientRef) -> Self {
+        Self { http_client }
+    }
+
+    /// Fetch a chapter, its scanlation group and its MangaDex@Home page URLs in one call.
+    pub fn chapter_bundle(&self) -> ChapterBundleRequestBuilder {
+        ChapterBundleRequestBuilder::default().http_client(self.http_client.clone())
+    }
+
+    /// Download every chapter of a manga to disk, in reading order.
+    pub fn download_manga(&self) -> DownloadMangaRequestBuilder {
+        DownloadMangaRequestBuilder::default().http_client(self.http_client.clone())
+    }
+}
+
+/// How long before it expires the MangaDex@Home server of a chapter is requested again.
+///
+/// This leaves time for the pages that are being fetched to complete.
+const AT_HOME_SERVER_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// MangaDex@Home server of a chapter that is requested again when it's about to expire.
+#[derive(Clone, Debug)]
+pub(crate) struct ChapterServer {
+    client: MangaDexClient,
+    chapter_id: Uuid,
+    force_port_443: bool,
+    server: AtHomeServer,
+}
+
+impl ChapterServer {
+    /// Request the MangaDex@Home server of the chapter.
+    pub(crate) async fn fetch(
+        client: MangaDexClient,
+        chapter_id: Uuid,
+        force_port_443: bool,
+    ) -> Result<Self> {
+        let server = fetch_server(&client, chapter_id, force_port_443).await?;
+
+        Ok(Self::new(client, chapter_id, force_port_443, server))
+    }
+
+    pub(crate) fn new(
+        client: MangaDexClient,
+        chapter_id: Uuid,
+        force_port_443: bool,
+        server: AtHomeServer,
+    ) -> Self {
+        Self {
+            client,
+            chapter_id,
+            force_port_443,
+            server,
+        }
+    }
+
+    /// Request the server again if it expires soon.
+    ///
+    /// Returns `true` if the server was refreshed, meaning that the page URLs have changed.
+    pub(crate) async fn refresh_if_expiring(&mut self) -> Result<bool> {
+        if !self.server.expires_within(AT_HOME_SERVER_REFRESH_MARGIN) {
+            return Ok(false);
+        }
+
+        self.server = fetch_server(&self.client, self.chapter_id, self.force_port_443).await?;
+
+        Ok(true)
+    }
+
+    pub(crate) fn page_urls(&self, quality: Quality) -> Vec<Url> {
+        self.server.page_urls(quality)
+    }
+}
+
+async fn fetch_server(
+    client: &MangaDexClient,
+    chapter_id: Uuid,
+    force_port_443: bool,
+) -> Result<AtHomeServer> {
+    client
+        .at_home()
+        .server()
+        .chapter_id(chapter_id)
+        .force_port_443(force_port_443)
+        .build()?
+        .send()
+        .await
+}
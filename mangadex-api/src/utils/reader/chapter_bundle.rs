@@ -0,0 +1,245 @@
+//! Fetch everything needed to display a chapter.
+//!
+//! This combines the following endpoints:
+//!
+//! - <https://api.mangadex.org/swagger.html#/Chapter/get-chapter-id>
+//! - <https://api.mangadex.org/swagger.html#/AtHome/get-at-home-server-chapterId>
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::v5::MangaDexClient;
+//! use mangadex_api_types::Quality;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let chapter_id = Uuid::new_v4();
+//! let bundle = client
+//!     .reader()
+//!     .chapter_bundle()
+//!     .chapter_id(chapter_id)
+//!     .quality(Quality::DataSaver)
+//!     .build()?
+//!     .send()
+//!     .await?;
+//!
+//! println!("Pages: {:?}", bundle.pages);
+//! # Ok(())
+//! # }
+//! ```
+
+use derive_builder::Builder;
+use mangadex_api_schema::v5::{ChapterData, GroupObject, RelatedAttributes};
+use mangadex_api_schema::ApiObject;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::{Quality, ReferenceExpansionResource, RelationshipType};
+use url::Url;
+use uuid::Uuid;
+
+use crate::{HttpClientRef, MangaDexClient};
+
+/// Everything needed to display a chapter.
+#[derive(Clone, Debug)]
+pub struct ChapterBundle {
+    /// The chapter with its scanlation group relationships expanded.
+    pub chapter: ChapterData,
+    /// MangaDex@Home URLs of the chapter pages, in reading order.
+    ///
+    /// These URLs are only valid for 15 minutes.
+    pub pages: Vec<Url>,
+    /// The first scanlation group credited for the chapter, if any.
+    pub group: Option<GroupObject>,
+}
+
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
+pub struct ChapterBundleRequest {
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    pub chapter_id: Uuid,
+    #[builder(default)]
+    pub quality: Quality,
+    /// Force selecting from MangaDex@Home servers that use the standard HTTPS port 443.
+    #[builder(default)]
+    pub force_port_443: bool,
+}
+
+impl ChapterBundleRequest {
+    /// Send the requests.
+    pub async fn send(&self) -> Result<ChapterBundle> {
+        let client = MangaDexClient::new_with_http_client_ref(self.http_client.clone());
+
+        let chapter = client
+            .chapter()
+            .get()
+            .chapter_id(self.chapter_id)
+            .include(ReferenceExpansionResource::ScanlationGroup)
+            .build()?
+            .send()
+            .await?;
+
+        let at_home = client
+            .at_home()
+            .server()
+            .chapter_id(self.chapter_id)
+            .force_port_443(self.force_port_443)
+            .build()?
+            .send()
+            .await?;
+
+        let pages = at_home.page_urls(self.quality);
+
+        let group = chapter.data.relationships.iter().find_map(|relationship| {
+            match (&relationship.type_, &relationship.attributes) {
+                (
+                    RelationshipType::ScanlationGroup,
+                    Some(RelatedAttributes::ScanlationGroup(attributes)),
+                ) => Some(ApiObject {
+                    id: relationship.id,
+                    type_: RelationshipType::ScanlationGroup,
+                    attributes: attributes.clone(),
+                    relationships: Default::default(),
+                }),
+                _ => None,
+            }
+        });
+
+        Ok(ChapterBundle {
+            chapter,
+            pages,
+            group,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path_regex, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::{MangaDexDateTime, Quality};
+
+    #[tokio::test]
+    async fn chapter_bundle_fetches_the_chapter_and_the_at_home_server() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let chapter_id = Uuid::new_v4();
+        let group_id = Uuid::new_v4();
+
+        let datetime = MangaDexDateTime::new(&OffsetDateTime::now_utc());
+
+        let chapter_body = json!({
+            "result": "ok",
+            "response": "entity",
+            "data": {
+                "id": chapter_id,
+                "type": "chapter",
+                "attributes": {
+                    "title": "Chapter title",
+                    "volume": "1",
+                    "chapter": "1",
+                    "pages": 2,
+                    "translatedLanguage": "en",
+                    "version": 1,
+                    "createdAt": datetime.to_string(),
+                    "updatedAt": datetime.to_string(),
+                    "publishAt": datetime.to_string(),
+                    "readableAt": datetime.to_string(),
+                },
+                "relationships": [
+                    {
+                        "id": group_id,
+                        "type": "scanlation_group",
+                        "attributes": {
+                            "name": "Group name",
+                            "altNames": [],
+                            "website": null,
+                            "ircServer": null,
+                            "ircChannel": null,
+                            "discord": null,
+                            "contactEmail": null,
+                            "description": null,
+                            "twitter": null,
+                            "mangaUpdates": null,
+                            "focusedLanguages": ["en"],
+                            "locked": false,
+                            "official": false,
+                            "verified": false,
+                            "inactive": false,
+                            "publishDelay": null,
+                            "version": 1,
+                            "createdAt": datetime.to_string(),
+                            "updatedAt": datetime.to_string(),
+                        }
+                    }
+                ]
+            }
+        });
+        let at_home_body = json!({
+            "result": "ok",
+            "baseUrl": "https://example.org",
+            "chapter": {
+                "hash": "abc123",
+                "data": ["1.png", "2.png"],
+                "dataSaver": ["1.jpg", "2.jpg"],
+            }
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/chapter/[0-9a-fA-F-]+"))
+            .and(query_param("includes[0]", "scanlation_group"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(chapter_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"/at-home/server/[0-9a-fA-F-]+"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(at_home_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bundle = mangadex_client
+            .reader()
+            .chapter_bundle()
+            .chapter_id(chapter_id)
+            .quality(Quality::DataSaver)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(bundle.chapter.data.id, chapter_id);
+        assert_eq!(
+            bundle.pages,
+            vec![
+                Url::parse("https://example.org/data-saver/abc123/1.jpg")?,
+                Url::parse("https://example.org/data-saver/abc123/2.jpg")?,
+            ]
+        );
+        let group = bundle
+            .group
+            .expect("the scanlation group should be expanded");
+        assert_eq!(group.id, group_id);
+        assert_eq!(group.attributes.name, "Group name");
+
+        Ok(())
+    }
+}
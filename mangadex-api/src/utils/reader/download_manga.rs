@@ -0,0 +1,372 @@
+//! Download every chapter of a manga to disk.
+//!
+//! This combines the following endpoints:
+//!
+//! - <https://api.mangadex.org/swagger.html#/Manga/get-manga-aggregate>
+//! - <https://api.mangadex.org/swagger.html#/AtHome/get-at-home-server-chapterId>
+//!
+//! The pages are written to `out_dir/{volume}/{chapter}/{page}.jpg`. The download fails if a volume
+//! or chapter name from MangaDex isn't a valid directory name, such as `..`.
+//! Chapters that already have a directory are skipped, so an interrupted download can be resumed.
+//! The MangaDex@Home server of a chapter is requested again when it's about to expire.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::path::PathBuf;
+//!
+//! use tokio::pin;
+//! use tokio_stream::StreamExt;
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::v5::MangaDexClient;
+//! use mangadex_api_types::{Language, Quality};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let manga_id = Uuid::new_v4();
+//! let request = client
+//!     .reader()
+//!     .download_manga()
+//!     .manga_id(manga_id)
+//!     .translated_language(Language::English)
+//!     .quality(Quality::DataSaver)
+//!     .out_dir(PathBuf::from("./downloads"))
+//!     .build()?;
+//!
+//! let progress = request.send().await?;
+//! pin!(progress);
+//! while let Some(progress) = progress.next().await {
+//!     println!("{:?}", progress?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+use std::path::{Component, Path, PathBuf};
+
+use async_stream::try_stream;
+use derive_builder::Builder;
+use mangadex_api_schema::v5::manga_aggregate::{ChapterAggregate, VolumeAggregate};
+use mangadex_api_types::error::Result;
+use mangadex_api_types::{Language, Quality};
+use tokio_stream::Stream;
+use uuid::Uuid;
+
+use crate::utils::get_reqwest_client;
+use crate::{HttpClientRef, MangaDexClient};
+
+use super::ChapterServer;
+
+/// Progress of a manga download.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DownloadProgress {
+    /// The chapter directory already exists so the chapter was not downloaded again.
+    ChapterSkipped { volume: String, chapter: String },
+    /// A page of the chapter was written to disk.
+    PageDownloaded {
+        volume: String,
+        chapter: String,
+        /// Page number, starting at 1.
+        page: usize,
+        /// Number of pages in the chapter.
+        pages: usize,
+    },
+    /// Every page of the chapter was written to disk.
+    ChapterDownloaded {
+        volume: String,
+        chapter: String,
+        path: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
+pub struct DownloadMangaRequest {
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    pub manga_id: Uuid,
+    /// Only the chapters translated in this language are downloaded.
+    pub translated_language: Language,
+    #[builder(default)]
+    pub quality: Quality,
+    /// Directory the volume directories are created in.
+    pub out_dir: PathBuf,
+    /// Force selecting from MangaDex@Home servers that use the standard HTTPS port 443.
+    #[builder(default)]
+    pub force_port_443: bool,
+}
+
+impl DownloadMangaRequest {
+    /// Fetch the manga aggregate and get a stream that downloads the chapters as it is polled.
+    ///
+    /// The stream ends after the first error.
+    pub async fn send(&self) -> Result<impl Stream<Item = Result<DownloadProgress>> + '_> {
+        let client = MangaDexClient::new_with_http_client_ref(self.http_client.clone());
+
+        let aggregate = client
+            .manga()
+            .aggregate()
+            .manga_id(self.manga_id)
+            .add_language(self.translated_language)
+            .build()?
+            .send()
+            .await?;
+        let chapters = reading_order(aggregate.volumes);
+        let http_client = get_reqwest_client(&client).await;
+
+        Ok(try_stream! {
+            for (volume, chapter) in chapters {
+                let volume_dir = self.out_dir.join(path_component(&volume)?);
+                let chapter_dir = volume_dir.join(path_component(&chapter.chapter)?);
+                if tokio::fs::try_exists(&chapter_dir).await? {
+                    yield DownloadProgress::ChapterSkipped {
+                        volume,
+                        chapter: chapter.chapter,
+                    };
+                    continue;
+                }
+
+                let mut server =
+                    ChapterServer::fetch(client.clone(), chapter.id, self.force_port_443).await?;
+                let mut pages = server.page_urls(self.quality);
+
+                // Write the pages to a temporary directory so that a partially downloaded chapter
+                // isn't skipped on the next run.
+                let part_dir = volume_dir.join(format!("{}.part", chapter.chapter));
+                tokio::fs::create_dir_all(&part_dir).await?;
+                for index in 0..pages.len() {
+                    // Large chapters can take longer to download than the server is valid for.
+                    if server.refresh_if_expiring().await? {
+                        pages = server.page_urls(self.quality);
+                    }
+
+                    let page = index + 1;
+                    let bytes = http_client
+                        .get(pages[index].clone())
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .bytes()
+                        .await?;
+                    tokio::fs::write(part_dir.join(format!("{page}.jpg")), &bytes).await?;
+
+                    yield DownloadProgress::PageDownloaded {
+                        volume: volume.clone(),
+                        chapter: chapter.chapter.clone(),
+                        page,
+                        pages: pages.len(),
+                    };
+                }
+                tokio::fs::rename(&part_dir, &chapter_dir).await?;
+
+                yield DownloadProgress::ChapterDownloaded {
+                    volume,
+                    chapter: chapter.chapter,
+                    path: chapter_dir,
+                };
+            }
+        })
+    }
+}
+
+/// Check that a volume or chapter name from the server can be used as a single directory name.
+///
+/// Names that are empty, `.`, `..` or contain a path separator are rejected so the pages can't be
+/// written outside of `out_dir`.
+fn path_component(name: &str) -> std::io::Result<&str> {
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) if !name.contains(['/', '\\']) => Ok(name),
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{name:?} is not a valid directory name"),
+        )),
+    }
+}
+
+/// Flatten the aggregate into `(volume, chapter)` pairs sorted in reading order.
+///
+/// Volumes and chapters without a number, such as `none`, are placed last.
+fn reading_order(volumes: Vec<VolumeAggregate>) -> Vec<(String, ChapterAggregate)> {
+    let mut volumes = volumes;
+    volumes.sort_by(|a, b| compare_numbers(&a.volume, &b.volume));
+
+    volumes
+        .into_iter()
+        .flat_map(|volume| {
+            let mut chapters = volume.chapters;
+            chapters.sort_by(|a, b| compare_numbers(&a.chapter, &b.chapter));
+
+            let name = volume.volume;
+            chapters
+                .into_iter()
+                .map(move |chapter| (name.clone(), chapter))
+        })
+        .collect()
+}
+
+fn compare_numbers(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tokio_stream::StreamExt;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::{path_component, DownloadProgress};
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::{Language, Quality};
+
+    #[tokio::test]
+    async fn download_manga_writes_pages_by_volume_and_chapter() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let out_dir = std::env::temp_dir().join(format!("mangadex-api-{}", Uuid::new_v4()));
+        // Chapter 2 was downloaded by a previous run.
+        std::fs::create_dir_all(out_dir.join("1").join("2"))?;
+
+        let aggregate_body = json!({
+            "result": "ok",
+            "volumes": {
+                "none": {
+                    "volume": "none",
+                    "count": 1,
+                    "chapters": {
+                        "10": {
+                            "chapter": "10",
+                            "id": Uuid::new_v4(),
+                            "others": [],
+                            "count": 1
+                        }
+                    }
+                },
+                "1": {
+                    "volume": "1",
+                    "count": 2,
+                    "chapters": {
+                        "2": {
+                            "chapter": "2",
+                            "id": Uuid::new_v4(),
+                            "others": [],
+                            "count": 1
+                        },
+                        "1": {
+                            "chapter": "1",
+                            "id": Uuid::new_v4(),
+                            "others": [],
+                            "count": 1
+                        }
+                    }
+                }
+            }
+        });
+        let at_home_body = json!({
+            "result": "ok",
+            "baseUrl": mock_server.uri(),
+            "chapter": {
+                "hash": "abc123",
+                "data": ["1.png", "2.png"],
+                "dataSaver": ["1.jpg", "2.jpg"],
+            }
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+/aggregate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(aggregate_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"/at-home/server/[0-9a-fA-F-]+"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(at_home_body))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/data-saver/abc123/1.jpg"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"page 1".to_vec()))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/data-saver/abc123/2.jpg"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"page 2".to_vec()))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let request = mangadex_client
+            .reader()
+            .download_manga()
+            .manga_id(Uuid::new_v4())
+            .translated_language(Language::English)
+            .quality(Quality::DataSaver)
+            .out_dir(out_dir.clone())
+            .build()?;
+        let progress = request.send().await?;
+        tokio::pin!(progress);
+
+        let mut finished = Vec::new();
+        while let Some(progress) = progress.next().await {
+            match progress? {
+                DownloadProgress::ChapterSkipped { volume, chapter } => {
+                    assert_eq!((volume.as_str(), chapter.as_str()), ("1", "2"));
+                }
+                DownloadProgress::ChapterDownloaded { path, .. } => finished.push(path),
+                DownloadProgress::PageDownloaded { .. } => {}
+            }
+        }
+
+        assert_eq!(
+            finished,
+            vec![out_dir.join("1").join("1"), out_dir.join("none").join("10")]
+        );
+        assert_eq!(
+            std::fs::read(out_dir.join("1").join("1").join("1.jpg"))?,
+            b"page 1"
+        );
+        assert_eq!(
+            std::fs::read(out_dir.join("none").join("10").join("2.jpg"))?,
+            b"page 2"
+        );
+        assert_eq!(std::fs::read_dir(out_dir.join("1").join("2"))?.count(), 0);
+        assert!(!out_dir.join("1").join("1.part").exists());
+
+        std::fs::remove_dir_all(&out_dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_component_rejects_names_that_escape_the_out_dir() {
+        for name in ["1", "10.5", "none"] {
+            assert_eq!(path_component(name).ok(), Some(name));
+        }
+        for name in ["", ".", "..", "../1", "1/2", "1\\2", "/1"] {
+            assert!(path_component(name).is_err(), "{name:?} should be rejected");
+        }
+    }
+}
@@ -0,0 +1,332 @@
+//! Fetch the next pages of a chapter in the background while the current one is read.
+//!
+//! Use [`PagePrefetcher::for_chapter`] to have the MangaDex@Home server of the chapter requested
+//! again when it's about to expire, instead of the page URLs returning errors after 15 minutes.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::utils::get_reqwest_client;
+//! use mangadex_api::utils::reader::page_prefetcher::PagePrefetcher;
+//! use mangadex_api::v5::MangaDexClient;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let bundle = client
+//!     .reader()
+//!     .chapter_bundle()
+//!     .chapter_id(Uuid::new_v4())
+//!     .build()?
+//!     .send()
+//!     .await?;
+//!
+//! let mut prefetcher = PagePrefetcher::new(get_reqwest_client(&client).await, bundle.pages, 3);
+//! let first_page = prefetcher.get(0).await?;
+//! println!("first page: {} bytes", first_page.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use mangadex_api_types::error::{Error, Result};
+use mangadex_api_types::Quality;
+use reqwest::Client;
+use tokio::task::JoinHandle;
+use url::Url;
+use uuid::Uuid;
+
+use crate::utils::get_reqwest_client;
+use crate::MangaDexClient;
+
+use super::ChapterServer;
+
+/// Page cache that fetches the pages following the last read page in the background.
+///
+/// Reading a page starts fetching the next `look_ahead` pages.
+/// Fetches for pages outside of that window, such as pages the reader skipped past,
+/// are cancelled.
+#[derive(Debug)]
+pub struct PagePrefetcher {
+    client: Client,
+    pages: Vec<Url>,
+    look_ahead: usize,
+    prefetches: HashMap<usize, JoinHandle<Result<Bytes>>>,
+    server: Option<(ChapterServer, Quality)>,
+}
+
+impl PagePrefetcher {
+    /// Create a prefetcher for the page URLs of a chapter, in reading order.
+    pub fn new(client: Client, pages: Vec<Url>, look_ahead: usize) -> Self {
+        Self {
+            client,
+            pages,
+            look_ahead,
+            prefetches: HashMap::new(),
+            server: None,
+        }
+    }
+
+    /// Create a prefetcher for the pages of a chapter.
+    ///
+    /// The MangaDex@Home server of the chapter is requested, and requested again before a page is
+    /// read when the server is about to expire.
+    pub async fn for_chapter(
+        client: &MangaDexClient,
+        chapter_id: Uuid,
+        quality: Quality,
+        look_ahead: usize,
+    ) -> Result<Self> {
+        let server = ChapterServer::fetch(client.clone(), chapter_id, false).await?;
+
+        Self::from_server(
+            get_reqwest_client(client).await,
+            server,
+            quality,
+            look_ahead,
+        )
+    }
+
+    fn from_server(
+        client: Client,
+        server: ChapterServer,
+        quality: Quality,
+        look_ahead: usize,
+    ) -> Result<Self> {
+        let pages = server.page_urls(quality);
+
+        let mut prefetcher = Self::new(client, pages, look_ahead);
+        prefetcher.server = Some((server, quality));
+
+        Ok(prefetcher)
+    }
+
+    /// Get the number of pages in the chapter.
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Check if the chapter has no pages.
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    /// Get the indexes of the pages that are being fetched or were fetched in the background,
+    /// in ascending order.
+    pub fn prefetching(&self) -> Vec<usize> {
+        let mut indexes: Vec<usize> = self.prefetches.keys().copied().collect();
+        indexes.sort_unstable();
+        indexes
+    }
+
+    /// Get the bytes of a page.
+    ///
+    /// The prefetched bytes are returned if the page was fetched in the background,
+    /// otherwise the page is fetched now.
+    /// The pages after `index` are then prefetched, up to the look-ahead window.
+    pub async fn get(&mut self, index: usize) -> Result<Bytes> {
+        if let Some((server, quality)) = &mut self.server {
+            if server.refresh_if_expiring().await? {
+                self.pages = server.page_urls(*quality);
+                // The pages that are still being fetched use the expired URLs.
+                self.prefetches.retain(|_, prefetch| {
+                    if !prefetch.is_finished() {
+                        prefetch.abort();
+                    }
+                    prefetch.is_finished()
+                });
+            }
+        }
+
+        let url = self.pages.get(index).cloned().ok_or_else(|| {
+            Error::OutOfRange(format!(
+                "page {index} doesn't exist, the chapter has {} pages",
+                self.pages.len()
+            ))
+        })?;
+
+        let window = index + 1..=index.saturating_add(self.look_ahead);
+        let current = self.prefetches.remove(&index);
+
+        // Cancel the pages the reader skipped past or went back before.
+        self.prefetches.retain(|page, prefetch| {
+            let keep = window.contains(page);
+            if !keep {
+                prefetch.abort();
+            }
+            keep
+        });
+
+        for page in window.take_while(|page| *page < self.pages.len()) {
+            if !self.prefetches.contains_key(&page) {
+                let client = self.client.clone();
+                let url = self.pages[page].clone();
+                self.prefetches
+                    .insert(page, tokio::spawn(fetch_page(client, url)));
+            }
+        }
+
+        match current {
+            Some(prefetch) => prefetch.await.map_err(anyhow::Error::from)?,
+            None => fetch_page(self.client.clone(), url).await,
+        }
+    }
+}
+
+impl Drop for PagePrefetcher {
+    fn drop(&mut self) {
+        for prefetch in self.prefetches.values() {
+            prefetch.abort();
+        }
+    }
+}
+
+async fn fetch_page(client: Client, url: Url) -> Result<Bytes> {
+    Ok(client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mangadex_api_schema::v5::AtHomeServer;
+    use mangadex_api_types::{FixedClock, Quality};
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::PagePrefetcher;
+    use crate::utils::reader::ChapterServer;
+    use crate::{HttpClient, MangaDexClient};
+
+    #[tokio::test]
+    async fn reading_a_page_prefetches_the_look_ahead_window() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+
+        let pages = (0..5)
+            .map(|page| Url::parse(&format!("{}/data/abc123/{page}.png", mock_server.uri())))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (page, expected_requests) in [(0, 1), (1, 1), (2, 1), (3, 0), (4, 0)] {
+            Mock::given(method("GET"))
+                .and(path(format!("/data/abc123/{page}.png")))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_bytes(format!("page {page}").into_bytes()),
+                )
+                .expect(expected_requests)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let mut prefetcher = PagePrefetcher::new(reqwest::Client::new(), pages, 2);
+
+        assert_eq!(prefetcher.get(0).await?, "page 0".as_bytes());
+        assert_eq!(prefetcher.prefetching(), vec![1, 2]);
+
+        // Wait for the background fetches to reach the server.
+        for _ in 0..100 {
+            if mock_server
+                .received_requests()
+                .await
+                .unwrap_or_default()
+                .len()
+                == 3
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn skipping_ahead_cancels_the_skipped_prefetches() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+
+        let pages = (0..5)
+            .map(|page| Url::parse(&format!("{}/data/abc123/{page}.png", mock_server.uri())))
+            .collect::<Result<Vec<_>, _>>()?;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"page".to_vec()))
+            .mount(&mock_server)
+            .await;
+
+        let mut prefetcher = PagePrefetcher::new(reqwest::Client::new(), pages, 1);
+
+        prefetcher.get(0).await?;
+        assert_eq!(prefetcher.prefetching(), vec![1]);
+
+        prefetcher.get(3).await?;
+        assert_eq!(prefetcher.prefetching(), vec![4]);
+
+        // The last page has nothing to prefetch.
+        prefetcher.get(4).await?;
+        assert!(prefetcher.prefetching().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn an_expired_server_is_refreshed_before_the_next_page_fetch() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let at_home_body = |hash: &str| {
+            json!({
+                "result": "ok",
+                "baseUrl": mock_server.uri(),
+                "chapter": {
+                    "hash": hash,
+                    "data": ["1.png"],
+                    "dataSaver": ["1.jpg"],
+                }
+            })
+        };
+        let mut expired: AtHomeServer = serde_json::from_value(at_home_body("expired"))?;
+        expired.fetched_at = serde_json::from_value(json!("2023-01-01T12:00:00+00:00"))?;
+        let clock = FixedClock::new(serde_json::from_value(json!("2023-01-01T12:15:00+00:00"))?);
+        assert!(expired.is_expired_with_clock(clock));
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/at-home/server/[0-9a-fA-F-]+"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(at_home_body("fresh")))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/data/fresh/1.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"page 1".to_vec()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/data/expired/1.png"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let server = ChapterServer::new(mangadex_client, Uuid::new_v4(), false, expired);
+        let mut prefetcher =
+            PagePrefetcher::from_server(reqwest::Client::new(), server, Quality::Data, 1)?;
+
+        assert_eq!(prefetcher.get(0).await?, "page 1".as_bytes());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,369 @@
+//! Subscribe to the new chapters of the manga followed by the logged-in user.
+//!
+//! MangaDex doesn't offer a push or streaming API for updates, so the subscription long-polls the
+//! followed manga feed (`GET /user/follows/manga/feed`). It polls every `min_interval` while new
+//! chapters keep coming and doubles the wait after every empty poll, up to `max_interval`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use futures::StreamExt;
+//!
+//! use mangadex_api::utils::updates::UpdateEvent;
+//! use mangadex_api::MangaDexClient;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let updates = client
+//!     .updates()
+//!     .min_interval(Duration::from_secs(60))
+//!     .build()?;
+//! let events = updates.subscribe();
+//! futures::pin_mut!(events);
+//!
+//! while let Some(event) = events.next().await {
+//!     match event? {
+//!         UpdateEvent::NewChapter(chapter) => println!("new chapter: {}", chapter.id),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_stream::stream;
+use derive_builder::Builder;
+use mangadex_api_schema::v5::ChapterObject;
+use mangadex_api_types::error::{Error, Result};
+use mangadex_api_types::{
+    Language, MangaDexDateTime, MangaFeedSortOrder, OrderDirection, SharedClock, SystemClock,
+};
+use tokio_stream::Stream;
+use uuid::Uuid;
+
+use crate::{HttpClientRef, MangaDexClient};
+
+/// Number of chapters fetched per request of a poll.
+const CHAPTERS_PER_PAGE: u32 = 100;
+
+/// Update reported by a subscription.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateEvent {
+    /// A chapter of a followed manga was published.
+    NewChapter(ChapterObject),
+}
+
+#[derive(Clone, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
+pub struct Updates {
+    /// This should never be set manually as this is only for internal use.
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    /// Only report the chapters published after this time.
+    ///
+    /// Defaults to the time the subscription starts.
+    #[builder(default)]
+    pub since: Option<MangaDexDateTime>,
+    #[builder(default, setter(each = "add_translated_language"))]
+    pub translated_language: Vec<Language>,
+    /// Wait between polls while new chapters are found.
+    ///
+    /// Defaults to 1 minute.
+    #[builder(default = "Duration::from_secs(60)")]
+    pub min_interval: Duration,
+    /// Longest wait between polls when no new chapters are found.
+    ///
+    /// Defaults to 15 minutes.
+    #[builder(default = "Duration::from_secs(15 * 60)")]
+    pub max_interval: Duration,
+    /// Clock giving the time the subscription starts, when `since` isn't set.
+    ///
+    /// Defaults to [`SystemClock`].
+    #[builder(default = "Arc::new(SystemClock)")]
+    pub clock: SharedClock,
+}
+
+impl std::fmt::Debug for Updates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Updates")
+            .field("since", &self.since)
+            .field("translated_language", &self.translated_language)
+            .field("min_interval", &self.min_interval)
+            .field("max_interval", &self.max_interval)
+            .finish()
+    }
+}
+
+impl UpdatesBuilder {
+    fn validate(&self) -> Result<()> {
+        if self.min_interval == Some(Duration::ZERO) {
+            return Err(Error::BuilderError {
+                field: "min_interval".to_string(),
+                message: "must be more than zero".to_string(),
+            });
+        }
+
+        let min_interval = self.min_interval.unwrap_or(Duration::from_secs(60));
+        let max_interval = self.max_interval.unwrap_or(Duration::from_secs(15 * 60));
+        if min_interval > max_interval {
+            return Err(Error::BuilderError {
+                field: "max_interval".to_string(),
+                message: "must not be less than min_interval".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Updates {
+    /// Poll the followed manga feed until the stream is dropped.
+    ///
+    /// The chapters are reported in the order they were published in. A failed poll yields the
+    /// error and is retried after backing off, so the stream never ends on its own.
+    pub fn subscribe(&self) -> impl Stream<Item = Result<UpdateEvent>> + '_ {
+        stream! {
+            let mut cursor = self.since.clone().unwrap_or_else(|| self.clock.now());
+            // Chapters published at the cursor, which the next poll returns again.
+            let mut seen: HashSet<Uuid> = HashSet::new();
+            let mut interval = self.min_interval;
+
+            loop {
+                match self.poll(&cursor).await {
+                    Ok(chapters) => {
+                        let chapters: Vec<ChapterObject> = chapters
+                            .into_iter()
+                            .filter(|chapter| !seen.contains(&chapter.id))
+                            .collect();
+
+                        interval = if chapters.is_empty() {
+                            (interval * 2).min(self.max_interval)
+                        } else {
+                            self.min_interval
+                        };
+
+                        for chapter in chapters {
+                            if chapter.attributes.publish_at.as_ref() > cursor.as_ref() {
+                                cursor = chapter.attributes.publish_at.clone();
+                                seen.clear();
+                            }
+                            seen.insert(chapter.id);
+
+                            yield Ok(UpdateEvent::NewChapter(chapter));
+                        }
+                    }
+                    Err(e) => {
+                        interval = (interval * 2).min(self.max_interval);
+                        yield Err(e);
+                    }
+                }
+
+                tokio::time::sleep(interval).await;
+            }
+        }
+    }
+
+    /// Fetch the chapters published since the cursor, oldest first.
+    async fn poll(&self, cursor: &MangaDexDateTime) -> Result<Vec<ChapterObject>> {
+        MangaDexClient::new_with_http_client_ref(self.http_client.clone())
+            .feed()
+            .followed_manga()
+            .publish_at_since(cursor.clone())
+            .translated_language(self.translated_language.clone())
+            .order(MangaFeedSortOrder::PublishAt(OrderDirection::Ascending))
+            .limit(CHAPTERS_PER_PAGE)
+            .build()?
+            .collect_all()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use super::UpdateEvent;
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::{FixedClock, MangaDexDateTime, SharedClock};
+
+    /// Create a client using the mock server.
+    fn mock_client(mock_server: &MockServer) -> anyhow::Result<MangaDexClient> {
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+
+        Ok(MangaDexClient::new_with_http_client(http_client))
+    }
+
+    /// Get the `publishAtSince` query parameter of a request.
+    fn publish_at_since(request: &Request) -> Option<String> {
+        request
+            .url
+            .query_pairs()
+            .find(|(key, _)| key == "publishAtSince")
+            .map(|(_, value)| value.into_owned())
+    }
+
+    fn chapter_json(chapter_id: Uuid, publish_at: &str) -> serde_json::Value {
+        json!({
+            "id": chapter_id,
+            "type": "chapter",
+            "attributes": {
+                "title": "",
+                "volume": "1",
+                "chapter": "1",
+                "pages": 4,
+                "translatedLanguage": "en",
+                "uploader": Uuid::nil(),
+                "version": 1,
+                "createdAt": publish_at,
+                "updatedAt": publish_at,
+                "publishAt": publish_at,
+                "readableAt": publish_at,
+            },
+            "relationships": [],
+        })
+    }
+
+    #[tokio::test]
+    async fn subscribe_reports_new_chapters_across_polls() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let mangadex_client = mock_client(&mock_server)?;
+
+        let first_id = Uuid::new_v4();
+        let second_id = Uuid::new_v4();
+        let first = chapter_json(first_id, "2023-01-01T00:00:00+00:00");
+        let second = chapter_json(second_id, "2023-01-02T00:00:00+00:00");
+
+        // The first poll finds a chapter, the second finds it again as the cursor is inclusive,
+        // and the third finds a newer one.
+        let polls = AtomicUsize::new(0);
+        Mock::given(method("GET"))
+            .and(path("/user/follows/manga/feed"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .respond_with(move |_: &Request| {
+                let data = match polls.fetch_add(1, Ordering::SeqCst) {
+                    0 | 1 => vec![first.clone()],
+                    _ => vec![first.clone(), second.clone()],
+                };
+
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "result": "ok",
+                    "response": "collection",
+                    "total": data.len(),
+                    "data": data,
+                    "limit": 100,
+                    "offset": 0
+                }))
+            })
+            .mount(&mock_server)
+            .await;
+
+        let updates = mangadex_client
+            .updates()
+            .since(serde_json::from_value::<MangaDexDateTime>(json!(
+                "2022-12-31T00:00:00+00:00"
+            ))?)
+            .min_interval(Duration::from_millis(1))
+            .max_interval(Duration::from_millis(4))
+            .build()?;
+        let events: Vec<_> = updates.subscribe().take(2).collect().await;
+
+        let chapter_ids = events
+            .into_iter()
+            .map(|event| match event? {
+                UpdateEvent::NewChapter(chapter) => Ok(chapter.id),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        assert_eq!(chapter_ids, vec![first_id, second_id]);
+
+        let requests = mock_server.received_requests().await.unwrap_or_default();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            publish_at_since(&requests[0]).as_deref(),
+            Some("2022-12-31T00:00:00")
+        );
+        assert_eq!(
+            publish_at_since(&requests[2]).as_deref(),
+            Some("2023-01-01T00:00:00")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn subscribe_starts_at_the_clock_time_by_default() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let mangadex_client = mock_client(&mock_server)?;
+
+        Mock::given(method("GET"))
+            .and(path("/user/follows/manga/feed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "total": 1,
+                "data": [chapter_json(Uuid::new_v4(), "2023-01-01T00:00:00+00:00")],
+                "limit": 100,
+                "offset": 0
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let now = serde_json::from_value::<MangaDexDateTime>(json!("2022-12-31T00:00:00+00:00"))?;
+        let updates = mangadex_client
+            .updates()
+            .clock(Arc::new(FixedClock::new(now)) as SharedClock)
+            .build()?;
+        let _: Vec<_> = updates.subscribe().take(1).collect().await;
+
+        let requests = mock_server.received_requests().await.unwrap_or_default();
+        assert_eq!(
+            publish_at_since(&requests[0]).as_deref(),
+            Some("2022-12-31T00:00:00")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn updates_rejects_a_max_interval_below_the_min_interval() {
+        let res = MangaDexClient::default()
+            .updates()
+            .min_interval(Duration::from_secs(60))
+            .max_interval(Duration::from_secs(1))
+            .build();
+
+        assert!(res.is_err());
+    }
+}
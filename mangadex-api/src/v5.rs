@@ -2,42 +2,61 @@
 #[cfg(feature = "legacy-account")]
 mod account;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "at-home")]
 mod at_home;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "auth")]
 mod auth;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "author")]
 mod author;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "captcha")]
 mod captcha;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "chapter")]
 mod chapter;
 #[cfg(not(feature = "deserializable-endpoint"))]
-mod cover;
+#[cfg(feature = "cover")]
+pub(crate) mod cover;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "custom-list")]
 pub(crate) mod custom_list;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "feed")]
 mod feed;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "infrastructure")]
 mod infrastructure;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "legacy")]
 mod legacy;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "manga")]
 mod manga;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "rating")]
 mod rating;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "report")]
 pub(crate) mod report;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "scanlation-group")]
 mod scanlation_group;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "search")]
 mod search;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "settings")]
 mod settings;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "statistics")]
 mod statistics;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "upload")]
 mod upload;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "user")]
 pub(crate) mod user;
 
 
@@ -45,86 +64,149 @@ pub(crate) mod user;
 #[cfg(feature = "legacy-account")]
 pub mod account;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "at-home")]
 pub mod at_home;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "auth")]
 pub mod auth;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "author")]
 pub mod author;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "captcha")]
 pub mod captcha;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "chapter")]
 pub mod chapter;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "cover")]
 pub mod cover;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "custom-list")]
 pub mod custom_list;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "feed")]
 pub mod feed;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "infrastructure")]
 pub mod infrastructure;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "legacy")]
 pub mod legacy;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "manga")]
 pub mod manga;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "rating")]
 pub mod rating;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "report")]
 pub mod report;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "scanlation-group")]
 pub mod scanlation_group;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "search")]
 pub mod search;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "settings")]
 pub mod settings;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "statistics")]
 pub mod statistics;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "upload")]
 pub mod upload;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "user")]
 pub mod user;
 
 #[cfg(not(feature = "multi-thread"))]
 use std::cell::RefCell;
 #[cfg(not(feature = "multi-thread"))]
 use std::rc::Rc;
-#[cfg(feature = "multi-thread")]
 use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(feature = "multi-thread")]
 use futures::lock::Mutex;
 pub use mangadex_api_schema::v5 as schema;
 pub(crate) use mangadex_api_schema::v5::AuthTokens;
-use reqwest::Client;
-
+use mangadex_api_schema::v5::TagObject;
+use mangadex_api_types::error::Result;
+#[cfg(all(feature = "token-refresher", feature = "multi-thread"))]
+use mangadex_api_types::SystemClock;
+use reqwest::header::HeaderValue;
+use reqwest::{Client, Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::http_client::HttpClientBuilder;
 #[cfg(feature = "legacy-account")]
 use crate::v5::account::AccountBuilder;
 
+#[cfg(feature = "at-home")]
 use crate::v5::at_home::AtHomeBuilder;
 
+#[cfg(feature = "auth")]
 use crate::v5::auth::AuthBuilder;
 
+#[cfg(feature = "author")]
 use crate::v5::author::AuthorBuilder;
+#[cfg(feature = "captcha")]
 use crate::v5::captcha::CaptchaBuilder;
+#[cfg(feature = "chapter")]
 use crate::v5::chapter::ChapterBuilder;
+#[cfg(feature = "cover")]
 use crate::v5::cover::CoverBuilder;
+#[cfg(feature = "custom-list")]
 use crate::v5::custom_list::CustomListBuilder;
+#[cfg(feature = "feed")]
 use crate::v5::feed::FeedBuilder;
+#[cfg(feature = "infrastructure")]
 use crate::v5::infrastructure::InfrastructureBuilder;
+#[cfg(feature = "legacy")]
 use crate::v5::legacy::LegacyBuilder;
+#[cfg(feature = "manga")]
 use crate::v5::manga::MangaBuilder;
+#[cfg(all(feature = "manga", feature = "statistics"))]
+pub use crate::v5::manga::MangaWithStatistics;
+#[cfg(feature = "rating")]
 use crate::v5::rating::RatingBuilder;
+#[cfg(feature = "report")]
 use crate::v5::report::ReportBuilder;
+#[cfg(feature = "report")]
+pub use crate::v5::report::ReportInput;
+#[cfg(feature = "scanlation-group")]
 use crate::v5::scanlation_group::ScanlationGroupBuilder;
+#[cfg(feature = "search")]
 use crate::v5::search::SearchBuilder;
+#[cfg(feature = "settings")]
 use crate::v5::settings::SettingsBuilder;
+#[cfg(feature = "statistics")]
 use crate::v5::statistics::StatisticsBuilder;
+#[cfg(feature = "upload")]
 use crate::v5::upload::UploadBuilder;
+#[cfg(feature = "upload")]
+pub use crate::v5::upload::{AbandonSessionFuture, AbandonSessionSpawner, UploadSessionGuard};
+#[cfg(feature = "user")]
 use crate::v5::user::UserBuilder;
 use crate::HttpClient;
 use crate::HttpClientRef;
 
+#[cfg(all(feature = "token-refresher", feature = "multi-thread"))]
+use crate::token_refresher::{
+    TokenRefresher, TokenRefresherBuilder, TokenRefresherHandle, DEFAULT_REFRESH_MARGIN,
+    DEFAULT_RETRY_INTERVAL,
+};
 #[cfg(feature = "utils")]
 use crate::utils::download::DownloadBuilder;
+#[cfg(feature = "utils")]
+use crate::utils::reader::ReaderBuilder;
+#[cfg(feature = "utils")]
+use crate::utils::updates::UpdatesBuilder;
+#[cfg(feature = "utils")]
+use crate::utils::UtilsBuilder;
 
 /// API client to make requests to the MangaDex v5 API.
 #[derive(Clone, Debug)]
@@ -177,6 +259,33 @@ impl MangaDexClient {
         Self::new_with_http_client_ref(create_ref_counted_http_client(HttpClient::new(client)))
     }
 
+    /// Get a builder to configure the HTTP client and create a `MangaDexClient` in one chain.
+    ///
+    /// Use [`MangaDexClient::new_with_http_client()`] for the settings the builder doesn't cover.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use reqwest::header::HeaderValue;
+    /// use url::Url;
+    ///
+    /// use mangadex_api::v5::MangaDexClient;
+    ///
+    /// # fn run() -> anyhow::Result<()> {
+    /// let client = MangaDexClient::builder()
+    ///     .base_url(Url::parse("https://api.mangadex.dev")?)
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent(HeaderValue::from_static("my-app/1.0"))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> MangaDexClientBuilder {
+        MangaDexClientBuilder::default()
+    }
+
     /// Create a new `MangaDexClient` with a custom client reference
     pub fn new_with_http_client_ref(http_client: HttpClientRef) -> Self {
         Self { http_client }
@@ -235,6 +344,7 @@ impl MangaDexClient {
     /// Get a builder for handling the At-Home endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/AtHome>
+    #[cfg(feature = "at-home")]
     pub fn at_home(&self) -> AtHomeBuilder {
         AtHomeBuilder::new(self.http_client.clone())
     }
@@ -244,6 +354,7 @@ impl MangaDexClient {
     /// This builder is deprecated
     ///
     /// <https://api.mangadex.org/docs/redoc.html#tag/Authentication>
+    #[cfg(feature = "auth")]
     pub fn auth(&self) -> AuthBuilder {
         AuthBuilder::new(self.http_client.clone())
     }
@@ -251,6 +362,7 @@ impl MangaDexClient {
     /// Get a builder for handling the author endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Author>
+    #[cfg(feature = "author")]
     pub fn author(&self) -> AuthorBuilder {
         AuthorBuilder::new(self.http_client.clone())
     }
@@ -258,6 +370,7 @@ impl MangaDexClient {
     /// Get a builder for handling the captcha endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Captcha>
+    #[cfg(feature = "captcha")]
     pub fn captcha(&self) -> CaptchaBuilder {
         CaptchaBuilder::new(self.http_client.clone())
     }
@@ -265,6 +378,7 @@ impl MangaDexClient {
     /// Get a builder for handling the chapter endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Chapter>
+    #[cfg(feature = "chapter")]
     pub fn chapter(&self) -> ChapterBuilder {
         ChapterBuilder::new(self.http_client.clone())
     }
@@ -272,6 +386,7 @@ impl MangaDexClient {
     /// Get a builder for handling manga volume cover art endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Cover>
+    #[cfg(feature = "cover")]
     pub fn cover(&self) -> CoverBuilder {
         CoverBuilder::new(self.http_client.clone())
     }
@@ -279,6 +394,7 @@ impl MangaDexClient {
     /// Get a builder for handling the custom list endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/CustomList>
+    #[cfg(feature = "custom-list")]
     pub fn custom_list(&self) -> CustomListBuilder {
         CustomListBuilder::new(self.http_client.clone())
     }
@@ -286,6 +402,7 @@ impl MangaDexClient {
     /// Get a builder for handling the feed endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Feed>
+    #[cfg(feature = "feed")]
     pub fn feed(&self) -> FeedBuilder {
         FeedBuilder::new(self.http_client.clone())
     }
@@ -293,6 +410,7 @@ impl MangaDexClient {
     /// Get a builder for handling the infrastructure endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Infrastructure>
+    #[cfg(feature = "infrastructure")]
     pub fn infrastructure(&self) -> InfrastructureBuilder {
         InfrastructureBuilder::new(self.http_client.clone())
     }
@@ -300,6 +418,7 @@ impl MangaDexClient {
     /// Get a builder for handling the legacy endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Legacy>
+    #[cfg(feature = "legacy")]
     pub fn legacy(&self) -> LegacyBuilder {
         LegacyBuilder::new(self.http_client.clone())
     }
@@ -307,6 +426,7 @@ impl MangaDexClient {
     /// Get a builder for handling the manga endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Manga>
+    #[cfg(feature = "manga")]
     pub fn manga(&self) -> MangaBuilder {
         MangaBuilder::new(self.http_client.clone())
     }
@@ -314,6 +434,7 @@ impl MangaDexClient {
     /// Get a builder for handling the rating endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Rating>
+    #[cfg(feature = "rating")]
     pub fn rating(&self) -> RatingBuilder {
         RatingBuilder::new(self.http_client.clone())
     }
@@ -321,6 +442,7 @@ impl MangaDexClient {
     /// Get a builder for handling the report endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Report>
+    #[cfg(feature = "report")]
     pub fn report(&self) -> ReportBuilder {
         ReportBuilder::new(self.http_client.clone())
     }
@@ -328,6 +450,7 @@ impl MangaDexClient {
     /// Get a builder for handling the scanlation group endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/ScanlationGroup>
+    #[cfg(feature = "scanlation-group")]
     pub fn scanlation_group(&self) -> ScanlationGroupBuilder {
         ScanlationGroupBuilder::new(self.http_client.clone())
     }
@@ -335,6 +458,7 @@ impl MangaDexClient {
     /// Get a builder for handling the search endpoints.
     ///
     /// This is a convenience builder that aggregates search endpoints from various categories.
+    #[cfg(feature = "search")]
     pub fn search(&self) -> SearchBuilder {
         SearchBuilder::new(self.http_client.clone())
     }
@@ -344,6 +468,7 @@ impl MangaDexClient {
     /// <https://api.mangadex.org/swagger.html#/Settings>
     // Not public yet as the settings endpoints are not stable as of MangaDex API v5.4.9.
     #[allow(unused)]
+    #[cfg(feature = "settings")]
     fn settings(&self) -> SettingsBuilder {
         SettingsBuilder::new(self.http_client.clone())
     }
@@ -351,6 +476,7 @@ impl MangaDexClient {
     /// Get a builder for handling the statistics endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/Statistics>
+    #[cfg(feature = "statistics")]
     pub fn statistics(&self) -> StatisticsBuilder {
         StatisticsBuilder::new(self.http_client.clone())
     }
@@ -358,6 +484,7 @@ impl MangaDexClient {
     /// Get a builder for handling uploads.
     ///
     /// <https://api.mangadex.org/swagger.html#/Upload>
+    #[cfg(feature = "upload")]
     pub fn upload(&self) -> UploadBuilder {
         UploadBuilder::new(self.http_client.clone())
     }
@@ -365,6 +492,7 @@ impl MangaDexClient {
     /// Get a builder for handling the user endpoints.
     ///
     /// <https://api.mangadex.org/swagger.html#/User>
+    #[cfg(feature = "user")]
     pub fn user(&self) -> UserBuilder {
         UserBuilder::new(self.http_client.clone())
     }
@@ -374,10 +502,215 @@ impl MangaDexClient {
     pub fn api_dev_client() -> Self {
         Self::new_with_http_client(HttpClient::api_dev_client())
     }
+    /// Get a request builder for an arbitrary path on the API.
+    ///
+    /// The path is joined to the base URL and the authentication tokens are attached.
+    /// This is useful for the cases the endpoint builders don't support yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mangadex_api::v5::MangaDexClient;
+    /// use reqwest::Method;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let client = MangaDexClient::default();
+    ///
+    /// let res = client
+    ///     .raw_request(Method::GET, "/ping")
+    ///     .await?
+    ///     .header("X-Custom-Header", "value")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let body: serde_json::Value = client.parse_response(res).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn raw_request(&self, method: Method, path: &str) -> Result<RequestBuilder> {
+        #[cfg(not(feature = "multi-thread"))]
+        {
+            self.http_client.try_borrow()?.raw_request(method, path)
+        }
+        #[cfg(feature = "multi-thread")]
+        {
+            self.http_client.lock().await.raw_request(method, path)
+        }
+    }
+
+    /// Deserialize the body of a response obtained with [`MangaDexClient::raw_request()`].
+    pub async fn parse_response<T>(&self, res: Response) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        HttpClient::parse_response(res).await
+    }
+
+    /// Set the authentication tokens used by the client, replacing the current ones.
+    ///
+    /// This is useful to restore tokens from your own store or to rotate them without rebuilding
+    /// the client. Every builder sharing this client's [`HttpClientRef`] will use the new tokens.
+    pub async fn set_auth_tokens(&self, auth_tokens: &AuthTokens) -> Result<()> {
+        #[cfg(not(feature = "multi-thread"))]
+        {
+            self.http_client
+                .try_borrow_mut()?
+                .set_auth_tokens(auth_tokens);
+        }
+        #[cfg(feature = "multi-thread")]
+        {
+            self.http_client.lock().await.set_auth_tokens(auth_tokens);
+        }
+
+        Ok(())
+    }
+
+    /// Remove the authentication tokens from the client.
+    ///
+    /// This does not end the session on the MangaDex server.
+    pub async fn clear_auth_tokens(&self) -> Result<()> {
+        #[cfg(not(feature = "multi-thread"))]
+        {
+            self.http_client.try_borrow_mut()?.clear_auth_tokens();
+        }
+        #[cfg(feature = "multi-thread")]
+        {
+            self.http_client.lock().await.clear_auth_tokens();
+        }
+
+        Ok(())
+    }
+
+    /// Set the tags used by the tag helpers instead of fetching them from MangaDex.
+    ///
+    /// With a catalog set, `client.manga().tags_grouped()` and `client.manga().find_tag()` don't
+    /// send any request.
+    ///
+    /// The tags can be given as a `Vec` or as an `Arc<[TagObject]>` shared with other clients.
+    pub async fn set_tag_catalog(&self, tags: impl Into<Arc<[TagObject]>>) -> Result<()> {
+        #[cfg(not(feature = "multi-thread"))]
+        {
+            self.http_client.try_borrow_mut()?.set_tag_catalog(tags);
+        }
+        #[cfg(feature = "multi-thread")]
+        {
+            self.http_client.lock().await.set_tag_catalog(tags);
+        }
+
+        Ok(())
+    }
+
+    /// Remove the stored tags so that the tag helpers fetch them from MangaDex again.
+    pub async fn clear_tag_catalog(&self) -> Result<()> {
+        #[cfg(not(feature = "multi-thread"))]
+        {
+            self.http_client.try_borrow_mut()?.clear_tag_catalog();
+        }
+        #[cfg(feature = "multi-thread")]
+        {
+            self.http_client.lock().await.clear_tag_catalog();
+        }
+
+        Ok(())
+    }
+
+    /// Get a builder for a background task refreshing the session token before it expires.
+    #[cfg(all(feature = "token-refresher", feature = "multi-thread"))]
+    pub fn token_refresher(&self) -> TokenRefresherBuilder {
+        TokenRefresherBuilder::default().http_client(self.http_client.clone())
+    }
+
+    /// Start refreshing the session token in the background shortly before it expires, with the
+    /// default settings.
+    ///
+    /// The task stops when the returned handle is dropped. See [`MangaDexClient::token_refresher()`]
+    /// to change the settings or persist the new tokens.
+    ///
+    /// # Panics
+    ///
+    /// This panics if it is called outside of a Tokio runtime.
+    #[cfg(all(feature = "token-refresher", feature = "multi-thread"))]
+    pub fn start_token_refresher(&self) -> TokenRefresherHandle {
+        TokenRefresher {
+            http_client: self.http_client.clone(),
+            margin: DEFAULT_REFRESH_MARGIN,
+            retry_interval: DEFAULT_RETRY_INTERVAL,
+            on_refresh: None,
+            on_error: None,
+            clock: Arc::new(SystemClock),
+        }
+        .start()
+    }
+
     #[cfg(feature = "utils")]
     pub fn download(&self) -> DownloadBuilder {
         DownloadBuilder::new(self.http_client.clone())
     }
+
+    /// Get a builder for a subscription to the new chapters of the followed manga.
+    ///
+    /// MangaDex has no push API, so this long-polls the followed manga feed.
+    #[cfg(feature = "utils")]
+    pub fn updates(&self) -> UpdatesBuilder {
+        UpdatesBuilder::default().http_client(self.http_client.clone())
+    }
+
+    /// Get a builder for the helpers that fetch everything needed to display content.
+    #[cfg(feature = "utils")]
+    pub fn reader(&self) -> ReaderBuilder {
+        ReaderBuilder::new(self.http_client.clone())
+    }
+
+    /// Get the helpers that compose several endpoints, such as resolving the manga of chapters.
+    #[cfg(feature = "utils")]
+    pub fn utils(&self) -> UtilsBuilder {
+        UtilsBuilder::new(self.http_client.clone())
+    }
+}
+
+/// Builder for a [`MangaDexClient`] and its [`HttpClient`].
+///
+/// Created with [`MangaDexClient::builder()`].
+#[derive(Default)]
+pub struct MangaDexClientBuilder {
+    http_client: HttpClientBuilder,
+}
+
+impl MangaDexClientBuilder {
+    /// Set the base URL of the API.
+    ///
+    /// Defaults to [`API_URL`](crate::API_URL).
+    pub fn base_url(mut self, base_url: Url) -> Self {
+        self.http_client.base_url(base_url);
+        self
+    }
+
+    /// Set the authentication tokens sent with the requests.
+    pub fn auth_tokens(mut self, auth_tokens: AuthTokens) -> Self {
+        self.http_client.auth_tokens(auth_tokens);
+        self
+    }
+
+    /// Set the timeout of a whole request.
+    ///
+    /// Defaults to no timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_client.timeout(timeout);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: HeaderValue) -> Self {
+        self.http_client.user_agent(user_agent);
+        self
+    }
+
+    /// Build the HTTP client and the `MangaDexClient` using it.
+    pub fn build(self) -> Result<MangaDexClient> {
+        Ok(MangaDexClient::new_with_http_client(
+            self.http_client.build()?,
+        ))
+    }
 }
 
 /// Create a new reference counted `HttpClient`.
@@ -391,3 +724,131 @@ fn create_ref_counted_http_client(http_client: HttpClient) -> HttpClientRef {
         Arc::new(Mutex::new(http_client))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::header::HeaderValue;
+    use reqwest::Method;
+    use serde_json::json;
+    use url::Url;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+
+    #[tokio::test]
+    async fn raw_request_fires_a_request_to_base_url_with_auth() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "preview": true,
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/some/unsupported/path"))
+            .and(query_param("weird", "param"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .and(header("X-Preview", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .raw_request(Method::GET, "/some/unsupported/path")
+            .await?
+            .query(&[("weird", "param")])
+            .header("X-Preview", "1")
+            .send()
+            .await?;
+        let body: serde_json::Value = mangadex_client.parse_response(res).await?;
+
+        assert_eq!(body["preview"], json!(true));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builder_configures_the_http_client_inline() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let mangadex_client = MangaDexClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .timeout(Duration::from_secs(10))
+            .user_agent(HeaderValue::from_static("mangadex-api-test"))
+            .build()?;
+
+        Mock::given(method("GET"))
+            .and(path("/ping"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .and(header("User-Agent", "mangadex-api-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": "ok"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .raw_request(Method::GET, "/ping")
+            .await?
+            .send()
+            .await?;
+        let _: serde_json::Value = mangadex_client.parse_response(res).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn auth_tokens_set_after_construction_are_sent() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        mangadex_client
+            .set_auth_tokens(&AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .await?;
+
+        Mock::given(method("GET"))
+            .and(path("/ping"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": "ok"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .raw_request(Method::GET, "/ping")
+            .await?
+            .send()
+            .await?;
+        let _: serde_json::Value = mangadex_client.parse_response(res).await?;
+
+        mangadex_client.clear_auth_tokens().await?;
+
+        #[cfg(not(feature = "multi-thread"))]
+        assert_eq!(mangadex_client.http_client.try_borrow()?.get_tokens(), None);
+        #[cfg(feature = "multi-thread")]
+        assert_eq!(mangadex_client.http_client.lock().await.get_tokens(), None);
+
+        Ok(())
+    }
+}
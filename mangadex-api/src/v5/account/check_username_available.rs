@@ -38,7 +38,10 @@ use crate::HttpClientRef;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option))]
+#[builder(
+    setter(into, strip_option),
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[deprecated = "Usage deprecated after the introduction of OAuth authentification from Mangadex API 5.9"]
 pub struct CheckUsernameAvailable {
     /// This should never be set manually as this is only for internal use.
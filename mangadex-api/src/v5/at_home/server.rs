@@ -40,7 +40,11 @@ use mangadex_api_schema::v5::AtHomeServerResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetAtHomeServer {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -76,7 +80,7 @@ mod tests {
     use serde_json::json;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{method, path_regex};
+    use wiremock::matchers::{method, path_regex, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::{HttpClient, MangaDexClient};
@@ -129,4 +133,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn get_at_home_server_sends_the_force_port_443_query_param() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "baseUrl": "https://example.org",
+            "chapter": {
+                "hash": "abc123",
+                "data": ["1.jpg"],
+                "dataSaver": ["1.jpg"],
+            }
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/at-home/server/[0-9a-fA-F-]+"))
+            .and(query_param("forcePort443", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        mangadex_client
+            .at_home()
+            .server()
+            .chapter_id(Uuid::new_v4())
+            .force_port_443(true)
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
 }
@@ -44,7 +44,10 @@ use mangadex_api_types::{Password, Username};
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option))]
+#[builder(
+    setter(into, strip_option),
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[deprecated = "Usage deprecated after the introduction of OAuth authentification from Mangadex API 5.9"]
 pub struct Login {
     /// This should never be set manually as this is only for internal use.
@@ -69,7 +72,9 @@ impl Login {
     pub async fn send(&self) -> Result<LoginResponse> {
         #[cfg(not(feature = "multi-thread"))]
         let res = {
-            let res = self.http_client.try_borrow()?.send_request(self).await??;
+            let request = self.http_client.try_borrow()?.prepare_request(self)?;
+            let (res, _) = request.send_with_headers::<Result<LoginResponse>>().await?;
+            let res = res?;
 
             self.http_client.borrow_mut().set_auth_tokens(&res.token);
 
@@ -77,7 +82,9 @@ impl Login {
         };
         #[cfg(feature = "multi-thread")]
         let res = {
-            let res = self.http_client.lock().await.send_request(self).await??;
+            let request = self.http_client.lock().await.prepare_request(self)?;
+            let (res, _) = request.send_with_headers::<Result<LoginResponse>>().await?;
+            let res = res?;
 
             self.http_client.lock().await.set_auth_tokens(&res.token);
 
@@ -253,7 +260,7 @@ mod tests {
         assert_eq!(mangadex_client.http_client.lock().await.get_tokens(), None);
 
         match res {
-            Error::RequestError(_) => {}
+            Error::DeserializeError(_) => {}
             _ => panic!("unexpected error"),
         }
 
@@ -48,7 +48,10 @@ use mangadex_api_types::error::Result;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option))]
+#[builder(
+    setter(into, strip_option),
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[deprecated = "Usage deprecated after the introduction of OAuth authentification from Mangadex API 5.9"]
 pub struct Logout {
     /// This should never be set manually as this is only for internal use.
@@ -63,13 +66,17 @@ impl Logout {
     pub async fn send(&self) -> Result<()> {
         #[cfg(not(feature = "multi-thread"))]
         {
-            self.http_client.try_borrow()?.send_request(self).await??;
+            let request = self.http_client.try_borrow()?.prepare_request(self)?;
+            let (res, _) = request.send_with_headers::<Result<NoData>>().await?;
+            res?;
 
             self.http_client.try_borrow_mut()?.clear_auth_tokens();
         }
         #[cfg(feature = "multi-thread")]
         {
-            self.http_client.lock().await.send_request(self).await??;
+            let request = self.http_client.lock().await.prepare_request(self)?;
+            let (res, _) = request.send_with_headers::<Result<NoData>>().await?;
+            res?;
 
             self.http_client.lock().await.clear_auth_tokens();
         }
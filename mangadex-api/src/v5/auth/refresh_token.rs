@@ -49,7 +49,10 @@ use mangadex_api_types::error::{Error, Result};
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option))]
+#[builder(
+    setter(into, strip_option),
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[deprecated = "Usage deprecated after the introduction of OAuth authentification from Mangadex API 5.9"]
 pub struct RefreshToken {
     /// This should never be set manually as this is only for internal use.
@@ -85,7 +88,11 @@ impl RefreshToken {
 
         #[cfg(not(feature = "multi-thread"))]
         {
-            let res = self.http_client.try_borrow()?.send_request(self).await??;
+            let request = self.http_client.try_borrow()?.prepare_request(self)?;
+            let (res, _) = request
+                .send_with_headers::<Result<RefreshTokenResponse>>()
+                .await?;
+            let res = res?;
 
             self.http_client
                 .try_borrow_mut()?
@@ -95,7 +102,11 @@ impl RefreshToken {
         }
         #[cfg(feature = "multi-thread")]
         {
-            let res = self.http_client.lock().await.send_request(self).await??;
+            let request = self.http_client.lock().await.prepare_request(self)?;
+            let (res, _) = request
+                .send_with_headers::<Result<RefreshTokenResponse>>()
+                .await?;
+            let res = res?;
 
             self.http_client.lock().await.set_auth_tokens(&res.token);
 
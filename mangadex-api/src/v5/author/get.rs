@@ -40,7 +40,11 @@ use mangadex_api_types::ReferenceExpansionResource;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetAuthor {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -59,7 +63,8 @@ pub struct GetAuthor {
 endpoint! {
     GET ("/author/{}", author_id),
     #[query] GetAuthor,
-    #[flatten_result] AuthorResponse
+    #[flatten_result] AuthorResponse,
+    #[not_found("author", author_id)]
 }
 
 #[cfg(test)]
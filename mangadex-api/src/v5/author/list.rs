@@ -37,7 +37,12 @@ use mangadex_api_types::{AuthorSortOrder, ReferenceExpansionResource};
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct ListAuthor {
     /// This should never be set manually as this is only for internal use.
@@ -64,6 +69,10 @@ endpoint! {
     #[flatten_result] AuthorListResponse
 }
 
+paginated! {
+    ListAuthor => mangadex_api_schema::v5::AuthorObject
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::lorem::en::Sentence;
@@ -51,7 +51,11 @@ use mangadex_api_schema::v5::{AuthorResponse, LocalizedString};
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct UpdateAuthor {
     /// This should never be set manually as this is only for internal use.
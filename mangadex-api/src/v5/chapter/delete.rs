@@ -50,7 +50,11 @@ use mangadex_api_types::error::Result;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct DeleteChapter {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -115,4 +119,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn delete_chapter_accepts_a_no_content_response() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let chapter_id = Uuid::new_v4();
+
+        Mock::given(method("DELETE"))
+            .and(path_regex(r"/chapter/[0-9a-fA-F-]+"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .chapter()
+            .delete()
+            .chapter_id(chapter_id)
+            .build()?
+            .send()
+            .await;
+
+        assert!(res.is_ok(), "unexpected error: {:?}", res);
+
+        Ok(())
+    }
 }
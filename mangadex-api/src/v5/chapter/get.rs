@@ -40,7 +40,11 @@ use mangadex_api_types::ReferenceExpansionResource;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetChapter {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -59,7 +63,8 @@ pub struct GetChapter {
 endpoint! {
     GET ("/chapter/{}", chapter_id),
     #[query] GetChapter,
-    #[flatten_result] ChapterResponse
+    #[flatten_result] ChapterResponse,
+    #[not_found("chapter", chapter_id)]
 }
 
 #[cfg(test)]
@@ -190,16 +195,12 @@ mod tests {
             .await
             .expect_err("expected error");
 
-        if let Error::Api(errors) = res {
-            assert_eq!(errors.errors.len(), 1);
-
-            assert_eq!(errors.errors[0].id, error_id);
-            assert_eq!(errors.errors[0].status, 404);
-            assert_eq!(errors.errors[0].title, Some("Not found".to_string()));
-            assert_eq!(
-                errors.errors[0].detail,
-                Some("Chapter could not be found".to_string())
-            );
+        match res {
+            Error::NotFound { resource, id } => {
+                assert_eq!(resource, "chapter");
+                assert_eq!(id, Some(chapter_id));
+            }
+            other => panic!("expected a not found error, got {other:?}"),
         }
 
         Ok(())
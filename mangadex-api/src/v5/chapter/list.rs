@@ -22,6 +22,27 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! Get the latest updates across all manga:
+//!
+//! ```rust
+//! use mangadex_api::v5::MangaDexClient;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let latest = client
+//!     .chapter()
+//!     .list()
+//!     .latest_updates(32)
+//!     .build()?
+//!     .send()
+//!     .await?;
+//!
+//! println!("latest chapters: {:?}", latest);
+//! # Ok(())
+//! # }
+//! ```
 
 use derive_builder::Builder;
 use serde::Serialize;
@@ -29,9 +50,11 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::ChapterListResponse;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::mangadex_datetime::serialize_query_datetime;
 use mangadex_api_types::{
     ChapterSortOrder, ContentRating, IncludeExternalUrl, IncludeFuturePages,
-    IncludeFuturePublishAt, IncludeFutureUpdates, Language, MangaDexDateTime,
+    IncludeFuturePublishAt, IncludeFutureUpdates, Language, MangaDexDateTime, OrderDirection,
     ReferenceExpansionResource,
 };
 
@@ -41,7 +64,15 @@ use mangadex_api_types::{
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 #[non_exhaustive]
 pub struct ListChapter {
     /// This should never be set manually as this is only for internal use.
@@ -92,10 +123,13 @@ pub struct ListChapter {
     /// Default: `IncludeFutureUpdates::Include` (1)
     pub include_future_updates: Option<IncludeFutureUpdates>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub created_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub updated_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub publish_at_since: Option<MangaDexDateTime>,
     /// Include empty pages
     pub include_empty_pages: Option<IncludeFuturePages>,
@@ -108,12 +142,49 @@ pub struct ListChapter {
     pub includes: Vec<ReferenceExpansionResource>,
 }
 
+impl ListChapterBuilder {
+    /// Get the `limit` most recently readable chapters, with their manga and scanlation groups
+    /// expanded.
+    ///
+    /// This is the listing shown as the latest updates on the MangaDex homepage.
+    /// Any previous `order`, `limit` and `includes` are replaced.
+    pub fn latest_updates(self, limit: u32) -> Self {
+        self.order(ChapterSortOrder::ReadableAt(OrderDirection::Descending))
+            .limit(limit)
+            .includes(vec![
+                ReferenceExpansionResource::Manga,
+                ReferenceExpansionResource::ScanlationGroup,
+            ])
+    }
+
+    /// Sort by `createdAt`, newest first, replacing any previous `order`.
+    pub fn newest_first(self) -> Self {
+        self.order(ChapterSortOrder::CreatedAt(OrderDirection::Descending))
+    }
+
+    /// Sort by `createdAt`, oldest first, replacing any previous `order`.
+    pub fn oldest_first(self) -> Self {
+        self.order(ChapterSortOrder::CreatedAt(OrderDirection::Ascending))
+    }
+
+    fn validate(&self) -> Result<()> {
+        Language::validate_known(
+            "translated_languages",
+            self.translated_languages.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
 endpoint! {
     GET "/chapter",
-    #[query] ListChapter,
+    #[query content_rating] ListChapter,
     #[flatten_result] ChapterListResponse
 }
 
+paginated! {
+    ListChapter => mangadex_api_schema::v5::ChapterObject
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::name::en::Name;
@@ -261,4 +332,99 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn list_chapter_latest_updates_orders_by_readable_at_and_expands_manga_and_groups(
+    ) -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let list_chapter = mangadex_client
+            .chapter()
+            .list()
+            .latest_updates(32)
+            .build()?;
+
+        assert_query_eq!(
+            list_chapter,
+            [
+                ("limit", "32"),
+                ("order[readableAt]", "desc"),
+                ("includes[0]", "manga"),
+                ("includes[1]", "scanlation_group"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_chapter_rejects_an_unknown_translated_language() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let res = mangadex_client
+            .chapter()
+            .list()
+            .add_translated_language(Language::from("not-a-language"))
+            .build();
+
+        assert!(matches!(
+            res,
+            Err(Error::BuilderError { field, .. }) if field == "translated_languages"
+        ));
+
+        let list_chapter = mangadex_client
+            .chapter()
+            .list()
+            .add_translated_language(Language::from("en"))
+            .build()?;
+
+        assert_query_eq!(list_chapter, [("translatedLanguage[0]", "en")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_chapter_newest_and_oldest_first_order_by_created_at() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let list_chapter = mangadex_client.chapter().list().newest_first().build()?;
+
+        assert_query_eq!(list_chapter, [("order[createdAt]", "desc")]);
+
+        let list_chapter = mangadex_client.chapter().list().oldest_first().build()?;
+
+        assert_query_eq!(list_chapter, [("order[createdAt]", "asc")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_chapter_encodes_the_date_filters_without_an_offset() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let since: MangaDexDateTime = serde_json::from_value(json!("2023-05-06T12:00:00+02:00"))?;
+
+        let list_chapter = mangadex_client
+            .chapter()
+            .list()
+            .created_at_since(since.clone())
+            .updated_at_since(since.clone())
+            .publish_at_since(since)
+            .build()?;
+
+        assert_query_eq!(
+            list_chapter,
+            [
+                ("createdAtSince", "2023-05-06T10:00:00"),
+                ("updatedAtSince", "2023-05-06T10:00:00"),
+                ("publishAtSince", "2023-05-06T10:00:00"),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -12,6 +12,8 @@ mod get;
 pub(crate) mod list;
 #[cfg(not(feature = "deserializable-endpoint"))]
 pub(crate) mod upload;
+#[cfg(not(feature = "deserializable-endpoint"))]
+pub(crate) mod urls;
 
 #[cfg(feature = "deserializable-endpoint")]
 pub mod delete;
@@ -23,12 +25,17 @@ pub mod get;
 pub mod list;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod upload;
+#[cfg(feature = "deserializable-endpoint")]
+pub mod urls;
 
+#[cfg(feature = "utils")]
+use crate::utils::download::all_covers::DownloadAllCoversBuilder;
 use crate::v5::cover::delete::DeleteCoverBuilder;
 use crate::v5::cover::edit::EditCoverBuilder;
 use crate::v5::cover::get::GetCoverBuilder;
 use crate::v5::cover::list::ListCoverBuilder;
 use crate::v5::cover::upload::UploadCoverBuilder;
+use crate::v5::cover::urls::CoverUrlsBuilder;
 use crate::HttpClientRef;
 
 /// Cover art endpoint handler builder.
@@ -95,4 +102,15 @@ impl CoverBuilder {
     pub fn upload(&self) -> UploadCoverBuilder {
         UploadCoverBuilder::default().http_client(self.http_client.clone())
     }
+
+    /// List the covers of a manga and resolve their image URLs, without downloading them.
+    pub fn urls(&self) -> CoverUrlsBuilder {
+        CoverUrlsBuilder::default().http_client(self.http_client.clone())
+    }
+
+    /// List the covers of a manga and download them concurrently.
+    #[cfg(feature = "utils")]
+    pub fn download_all(&self) -> DownloadAllCoversBuilder {
+        DownloadAllCoversBuilder::default().http_client(self.http_client.clone())
+    }
 }
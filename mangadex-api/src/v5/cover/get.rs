@@ -40,7 +40,11 @@ use mangadex_api_types::ReferenceExpansionResource;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetCover {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -60,7 +64,8 @@ pub struct GetCover {
 endpoint! {
     GET ("/cover/{}", cover_id),
     #[query] GetCover,
-    #[flatten_result] CoverResponse
+    #[flatten_result] CoverResponse,
+    #[not_found("cover", cover_id)]
 }
 
 #[cfg(test)]
@@ -181,16 +186,12 @@ mod tests {
             .await
             .expect_err("expected error");
 
-        if let Error::Api(errors) = res {
-            assert_eq!(errors.errors.len(), 1);
-
-            assert_eq!(errors.errors[0].id, error_id);
-            assert_eq!(errors.errors[0].status, 404);
-            assert_eq!(errors.errors[0].title, Some("Not found".to_string()));
-            assert_eq!(
-                errors.errors[0].detail,
-                Some("Cover could not be found".to_string())
-            );
+        match res {
+            Error::NotFound { resource, id } => {
+                assert_eq!(resource, "cover");
+                assert_eq!(id, Some(cover_id));
+            }
+            other => panic!("expected a not found error, got {other:?}"),
         }
 
         Ok(())
@@ -41,7 +41,12 @@ use mangadex_api_types::{Language, ReferenceExpansionResource, CoverSortOrder};
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct ListCover {
     /// This should never be set manually as this is only for internal use.
@@ -75,6 +80,10 @@ endpoint! {
     #[flatten_result] CoverListResponse
 }
 
+paginated! {
+    ListCover => mangadex_api_schema::v5::CoverObject
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::lorem::en::Sentence;
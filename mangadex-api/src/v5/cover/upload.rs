@@ -60,7 +60,11 @@ use mangadex_api_types::Language;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct UploadCover {
     /// This should never be set manually as this is only for internal use.
@@ -126,13 +130,12 @@ impl Endpoint for UploadCover {
 impl UploadCover {
     pub async fn send(&self) -> CoverResponse {
         #[cfg(not(feature = "multi-thread"))]
-        {
-            self.http_client.try_borrow()?.send_request(self).await?
-        }
+        let request = self.http_client.try_borrow()?.prepare_request(self)?;
         #[cfg(feature = "multi-thread")]
-        {
-            self.http_client.lock().await.send_request(self).await?
-        }
+        let request = self.http_client.lock().await.prepare_request(self)?;
+        let (res, _) = request.send_with_headers::<CoverResponse>().await?;
+
+        res
     }
 }
 
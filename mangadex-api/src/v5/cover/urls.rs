@@ -0,0 +1,190 @@
+//! Builder for resolving the image URLs of every cover of a manga, without downloading them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::MangaDexClient;
+//! use mangadex_api_types::CoverSize;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! // Official Test Manga ID.
+//! let manga_id = Uuid::parse_str("f9c33607-9180-4ba6-b85c-e4b5faee7192")?;
+//!
+//! let covers = client
+//!     .cover()
+//!     .urls()
+//!     .manga_id(manga_id)
+//!     .size(CoverSize::Small)
+//!     .build()?
+//!     .send()
+//!     .await?;
+//!
+//! for (cover, url) in covers {
+//!     println!("{}: {url}", cover.attributes.file_name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use derive_builder::Builder;
+use mangadex_api_schema::v5::CoverObject;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::CoverSize;
+use url::Url;
+use uuid::Uuid;
+
+use crate::{HttpClientRef, MangaDexClient, CDN_URL};
+
+/// Number of covers the manga covers are listed by per request.
+pub(crate) const COVERS_PER_PAGE: u32 = 100;
+
+#[derive(Clone, Debug, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
+pub struct CoverUrls {
+    /// This should never be set manually as this is only for internal use.
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    pub manga_id: Uuid,
+    #[builder(default)]
+    pub size: CoverSize,
+    /// Host serving the cover images.
+    ///
+    /// Defaults to [`CDN_URL`].
+    #[builder(default = "Url::parse(CDN_URL).expect(\"CDN_URL is a valid URL\")")]
+    pub cdn_url: Url,
+}
+
+impl CoverUrls {
+    /// List the covers of the manga and resolve the URL of each image.
+    ///
+    /// The covers are returned in the order they were listed in.
+    pub async fn send(&self) -> Result<Vec<(CoverObject, Url)>> {
+        let covers = MangaDexClient::new_with_http_client_ref(self.http_client.clone())
+            .cover()
+            .list()
+            .add_manga_id(self.manga_id)
+            .limit(COVERS_PER_PAGE)
+            .build()?
+            .collect_all()
+            .await?;
+
+        covers
+            .into_iter()
+            .map(|cover| {
+                let url = cover_url(
+                    &self.cdn_url,
+                    self.manga_id,
+                    &cover.attributes.file_name,
+                    self.size,
+                )?;
+
+                Ok((cover, url))
+            })
+            .collect()
+    }
+}
+
+/// Get the URL of a cover image of the given size on the CDN.
+pub(crate) fn cover_url(
+    cdn_url: &Url,
+    manga_id: Uuid,
+    file_name: &str,
+    size: CoverSize,
+) -> Result<Url> {
+    Ok(cdn_url.join(&format!("/covers/{manga_id}/{file_name}{}", size.suffix()))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::CoverSize;
+
+    #[tokio::test]
+    async fn cover_urls_are_resolved_without_downloading() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_id = Uuid::new_v4();
+        let cover = |file_name: &str, volume: &str| {
+            json!({
+                "id": Uuid::new_v4(),
+                "type": "cover_art",
+                "attributes": {
+                    "volume": volume,
+                    "fileName": file_name,
+                    "description": "",
+                    "locale": "ja",
+                    "version": 1,
+                    "createdAt": "2021-05-24T17:03:01+00:00",
+                    "updatedAt": "2021-05-24T17:03:01+00:00"
+                },
+                "relationships": []
+            })
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/cover"))
+            .and(query_param("manga[0]", manga_id.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [cover("first.jpg", "1"), cover("second.png", "2")],
+                "limit": 100,
+                "offset": 0,
+                "total": 2
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let covers = mangadex_client
+            .cover()
+            .urls()
+            .manga_id(manga_id)
+            .size(CoverSize::Small)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(covers.len(), 2);
+        for (cover, url) in &covers {
+            assert_eq!(
+                url.as_str(),
+                format!(
+                    "https://uploads.mangadex.org/covers/{manga_id}/{}.256.jpg",
+                    cover.attributes.file_name
+                )
+            );
+        }
+        // Only the cover list was requested.
+        assert_eq!(
+            mock_server
+                .received_requests()
+                .await
+                .unwrap_or_default()
+                .len(),
+            1
+        );
+
+        Ok(())
+    }
+}
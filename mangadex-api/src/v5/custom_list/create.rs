@@ -52,7 +52,11 @@ use mangadex_api_types::CustomListVisibility;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct CreateCustomList {
     /// This should never be set manually as this is only for internal use.
@@ -72,11 +76,16 @@ pub struct CreateCustomList {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     pub version: Option<u32>,
+    /// Key sent in the `Idempotency-Key` header so that the request can be retried
+    /// without creating a duplicate.
+    #[serde(skip)]
+    #[builder(default)]
+    pub idempotency_key: Option<Uuid>,
 }
 
 endpoint! {
     POST ("/list"),
-    #[body auth] CreateCustomList,
+    #[body auth idempotency_key] CreateCustomList,
     #[flatten_result] CustomListResponse
 }
 
@@ -32,6 +32,7 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::CustomListResponse;
+use mangadex_api_types::ReferenceExpansionResource;
 
 #[cfg_attr(
     feature = "deserializable-endpoint",
@@ -39,7 +40,11 @@ use mangadex_api_schema::v5::CustomListResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetCustomList{
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -50,12 +55,18 @@ pub struct GetCustomList{
 
     #[serde(skip_serializing)]
     pub list_id: Uuid,
+
+    /// Expand the relationships of the list, such as [`ReferenceExpansionResource::User`] for
+    /// its creator.
+    #[builder(setter(each = "include"), default)]
+    pub includes: Vec<ReferenceExpansionResource>,
 }
 
 endpoint! {
     GET ("/list/{}", list_id),
     #[query] GetCustomList,
-    #[flatten_result] CustomListResponse
+    #[flatten_result] CustomListResponse,
+    #[not_found("custom list", list_id)]
 }
 
 #[cfg(test)]
@@ -65,12 +76,13 @@ mod tests {
     use serde_json::json;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{method, path_regex};
+    use wiremock::matchers::{method, path_regex, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_schema::v5::RelatedAttributes;
     use mangadex_api_types::error::Error;
-    use mangadex_api_types::CustomListVisibility;
+    use mangadex_api_types::{CustomListVisibility, ReferenceExpansionResource, RelationshipType};
 
     #[tokio::test]
     async fn get_custom_list_fires_a_request_to_base_url() -> anyhow::Result<()> {
@@ -123,6 +135,72 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn get_custom_list_expands_the_creator() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let list_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let response_body = json!({
+            "result": "ok",
+            "response": "entity",
+            "data": {
+                "id": list_id,
+                "type": "custom_list",
+                "attributes": {
+                    "name": "Favourites",
+                    "visibility": "public",
+                    "version": 1
+                },
+                "relationships": [
+                    {
+                        "id": user_id,
+                        "type": "user",
+                        "attributes": {
+                            "username": "myusername",
+                            "roles": ["ROLE_MEMBER"],
+                            "version": 1
+                        }
+                    }
+                ]
+            }
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/list/[0-9a-fA-F-]+"))
+            .and(query_param("includes[0]", "user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .custom_list()
+            .get()
+            .list_id(list_id)
+            .include(ReferenceExpansionResource::User)
+            .build()?
+            .send()
+            .await?;
+
+        let creator = res
+            .data
+            .relationships
+            .first_of_type(RelationshipType::User)
+            .expect("the creator relationship is missing");
+        assert_eq!(creator.id, user_id);
+        match &creator.attributes {
+            Some(RelatedAttributes::User(user)) => assert_eq!(user.username, "myusername"),
+            other => panic!("expected the user attributes, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn get_custom_list_handles_404() -> anyhow::Result<()> {
         let mock_server = MockServer::start().await;
@@ -160,16 +238,12 @@ mod tests {
             .await
             .expect_err("expected error");
 
-        if let Error::Api(errors) = res {
-            assert_eq!(errors.errors.len(), 1);
-
-            assert_eq!(errors.errors[0].id, error_id);
-            assert_eq!(errors.errors[0].status, 404);
-            assert_eq!(errors.errors[0].title, Some("Not found".to_string()));
-            assert_eq!(
-                errors.errors[0].detail,
-                Some("CustomList could not be found".to_string())
-            );
+        match res {
+            Error::NotFound { resource, id } => {
+                assert_eq!(resource, "custom list");
+                assert_eq!(id, Some(list_id));
+            }
+            other => panic!("expected a not found error, got {other:?}"),
         }
 
         Ok(())
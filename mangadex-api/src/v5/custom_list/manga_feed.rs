@@ -33,6 +33,8 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::ChapterListResponse;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::mangadex_datetime::serialize_query_datetime;
 use mangadex_api_types::{
     ContentRating, IncludeFutureUpdates, Language, MangaDexDateTime, MangaFeedSortOrder,
     ReferenceExpansionResource,
@@ -44,7 +46,14 @@ use mangadex_api_types::{
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 #[non_exhaustive]
 pub struct CustomListMangaFeed {
     /// This should never be set manually as this is only for internal use.
@@ -82,12 +91,15 @@ pub struct CustomListMangaFeed {
     pub include_future_updates: Option<IncludeFutureUpdates>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
     #[builder(default)]
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub created_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
     #[builder(default)]
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub updated_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
     #[builder(default)]
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub publish_at_since: Option<MangaDexDateTime>,
     #[builder(default)]
     pub order: Option<MangaFeedSortOrder>,
@@ -95,12 +107,25 @@ pub struct CustomListMangaFeed {
     pub includes: Vec<ReferenceExpansionResource>,
 }
 
+impl CustomListMangaFeedBuilder {
+    fn validate(&self) -> Result<()> {
+        Language::validate_known(
+            "translated_language",
+            self.translated_language.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
 endpoint! {
     GET ("/list/{}/feed", list_id),
-    #[query] CustomListMangaFeed,
+    #[query content_rating] CustomListMangaFeed,
     #[flatten_result] ChapterListResponse
 }
 
+paginated! {
+    CustomListMangaFeed => mangadex_api_schema::v5::ChapterObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
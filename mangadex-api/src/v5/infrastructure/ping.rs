@@ -38,7 +38,11 @@ use mangadex_api_types::error::{Error, Result};
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct Ping {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -57,18 +61,10 @@ endpoint! {
 impl Ping {
     pub async fn send(&self) -> Result<String> {
         #[cfg(not(feature = "multi-thread"))]
-        let res = self
-            .http_client
-            .try_borrow()?
-            .send_request_without_deserializing(self)
-            .await?;
+        let request = self.http_client.try_borrow()?.prepare_request(self)?;
         #[cfg(feature = "multi-thread")]
-        let res = self
-            .http_client
-            .lock()
-            .await
-            .send_request_without_deserializing(self)
-            .await?;
+        let request = self.http_client.lock().await.prepare_request(self)?;
+        let res = request.send().await?;
 
         let response_body = res.text().await?;
         if response_body.as_str() == "pong" {
@@ -26,6 +26,9 @@ mod get_manga_read_chapters;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod get_read_chapters;
 #[cfg(not(feature = "deserializable-endpoint"))]
+#[cfg(feature = "statistics")]
+mod get_with_statistics;
+#[cfg(not(feature = "deserializable-endpoint"))]
 pub(crate) mod list;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod list_drafts;
@@ -36,6 +39,8 @@ mod list_tags;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod random;
 #[cfg(not(feature = "deserializable-endpoint"))]
+mod read_markers;
+#[cfg(not(feature = "deserializable-endpoint"))]
 mod reading_status;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod reading_statuses;
@@ -75,6 +80,9 @@ pub mod get_manga_read_chapters;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod get_read_chapters;
 #[cfg(feature = "deserializable-endpoint")]
+#[cfg(feature = "statistics")]
+pub mod get_with_statistics;
+#[cfg(feature = "deserializable-endpoint")]
 pub mod list;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod list_drafts;
@@ -85,6 +93,8 @@ pub mod list_tags;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod random;
 #[cfg(feature = "deserializable-endpoint")]
+pub mod read_markers;
+#[cfg(feature = "deserializable-endpoint")]
 pub mod reading_status;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod reading_statuses;
@@ -99,6 +109,13 @@ pub mod update;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod update_reading_status;
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use mangadex_api_schema::v5::TagObject;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::TagGroup;
+
 use crate::v5::manga::add_to_custom_list::AddMangaToCustomListBuilder;
 use crate::v5::manga::aggregate::GetMangaAggregateBuilder;
 use crate::v5::manga::create::CreateMangaBuilder;
@@ -111,11 +128,16 @@ use crate::v5::manga::get::GetMangaBuilder;
 use crate::v5::manga::get_draft::GetMangaDraftBuilder;
 use crate::v5::manga::get_manga_read_chapters::GetMangaReadChaptersBuilder;
 use crate::v5::manga::get_read_chapters::GetReadChaptersBuilder;
+#[cfg(feature = "statistics")]
+use crate::v5::manga::get_with_statistics::GetMangaWithStatisticsBuilder;
+#[cfg(feature = "statistics")]
+pub use crate::v5::manga::get_with_statistics::MangaWithStatistics;
 use crate::v5::manga::list::ListMangaBuilder;
 use crate::v5::manga::list_drafts::ListMangaDraftsBuilder;
 use crate::v5::manga::list_relations::ListMangaRelationsBuilder;
 use crate::v5::manga::list_tags::ListTagsBuilder;
 use crate::v5::manga::random::GetRandomMangaBuilder;
+use crate::v5::manga::read_markers::GetReadMarkersBuilder;
 use crate::v5::manga::reading_status::MangaReadingStatusBuilder;
 use crate::v5::manga::reading_statuses::MangaReadingStatusesBuilder;
 use crate::v5::manga::remove_from_custom_list::RemoveMangaFromCustomListBuilder;
@@ -177,6 +199,17 @@ impl MangaBuilder {
         self.get()
     }
 
+    /// View a single Manga along with its statistics.
+    ///
+    /// The Manga and its statistics are requested at the same time.
+    ///
+    /// <https://api.mangadex.org/swagger.html#/Manga/get-manga-id>
+    /// <https://api.mangadex.org/swagger.html#/Statistics/get-statistics-manga-uuid>
+    #[cfg(feature = "statistics")]
+    pub fn get_with_statistics(&self) -> GetMangaWithStatisticsBuilder {
+        GetMangaWithStatisticsBuilder::default().http_client(self.http_client.clone())
+    }
+
     /// Update a manga.
     ///
     /// <https://api.mangadex.org/swagger.html#/Manga/put-manga-id>
@@ -263,6 +296,16 @@ impl MangaBuilder {
         GetReadChaptersBuilder::default().http_client(self.http_client.clone())
     }
 
+    /// Get the read chapter IDs of any number of manga, grouped by manga ID.
+    ///
+    /// The manga IDs are split into several requests, as MangaDex caps how many a request can
+    /// have.
+    ///
+    /// <https://api.mangadex.org/swagger.html#/Manga/get-manga-chapter-readmarkers-2>
+    pub fn read_markers(&self) -> GetReadMarkersBuilder {
+        GetReadMarkersBuilder::default().http_client(self.http_client.clone())
+    }
+
     /// List all of the available tags.
     ///
     /// <https://api.mangadex.org/swagger.html#/Manga/get-manga-tag>
@@ -270,6 +313,52 @@ impl MangaBuilder {
         ListTagsBuilder::default().http_client(self.http_client.clone())
     }
 
+    /// Fetch all of the available tags, bucketed by their [`TagGroup`].
+    ///
+    /// The tag catalog set with [`MangaDexClient::set_tag_catalog()`] is used instead of a
+    /// request when there is one.
+    ///
+    /// <https://api.mangadex.org/swagger.html#/Manga/get-manga-tag>
+    ///
+    /// [`MangaDexClient::set_tag_catalog()`]: crate::MangaDexClient::set_tag_catalog
+    pub async fn tags_grouped(&self) -> Result<HashMap<TagGroup, Vec<TagObject>>> {
+        let mut grouped: HashMap<TagGroup, Vec<TagObject>> = HashMap::new();
+        for tag in self.tags().await?.iter() {
+            grouped
+                .entry(tag.attributes.group)
+                .or_default()
+                .push(tag.clone());
+        }
+
+        Ok(grouped)
+    }
+
+    /// Find a tag by its name, in any language and ignoring case.
+    ///
+    /// The tag catalog set with [`MangaDexClient::set_tag_catalog()`] is used instead of a
+    /// request when there is one.
+    ///
+    /// [`MangaDexClient::set_tag_catalog()`]: crate::MangaDexClient::set_tag_catalog
+    pub async fn find_tag(&self, name: &str) -> Result<Option<TagObject>> {
+        Ok(find_tag_by_name(&self.tags().await?, name).cloned())
+    }
+
+    /// Get the tags from the client's tag catalog, or fetch them if it isn't set.
+    pub(crate) async fn tags(&self) -> Result<Arc<[TagObject]>> {
+        #[cfg(not(feature = "multi-thread"))]
+        let catalog = self.http_client.try_borrow()?.get_tag_catalog().cloned();
+        #[cfg(feature = "multi-thread")]
+        let catalog = self.http_client.lock().await.get_tag_catalog().cloned();
+
+        if let Some(tags) = catalog {
+            return Ok(tags);
+        }
+
+        let tags = self.list_tags().build()?.send().await?;
+
+        Ok(tags.data.into())
+    }
+
     /// Get the reading status for a given followed manga.
     ///
     /// <https://api.mangadex.org/swagger.html#/Manga/get-manga-id-status>
@@ -346,3 +435,15 @@ impl MangaBuilder {
         DeleteMangaRelationBuilder::default().http_client(self.http_client.clone())
     }
 }
+
+/// Find the tag with the given name, in any language and ignoring case.
+pub(crate) fn find_tag_by_name<'a>(tags: &'a [TagObject], name: &str) -> Option<&'a TagObject> {
+    let name = name.trim().to_lowercase();
+
+    tags.iter().find(|tag| {
+        tag.attributes
+            .name
+            .values()
+            .any(|tag_name| tag_name.to_lowercase() == name)
+    })
+}
@@ -53,7 +53,10 @@ use mangadex_api_types::{ContentRating, Demographic, Language, MangaLinks, Manga
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option))]
+#[builder(
+    setter(into, strip_option),
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct CreateManga {
     /// This should never be set manually as this is only for internal use.
@@ -107,11 +110,16 @@ pub struct CreateManga {
     pub primary_cover: Option<Option<Uuid>>,
     /// >= 1
     pub version: u32,
+    /// Key sent in the `Idempotency-Key` header so that the request can be retried
+    /// without creating a duplicate.
+    #[serde(skip)]
+    #[builder(default)]
+    pub idempotency_key: Option<Uuid>,
 }
 
 endpoint! {
     POST "/manga",
-    #[body auth] CreateManga,
+    #[body auth idempotency_key] CreateManga,
     #[flatten_result] MangaResponse
 }
 
@@ -121,11 +129,12 @@ mod tests {
     use time::OffsetDateTime;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{any, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::v5::AuthTokens;
     use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::error::Error;
     use mangadex_api_types::{
         ContentRating, Demographic, Language, MangaDexDateTime, MangaStatus, ResponseType, Tag,
     };
@@ -597,4 +606,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn create_manga_dry_run_returns_the_body_without_sending() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client: HttpClient = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .manga()
+            .create()
+            .add_title((Language::English, "Test Manga".to_string()))
+            .original_language(Language::Japanese)
+            .status(MangaStatus::Ongoing)
+            .content_rating(ContentRating::Safe)
+            .version(1_u32)
+            .build()?
+            .dry_run()
+            .await?;
+
+        assert_eq!(res.method, reqwest::Method::POST);
+        assert_eq!(res.url.as_str(), format!("{}/manga", mock_server.uri()));
+        assert_eq!(
+            res.body,
+            Some(json!({
+                "title": {
+                    "en": "Test Manga"
+                },
+                "originalLanguage": "ja",
+                "status": "ongoing",
+                "contentRating": "safe",
+                "chapterNumbersResetOnNewVolume": false,
+                "version": 1
+            }))
+        );
+
+        // The client-side checks still apply.
+        let res = MangaDexClient::default()
+            .manga()
+            .create()
+            .add_title((Language::English, "Test Manga".to_string()))
+            .original_language(Language::Japanese)
+            .status(MangaStatus::Ongoing)
+            .content_rating(ContentRating::Safe)
+            .version(1_u32)
+            .build()?
+            .dry_run()
+            .await;
+        assert!(matches!(res, Err(Error::MissingTokens)));
+
+        Ok(())
+    }
 }
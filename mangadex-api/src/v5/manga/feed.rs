@@ -32,6 +32,8 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::ChapterListResponse;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::mangadex_datetime::serialize_query_datetime;
 use mangadex_api_types::{
     ContentRating, IncludeFutureUpdates, Language, MangaDexDateTime, MangaFeedSortOrder,
     ReferenceExpansionResource, IncludeFuturePublishAt, IncludeExternalUrl, IncludeFuturePages,
@@ -43,7 +45,14 @@ use mangadex_api_types::{
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 #[non_exhaustive]
 pub struct GetMangaFeed {
     /// This should never be set manually as this is only for internal use.
@@ -82,12 +91,15 @@ pub struct GetMangaFeed {
     pub include_future_updates: Option<IncludeFutureUpdates>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
     #[builder(default)]
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub created_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
     #[builder(default)]
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub updated_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
     #[builder(default)]
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub publish_at_since: Option<MangaDexDateTime>,
     #[builder(default)]
     pub order: Option<MangaFeedSortOrder>,
@@ -101,12 +113,25 @@ pub struct GetMangaFeed {
     pub include_external_url : Option<IncludeExternalUrl>,
 }
 
+impl GetMangaFeedBuilder {
+    fn validate(&self) -> Result<()> {
+        Language::validate_known(
+            "translated_language",
+            self.translated_language.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
 endpoint! {
     GET ("/manga/{}/feed", manga_id),
-    #[query] GetMangaFeed,
+    #[query content_rating] GetMangaFeed,
     ChapterListResponse
 }
 
+paginated! {
+    GetMangaFeed => mangadex_api_schema::v5::ChapterObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -117,7 +142,8 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::{HttpClient, MangaDexClient};
-    use mangadex_api_types::MangaDexDateTime;
+    use mangadex_api_types::error::Error;
+    use mangadex_api_types::{Language, MangaDexDateTime};
 
     #[tokio::test]
     async fn get_manga_feed_fires_a_request_to_base_url() -> anyhow::Result<()> {
@@ -186,4 +212,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn get_manga_feed_encodes_the_include_flags_as_numbers() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_feed = mangadex_client
+            .manga()
+            .feed()
+            .manga_id(Uuid::new_v4())
+            .include_future_updates(true)
+            .include_empty_pages(false)
+            .include_future_publish_at(true)
+            .include_external_url(false)
+            .build()?;
+
+        assert_query_eq!(
+            manga_feed,
+            [
+                ("includeFutureUpdates", "1"),
+                ("includeEmptyPages", "0"),
+                ("includeFuturePublishAt", "1"),
+                ("includeExternalUrl", "0"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_manga_feed_rejects_an_unknown_translated_language() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let res = mangadex_client
+            .manga()
+            .feed()
+            .manga_id(Uuid::new_v4())
+            .add_translated_language(Language::from("xx"))
+            .build();
+
+        assert!(matches!(
+            res,
+            Err(Error::BuilderError { field, .. }) if field == "translated_language"
+        ));
+
+        let manga_feed = mangadex_client
+            .manga()
+            .feed()
+            .manga_id(Uuid::new_v4())
+            .add_translated_language(Language::from("ja"))
+            .build()?;
+
+        assert_eq!(manga_feed.translated_language, vec![Language::Japanese]);
+
+        Ok(())
+    }
 }
@@ -40,7 +40,11 @@ use mangadex_api_types::ReferenceExpansionResource;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetManga {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -59,7 +63,8 @@ pub struct GetManga {
 endpoint! {
     GET ("/manga/{}", manga_id),
     #[query] GetManga,
-    #[flatten_result] MangaResponse
+    #[flatten_result] MangaResponse,
+    #[not_found("manga", manga_id)]
 }
 
 #[cfg(test)]
@@ -73,6 +78,7 @@ mod tests {
 
     use crate::{HttpClient, MangaDexClient};
     use mangadex_api_schema::v5::RelatedAttributes;
+    use mangadex_api_types::error::Error;
     use mangadex_api_types::{
         MangaDexDateTime, MangaRelation, ReferenceExpansionResource, RelationshipType,
     };
@@ -497,4 +503,64 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn get_manga_without_manga_id_returns_a_builder_error() {
+        let mangadex_client = MangaDexClient::default();
+
+        let res = mangadex_client.manga().get().build();
+
+        match res {
+            Err(Error::BuilderError { field, message }) => {
+                assert_eq!(field, "manga_id");
+                assert_eq!(message, "must be initialized");
+            }
+            other => panic!("expected a builder error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_manga_handles_404_as_not_found() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_id = Uuid::new_v4();
+        let response_body = json!({
+            "result": "error",
+            "errors": [{
+                "id": Uuid::new_v4(),
+                "status": 404,
+                "title": "Not found",
+                "detail": "Manga could not be found"
+            }]
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .manga()
+            .get()
+            .manga_id(manga_id)
+            .build()?
+            .send()
+            .await;
+
+        match res {
+            Err(Error::NotFound { resource, id }) => {
+                assert_eq!(resource, "manga");
+                assert_eq!(id, Some(manga_id));
+            }
+            other => panic!("expected a not found error, got {other:?}"),
+        }
+
+        Ok(())
+    }
 }
@@ -40,7 +40,11 @@ use mangadex_api_schema::v5::MangaReadMarkersResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetReadChapters {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -49,6 +53,7 @@ pub struct GetReadChapters {
     #[cfg_attr(feature = "deserializable-endpoint", getset(set = "pub", get = "pub"))]
     pub(crate) http_client: HttpClientRef,
 
+    #[serde(rename = "ids")]
     #[builder(setter(each = "add_manga_id"))]
     pub manga_ids: Vec<Uuid>,
 
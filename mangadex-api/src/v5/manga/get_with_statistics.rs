@@ -0,0 +1,215 @@
+//! Builder for getting a single Manga along with its statistics.
+//!
+//! This sends the following requests at the same time:
+//!
+//! - <https://api.mangadex.org/swagger.html#/Manga/get-manga-id>
+//! - <https://api.mangadex.org/swagger.html#/Statistics/get-statistics-manga-uuid>
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::v5::MangaDexClient;
+//! use mangadex_api_types::ReferenceExpansionResource;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! // Official Test Manga ID.
+//! let manga_id = Uuid::parse_str("f9c33607-9180-4ba6-b85c-e4b5faee7192")?;
+//!
+//! let res = client
+//!     .manga()
+//!     .get_with_statistics()
+//!     .manga_id(manga_id)
+//!     .include(ReferenceExpansionResource::Author)
+//!     .build()?
+//!     .send()
+//!     .await?;
+//!
+//! println!(
+//!     "{:?} is followed by {} users",
+//!     res.manga.data.attributes.title, res.statistics.follows
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use derive_builder::Builder;
+use uuid::Uuid;
+
+use crate::v5::manga::get::GetManga;
+use crate::v5::statistics::get_manga::GetMangaStatistics;
+use crate::HttpClientRef;
+use mangadex_api_schema::v5::statistics::manga::MangaStatistics;
+use mangadex_api_schema::v5::MangaData;
+use mangadex_api_types::error::{Error, Result};
+use mangadex_api_types::ReferenceExpansionResource;
+
+/// A Manga with its statistics, returned by [`GetMangaWithStatistics`].
+#[derive(Debug, Clone)]
+pub struct MangaWithStatistics {
+    pub manga: MangaData,
+    pub statistics: MangaStatistics,
+}
+
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
+pub struct GetMangaWithStatistics {
+    /// This should never be set manually as this is only for internal use.
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    pub manga_id: Uuid,
+
+    #[builder(setter(each = "include"), default)]
+    pub includes: Vec<ReferenceExpansionResource>,
+}
+
+impl GetMangaWithStatistics {
+    /// Send both requests and merge the responses.
+    ///
+    /// An error is returned if either request fails.
+    pub async fn send(&self) -> Result<MangaWithStatistics> {
+        let get_manga = GetManga {
+            http_client: self.http_client.clone(),
+            manga_id: self.manga_id,
+            includes: self.includes.clone(),
+        };
+        let get_statistics = GetMangaStatistics {
+            http_client: self.http_client.clone(),
+            manga_id: self.manga_id,
+        };
+
+        let (manga, mut statistics) = futures::try_join!(get_manga.send(), get_statistics.send())?;
+
+        let statistics = statistics
+            .statistics
+            .remove(&self.manga_id)
+            .ok_or_else(|| {
+                Error::ParseError(format!(
+                    "the statistics response doesn't include manga {}",
+                    self.manga_id
+                ))
+            })?;
+
+        Ok(MangaWithStatistics { manga, statistics })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path_regex, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::{Language, ReferenceExpansionResource};
+
+    #[tokio::test]
+    async fn get_with_statistics_merges_the_manga_and_its_statistics() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_id = Uuid::new_v4();
+        let manga_body = json!({
+            "result": "ok",
+            "response": "entity",
+            "data": {
+                "id": manga_id,
+                "type": "manga",
+                "attributes": {
+                    "title": {
+                        "en": "Test Manga"
+                    },
+                    "altTitles": [],
+                    "description": [],
+                    "isLocked": false,
+                    "links": null,
+                    "originalLanguage": "ja",
+                    "lastVolume": null,
+                    "lastChapter": null,
+                    "publicationDemographic": null,
+                    "status": "ongoing",
+                    "year": null,
+                    "contentRating": "safe",
+                    "chapterNumbersResetOnNewVolume": false,
+                    "availableTranslatedLanguages": [],
+                    "tags": [],
+                    "state": "published",
+                    "createdAt": "2022-01-01T00:00:00+00:00",
+                    "updatedAt": "2022-01-01T00:00:00+00:00",
+                    "version": 1
+                },
+                "relationships": []
+            }
+        });
+        let statistics_body = json!({
+            "result": "ok",
+            "statistics": {
+                manga_id.to_string(): {
+                    "rating": {
+                        "average": 7.5,
+                        "distribution": {
+                            "1": 0,
+                            "2": 0,
+                            "3": 0,
+                            "4": 0,
+                            "5": 0,
+                            "6": 0,
+                            "7": 2,
+                            "8": 2,
+                            "9": 0,
+                            "10": 0
+                        }
+                    },
+                    "follows": 42
+                }
+            }
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/manga/[0-9a-fA-F-]+$"))
+            .and(query_param("includes[0]", "author"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(manga_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/statistics/manga/[0-9a-fA-F-]+$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(statistics_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .manga()
+            .get_with_statistics()
+            .manga_id(manga_id)
+            .include(ReferenceExpansionResource::Author)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.manga.data.id, manga_id);
+        assert_eq!(
+            res.manga.data.attributes.title.get(&Language::English),
+            Some(&"Test Manga".to_string())
+        );
+        assert_eq!(res.statistics.follows, 42);
+        assert_eq!(res.statistics.rating.average, Some(7.5));
+
+        Ok(())
+    }
+}
@@ -29,11 +29,14 @@ use derive_builder::Builder;
 use serde::Serialize;
 use uuid::Uuid;
 
+use crate::v5::manga::find_tag_by_name;
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::MangaListResponse;
+use mangadex_api_types::error::{Error, Result};
+use mangadex_api_types::mangadex_datetime::serialize_query_datetime;
 use mangadex_api_types::{
     ContentRating, Demographic, Language, MangaDexDateTime, MangaSortOrder, MangaStatus,
-    ReferenceExpansionResource, TagSearchMode,
+    OrderDirection, ReferenceExpansionResource, TagSearchMode,
 };
 
 #[cfg_attr(
@@ -42,7 +45,15 @@ use mangadex_api_types::{
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 #[non_exhaustive]
 pub struct ListManga {
     #[doc(hidden)]
@@ -57,16 +68,24 @@ pub struct ListManga {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     pub author_or_artist : Option<Uuid>,
+    /// Author IDs, sent as `authors[]`.
     #[builder(setter(each = "add_author"))]
     pub authors: Vec<Uuid>,
+    /// Artist IDs, sent as `artists[]`.
     #[builder(setter(each = "add_artist"))]
     pub artists: Vec<Uuid>,
     pub year: Option<u16>,
+    /// Building the request fails with [`Error::InvalidRequest`] if a tag is both included and
+    /// excluded.
     #[builder(setter(each = "include_tag"))]
     pub included_tags: Vec<Uuid>,
+    /// Building the request fails with [`Error::InvalidRequest`] if this is set without any
+    /// `included_tags`.
     pub included_tags_mode: Option<TagSearchMode>,
     #[builder(setter(each = "exclude_tag"))]
     pub excluded_tags: Vec<Uuid>,
+    /// Building the request fails with [`Error::InvalidRequest`] if this is set without any
+    /// `excluded_tags`.
     pub excluded_tags_mode: Option<TagSearchMode>,
     #[builder(setter(each = "add_status"))]
     pub status: Vec<MangaStatus>,
@@ -87,8 +106,10 @@ pub struct ListManga {
     #[builder(setter(each = "add_content_rating"))]
     pub content_rating: Vec<ContentRating>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub created_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
+    #[serde(serialize_with = "serialize_query_datetime")]
     pub updated_at_since: Option<MangaDexDateTime>,
     pub order: Option<MangaSortOrder>,
     #[builder(setter(each = "include"))]
@@ -98,25 +119,150 @@ pub struct ListManga {
     pub group: Option<Uuid>,
 }
 
+impl ListMangaBuilder {
+    /// Expand the cover art, authors and artists of the manga, replacing any previous `includes`.
+    pub fn include_all_common(self) -> Self {
+        self.includes(vec![
+            ReferenceExpansionResource::CoverArt,
+            ReferenceExpansionResource::Author,
+            ReferenceExpansionResource::Artist,
+        ])
+    }
+
+    /// Expand the cover art of the manga.
+    pub fn include_cover(self) -> Self {
+        self.include(ReferenceExpansionResource::CoverArt)
+    }
+
+    /// Only get the completed manga, replacing any previous `status` filter.
+    pub fn only_completed(self) -> Self {
+        self.status(vec![MangaStatus::Completed])
+    }
+
+    /// Only get the ongoing manga, replacing any previous `status` filter.
+    pub fn only_ongoing(self) -> Self {
+        self.status(vec![MangaStatus::Ongoing])
+    }
+
+    /// Sort by `createdAt`, newest first, replacing any previous `order`.
+    pub fn newest_first(self) -> Self {
+        self.order(MangaSortOrder::CreatedAt(OrderDirection::Descending))
+    }
+
+    /// Sort by `createdAt`, oldest first, replacing any previous `order`.
+    pub fn oldest_first(self) -> Self {
+        self.order(MangaSortOrder::CreatedAt(OrderDirection::Ascending))
+    }
+
+    /// Sort by relevance to the `title`, best match first, replacing any previous `order`.
+    ///
+    /// MangaDex only ranks by relevance when a `title` is searched; without one, this has no
+    /// effect on the order.
+    pub fn best_match(self) -> Self {
+        self.order(MangaSortOrder::Relevance(OrderDirection::Descending))
+    }
+
+    /// Include the tag with the given name, in any language and ignoring case.
+    ///
+    /// The name is looked up in the tag catalog set with
+    /// [`MangaDexClient::set_tag_catalog()`]. An error is returned if no catalog is set or if it
+    /// has no tag with that name.
+    ///
+    /// [`MangaDexClient::set_tag_catalog()`]: crate::MangaDexClient::set_tag_catalog
+    pub async fn include_tag_named(self, name: &str) -> Result<Self> {
+        let tag_id = self.tag_id_named("included_tags", name).await?;
+
+        Ok(self.include_tag(tag_id))
+    }
+
+    /// Exclude the tag with the given name, in any language and ignoring case.
+    ///
+    /// The name is looked up in the tag catalog set with
+    /// [`MangaDexClient::set_tag_catalog()`]. An error is returned if no catalog is set or if it
+    /// has no tag with that name.
+    ///
+    /// [`MangaDexClient::set_tag_catalog()`]: crate::MangaDexClient::set_tag_catalog
+    pub async fn exclude_tag_named(self, name: &str) -> Result<Self> {
+        let tag_id = self.tag_id_named("excluded_tags", name).await?;
+
+        Ok(self.exclude_tag(tag_id))
+    }
+
+    /// Look up the ID of a tag by its name in the client's tag catalog.
+    async fn tag_id_named(&self, field: &str, name: &str) -> Result<Uuid> {
+        let builder_error = |message: String| Error::BuilderError {
+            field: field.to_string(),
+            message,
+        };
+        let http_client = self
+            .http_client
+            .as_ref()
+            .ok_or_else(|| builder_error("the HTTP client is not set".to_string()))?;
+
+        #[cfg(not(feature = "multi-thread"))]
+        let http_client = http_client.try_borrow()?;
+        #[cfg(feature = "multi-thread")]
+        let http_client = http_client.lock().await;
+
+        let tags = http_client.get_tag_catalog().ok_or_else(|| {
+            builder_error("a tag catalog must be set to look up tags by name".to_string())
+        })?;
+
+        find_tag_by_name(tags, name)
+            .map(|tag| tag.id)
+            .ok_or_else(|| builder_error(format!("no tag named {name:?} in the tag catalog")))
+    }
+
+    fn validate(&self) -> Result<()> {
+        let included_tags = self.included_tags.as_deref().unwrap_or_default();
+        let excluded_tags = self.excluded_tags.as_deref().unwrap_or_default();
+
+        if let Some(tag) = included_tags.iter().find(|tag| excluded_tags.contains(tag)) {
+            return Err(Error::InvalidRequest(format!(
+                "tag {tag} is both included and excluded"
+            )));
+        }
+
+        if matches!(self.included_tags_mode, Some(Some(_))) && included_tags.is_empty() {
+            return Err(Error::InvalidRequest(
+                "included_tags_mode is set without any included_tags".to_string(),
+            ));
+        }
+
+        if matches!(self.excluded_tags_mode, Some(Some(_))) && excluded_tags.is_empty() {
+            return Err(Error::InvalidRequest(
+                "excluded_tags_mode is set without any excluded_tags".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 endpoint! {
     GET "/manga",
-    #[query] ListManga,
+    #[query content_rating] ListManga,
     #[flatten_result] MangaListResponse
 }
 
+paginated! {
+    ListManga => mangadex_api_schema::v5::MangaObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
     use time::OffsetDateTime;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::{HttpClient, MangaDexClient};
     use mangadex_api_types::error::Error;
     use mangadex_api_types::{
         ContentRating, Demographic, Language, MangaDexDateTime, MangaStatus, ResponseType,
+        TagSearchMode,
     };
 
     #[tokio::test]
@@ -275,4 +421,446 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn list_manga_reports_the_limit_the_server_applied() -> anyhow::Result<()> {
+        use mangadex_api_schema::v5::CollectionResponse;
+
+        let mock_server = MockServer::start().await;
+        let http_client: HttpClient = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 100,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga"))
+            .and(query_param("limit", "200"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .manga()
+            .search()
+            .limit(200u32)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.limit, 100);
+        assert!(res.pagination().was_clamped(200));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_manga_include_all_common_expands_three_resources() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga"))
+            .and(query_param("includes[0]", "cover_art"))
+            .and(query_param("includes[1]", "author"))
+            .and(query_param("includes[2]", "artist"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request = mangadex_client
+            .manga()
+            .search()
+            .include_all_common()
+            .build()?;
+
+        assert_eq!(request.includes.len(), 3);
+
+        request.send().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_manga_by_author_and_artist_encodes_both_array_params() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let author_id = Uuid::new_v4();
+        let artist_id = Uuid::new_v4();
+        let group_id = Uuid::new_v4();
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga"))
+            .and(query_param("authors[0]", author_id.to_string()))
+            .and(query_param("artists[0]", artist_id.to_string()))
+            .and(query_param("group", group_id.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        mangadex_client
+            .manga()
+            .search()
+            .add_author(author_id)
+            .add_artist(artist_id)
+            .group(group_id)
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_manga_applies_the_default_content_rating_when_unset() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .default_content_rating(vec![ContentRating::Safe, ContentRating::Suggestive])
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga"))
+            .and(query_param("title", "Test"))
+            .and(query_param("contentRating[0]", "safe"))
+            .and(query_param("contentRating[1]", "suggestive"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        mangadex_client
+            .manga()
+            .search()
+            .title("Test")
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_manga_content_rating_overrides_the_default() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .default_content_rating(vec![ContentRating::Safe, ContentRating::Suggestive])
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/manga"))
+            .and(query_param("contentRating[0]", "erotica"))
+            .and(query_param_is_missing("contentRating[1]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        mangadex_client
+            .manga()
+            .search()
+            .add_content_rating(ContentRating::Erotica)
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_manga_encodes_the_available_chapters_filters() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let list_manga = mangadex_client
+            .manga()
+            .list()
+            .has_available_chapters(true)
+            .add_available_translated_language(Language::English)
+            .add_available_translated_language(Language::Japanese)
+            .build()?;
+
+        assert_query_eq!(
+            list_manga,
+            [
+                ("hasAvailableChapters", "true"),
+                ("availableTranslatedLanguage[0]", "en"),
+                ("availableTranslatedLanguage[1]", "ja"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_manga_encodes_the_date_filters_without_an_offset() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let created_at_since: MangaDexDateTime =
+            serde_json::from_value(json!("2023-05-06T07:08:09.123Z"))?;
+        let updated_at_since: MangaDexDateTime =
+            serde_json::from_value(json!("2023-05-06T12:00:00+02:00"))?;
+
+        let list_manga = mangadex_client
+            .manga()
+            .list()
+            .created_at_since(created_at_since)
+            .updated_at_since(updated_at_since)
+            .build()?;
+
+        assert_query_eq!(
+            list_manga,
+            [
+                ("createdAtSince", "2023-05-06T07:08:09"),
+                ("updatedAtSince", "2023-05-06T10:00:00"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_manga_only_completed_replaces_the_status_filter() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let list_manga = mangadex_client
+            .manga()
+            .list()
+            .add_status(MangaStatus::Hiatus)
+            .only_completed()
+            .build()?;
+
+        assert_query_eq!(list_manga, [("status[0]", "completed")]);
+
+        let list_manga = mangadex_client.manga().list().only_ongoing().build()?;
+
+        assert_query_eq!(list_manga, [("status[0]", "ongoing")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_manga_rejects_a_tag_both_included_and_excluded() {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let tag_id = Uuid::new_v4();
+        let res = mangadex_client
+            .manga()
+            .list()
+            .include_tag(Uuid::new_v4())
+            .include_tag(tag_id)
+            .exclude_tag(tag_id)
+            .build();
+
+        match res {
+            Err(Error::InvalidRequest(message)) => assert!(message.contains(&tag_id.to_string())),
+            other => panic!("expected an invalid request error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn list_manga_rejects_a_tags_mode_without_tags() {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let res = mangadex_client
+            .manga()
+            .list()
+            .included_tags_mode(TagSearchMode::And)
+            .build();
+        assert!(matches!(res, Err(Error::InvalidRequest(_))));
+
+        let res = mangadex_client
+            .manga()
+            .list()
+            .include_tag(Uuid::new_v4())
+            .excluded_tags_mode(TagSearchMode::Or)
+            .build();
+        assert!(matches!(res, Err(Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn list_manga_accepts_distinct_included_and_excluded_tags() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let included_tag = Uuid::new_v4();
+        let excluded_tag = Uuid::new_v4();
+        let list_manga = mangadex_client
+            .manga()
+            .list()
+            .include_tag(included_tag)
+            .included_tags_mode(TagSearchMode::And)
+            .exclude_tag(excluded_tag)
+            .excluded_tags_mode(TagSearchMode::Or)
+            .build()?;
+
+        assert_eq!(list_manga.included_tags, vec![included_tag]);
+        assert_eq!(list_manga.excluded_tags, vec![excluded_tag]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_manga_newest_and_oldest_first_order_by_created_at() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let list_manga = mangadex_client.manga().list().newest_first().build()?;
+
+        assert_query_eq!(list_manga, [("order[createdAt]", "desc")]);
+
+        let list_manga = mangadex_client.manga().list().oldest_first().build()?;
+
+        assert_query_eq!(list_manga, [("order[createdAt]", "asc")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_manga_best_match_orders_by_relevance() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let list_manga = mangadex_client
+            .manga()
+            .search()
+            .title("Lycoris Recoil")
+            .best_match()
+            .build()?;
+
+        assert_query_eq!(
+            list_manga,
+            [("title", "Lycoris Recoil"), ("order[relevance]", "desc")]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_manga_resolves_tag_names_from_the_tag_catalog() -> anyhow::Result<()> {
+        let mangadex_client = MangaDexClient::default();
+
+        let romance_id = Uuid::parse_str("423e2eae-a7a2-4a8b-ac03-a8351462d71d")?;
+        let oneshot_id = Uuid::parse_str("0234a31e-a729-4e28-9d6a-3f87c4966b9e")?;
+        let tag = |id: Uuid, name: &str, group: &str| {
+            json!({
+                "id": id,
+                "type": "tag",
+                "attributes": {
+                    "name": {
+                        "en": name
+                    },
+                    "description": [],
+                    "group": group,
+                    "version": 1
+                },
+                "relationships": []
+            })
+        };
+        mangadex_client
+            .set_tag_catalog(serde_json::from_value::<
+                Vec<mangadex_api_schema::v5::TagObject>,
+            >(json!([
+                tag(romance_id, "Romance", "genre"),
+                tag(oneshot_id, "Oneshot", "format"),
+            ]))?)
+            .await?;
+
+        let list_manga = mangadex_client
+            .manga()
+            .search()
+            .include_tag_named("Romance")
+            .await?
+            .exclude_tag_named("oneshot")
+            .await?
+            .build()?;
+
+        assert_query_eq!(
+            list_manga,
+            [
+                ("includedTags[0]", romance_id.to_string()),
+                ("excludedTags[0]", oneshot_id.to_string())
+            ]
+        );
+
+        let res = mangadex_client
+            .manga()
+            .search()
+            .include_tag_named("Isekai")
+            .await;
+        assert!(matches!(res, Err(Error::BuilderError { .. })));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_manga_tag_names_require_a_tag_catalog() {
+        let res = MangaDexClient::default()
+            .manga()
+            .search()
+            .include_tag_named("Romance")
+            .await;
+
+        assert!(matches!(res, Err(Error::BuilderError { .. })));
+    }
 }
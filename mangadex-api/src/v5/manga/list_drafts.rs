@@ -50,7 +50,12 @@ use mangadex_api_types::{MangaDraftsSortOrder, MangaState, ReferenceExpansionRes
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct ListMangaDrafts {
     #[doc(hidden)]
@@ -81,6 +86,10 @@ endpoint! {
     #[flatten_result] MangaListResponse
 }
 
+paginated! {
+    ListMangaDrafts => mangadex_api_schema::v5::MangaObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -36,7 +36,11 @@ use mangadex_api_schema::v5::TagListResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct ListTags {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -54,6 +58,8 @@ endpoint! {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use serde_json::json;
     use url::Url;
     use uuid::Uuid;
@@ -61,6 +67,7 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_schema::v5::TagObject;
     use mangadex_api_types::{Language, ResponseType, TagGroup};
 
     #[tokio::test]
@@ -135,4 +142,133 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn tags_grouped_buckets_tags_by_group() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let tag = |id: &str, name: &str, group: &str| {
+            json!({
+                "id": id,
+                "type": "tag",
+                "attributes": {
+                    "name": {
+                        "en": name
+                    },
+                    "description": [],
+                    "group": group,
+                    "version": 1
+                },
+                "relationships": []
+            })
+        };
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [
+                tag("0234a31e-a729-4e28-9d6a-3f87c4966b9e", "Oneshot", "format"),
+                tag("07251805-a27e-4d59-b488-f0bfbec15168", "Thriller", "genre"),
+                tag("33771934-028e-4cb3-8744-691e866a923e", "Romance", "genre"),
+                tag("423e2eae-a7a2-4a8b-ac03-a8351462d71d", "Vampires", "theme"),
+            ],
+            "limit": 10,
+            "offset": 0,
+            "total": 4
+        });
+
+        Mock::given(method("GET"))
+            .and(path(r"/manga/tag"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let grouped = mangadex_client.manga().tags_grouped().await?;
+
+        assert_eq!(grouped.len(), 3);
+        assert_eq!(grouped[&TagGroup::Format].len(), 1);
+        assert_eq!(grouped[&TagGroup::Genre].len(), 2);
+        assert_eq!(grouped[&TagGroup::Theme].len(), 1);
+        assert!(grouped[&TagGroup::Genre]
+            .iter()
+            .all(|tag| tag.attributes.group == TagGroup::Genre));
+        assert!(!grouped.contains_key(&TagGroup::Content));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_tag_uses_the_tag_catalog_without_a_request() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let catalog: Arc<[TagObject]> = serde_json::from_value::<Vec<TagObject>>(json!([
+            {
+                "id": "33771934-028e-4cb3-8744-691e866a923e",
+                "type": "tag",
+                "attributes": {
+                    "name": {
+                        "en": "Romance"
+                    },
+                    "description": [],
+                    "group": "genre",
+                    "version": 1
+                },
+                "relationships": []
+            },
+            {
+                "id": "0234a31e-a729-4e28-9d6a-3f87c4966b9e",
+                "type": "tag",
+                "attributes": {
+                    "name": {
+                        "en": "Oneshot"
+                    },
+                    "description": [],
+                    "group": "format",
+                    "version": 1
+                },
+                "relationships": []
+            }
+        ]))?
+        .into();
+        mangadex_client.set_tag_catalog(catalog.clone()).await?;
+        {
+            let http_client = mangadex_client.get_http_client();
+            #[cfg(not(feature = "multi-thread"))]
+            let http_client = http_client.try_borrow()?;
+            #[cfg(feature = "multi-thread")]
+            let http_client = http_client.lock().await;
+            let shared = http_client
+                .get_tag_catalog()
+                .is_some_and(|tags| Arc::ptr_eq(tags, &catalog));
+            assert!(shared);
+        }
+
+        Mock::given(method("GET"))
+            .and(path(r"/manga/tag"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let romance = mangadex_client.manga().find_tag("romance").await?;
+        assert_eq!(
+            romance.map(|tag| tag.id),
+            Some(Uuid::parse_str("33771934-028e-4cb3-8744-691e866a923e")?)
+        );
+        assert!(mangadex_client.manga().find_tag("Isekai").await?.is_none());
+
+        let grouped = mangadex_client.manga().tags_grouped().await?;
+        assert_eq!(grouped[&TagGroup::Genre].len(), 1);
+        assert_eq!(grouped[&TagGroup::Format].len(), 1);
+
+        Ok(())
+    }
 }
@@ -36,7 +36,11 @@ use crate::HttpClientRef;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetRandomManga {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -66,7 +70,7 @@ pub struct GetRandomManga {
 
 endpoint! {
     GET ("/manga/random"),
-    #[query] GetRandomManga,
+    #[query content_rating] GetRandomManga,
     #[flatten_result] MangaResponse
 }
 
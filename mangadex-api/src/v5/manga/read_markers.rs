@@ -0,0 +1,228 @@
+//! Builder for fetching the read markers of many manga at once.
+//!
+//! This sends grouped `GET /manga/read` requests with a limited number of manga IDs each,
+//! a few at a time, and merges the results.
+//!
+//! <https://api.mangadex.org/swagger.html#/Manga/get-manga-chapter-readmarkers-2>
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::v5::MangaDexClient;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let manga_ids: Vec<Uuid> = (0..400).map(|_| Uuid::new_v4()).collect();
+//! let read_markers = client
+//!     .manga()
+//!     .read_markers()
+//!     .manga_ids(manga_ids)
+//!     .build()?
+//!     .send()
+//!     .await?;
+//!
+//! for (manga_id, chapter_ids) in read_markers {
+//!     println!("{manga_id}: {} chapters read", chapter_ids.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use derive_builder::Builder;
+use futures::StreamExt;
+use uuid::Uuid;
+
+use crate::v5::manga::get_read_chapters::GetReadChapters;
+use crate::HttpClientRef;
+use mangadex_api_schema::v5::MangaReadMarkers;
+use mangadex_api_types::error::{Error, Result};
+
+/// Maximum number of manga IDs MangaDex accepts in a single read markers request.
+pub const MAX_MANGA_IDS_PER_REQUEST: usize = 100;
+
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
+pub struct GetReadMarkers {
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    /// Manga to get the read markers of. Duplicates are only requested once.
+    #[builder(setter(each = "add_manga_id"))]
+    pub manga_ids: Vec<Uuid>,
+    /// Number of manga IDs sent per request.
+    ///
+    /// Defaults to, and can't be more than, [`MAX_MANGA_IDS_PER_REQUEST`].
+    #[builder(default = "MAX_MANGA_IDS_PER_REQUEST")]
+    pub chunk_size: usize,
+    /// Maximum number of requests in flight at once.
+    #[builder(default = "4")]
+    pub concurrency: usize,
+}
+
+impl GetReadMarkersBuilder {
+    fn validate(&self) -> Result<()> {
+        if let Some(chunk_size) = self.chunk_size {
+            if chunk_size == 0 || chunk_size > MAX_MANGA_IDS_PER_REQUEST {
+                return Err(Error::BuilderError {
+                    field: "chunk_size".to_string(),
+                    message: format!("must be between 1 and {MAX_MANGA_IDS_PER_REQUEST}"),
+                });
+            }
+        }
+
+        if self.concurrency == Some(0) {
+            return Err(Error::BuilderError {
+                field: "concurrency".to_string(),
+                message: "must be at least 1".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl GetReadMarkers {
+    /// Send the requests and merge the read chapter IDs, keyed by manga ID.
+    ///
+    /// An error is returned if any of the requests fails.
+    pub async fn send(&self) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let mut seen = HashSet::new();
+        let manga_ids: Vec<Uuid> = self
+            .manga_ids
+            .iter()
+            .copied()
+            .filter(|manga_id| seen.insert(*manga_id))
+            .collect();
+
+        let mut responses = futures::stream::iter(manga_ids.chunks(self.chunk_size))
+            .map(|manga_ids| async move {
+                GetReadChapters {
+                    http_client: self.http_client.clone(),
+                    manga_ids: manga_ids.to_vec(),
+                    grouped: true,
+                }
+                .send()
+                .await
+            })
+            .buffer_unordered(self.concurrency);
+
+        let mut read_markers = HashMap::new();
+        while let Some(response) = responses.next().await {
+            match response? {
+                MangaReadMarkers::Grouped(grouped) => read_markers.extend(grouped.data),
+                // MangaDex sends an empty list instead of an empty map when nothing was read.
+                MangaReadMarkers::Ungrouped(ungrouped) if ungrouped.data.is_empty() => {}
+                MangaReadMarkers::Ungrouped(_) => {
+                    return Err(Error::ParseError(
+                        "expected read markers grouped by manga ID".to_string(),
+                    ))
+                }
+            }
+        }
+
+        Ok(read_markers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+
+    #[tokio::test]
+    async fn read_markers_are_fetched_in_chunks_and_merged() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_ids: Vec<Uuid> = (0..150).map(|_| Uuid::new_v4()).collect();
+        let chapter_ids: HashMap<Uuid, Uuid> = manga_ids
+            .iter()
+            .map(|manga_id| (*manga_id, Uuid::new_v4()))
+            .collect();
+
+        let response_chapter_ids = chapter_ids.clone();
+        Mock::given(method("GET"))
+            .and(path("/manga/read"))
+            .and(query_param("grouped", "true"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .respond_with(move |req: &Request| {
+                // Mark one chapter as read for every requested manga.
+                let data: HashMap<String, Vec<Uuid>> = req
+                    .url
+                    .query_pairs()
+                    .filter(|(key, _)| key.starts_with("ids["))
+                    .map(|(_, manga_id)| {
+                        let chapter_id = response_chapter_ids[&Uuid::parse_str(&manga_id).unwrap()];
+                        (manga_id.into_owned(), vec![chapter_id])
+                    })
+                    .collect();
+
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "result": "ok",
+                    "data": data
+                }))
+            })
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        // Duplicates are only requested once.
+        let mut requested_ids = manga_ids.clone();
+        requested_ids.extend_from_slice(&manga_ids[..10]);
+
+        let res = mangadex_client
+            .manga()
+            .read_markers()
+            .manga_ids(requested_ids)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.len(), 150);
+        for (manga_id, chapter_id) in &chapter_ids {
+            assert_eq!(res[manga_id], vec![*chapter_id]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_markers_rejects_a_chunk_size_over_the_maximum() {
+        let res = MangaDexClient::default()
+            .manga()
+            .read_markers()
+            .add_manga_id(Uuid::new_v4())
+            .chunk_size(101_usize)
+            .build();
+
+        assert!(res.is_err());
+    }
+}
@@ -39,7 +39,11 @@ use mangadex_api_schema::v5::MangaReadingStatusResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct MangaReadingStatus {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -37,7 +37,12 @@ use mangadex_api_types::ReadingStatus;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned", default)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    default,
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct MangaReadingStatuses {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -1,6 +1,6 @@
-//! Builder for the chapter update endpoint.
+//! Builder for the manga update endpoint.
 //!
-//! <https://api.mangadex.org/swagger.html#/Manga/put-chapter-id>
+//! <https://api.mangadex.org/swagger.html#/Manga/put-manga-id>
 //!
 //! # Examples
 //!
@@ -53,15 +53,20 @@ use mangadex_api_types::{ContentRating, Demographic, Language, MangaLinks, Manga
 
 /// Update a manga's information.
 ///
-/// All fields that are not changing should still have the field populated with the old information
-/// so that it is not set as `null` on the server.
+/// Only the fields that are set are sent, so the others are left unchanged on the server.
+///
+/// If `version` isn't the current version of the manga, MangaDex responds with `409 Conflict`,
+/// which can be checked with [`Error::is_conflict()`](mangadex_api_types::error::Error::is_conflict).
 #[cfg_attr(
     feature = "deserializable-endpoint",
     derive(serde::Deserialize, getset::Getters, getset::Setters)
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option))]
+#[builder(
+    setter(into, strip_option),
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct UpdateManga {
     /// This should never be set manually as this is only for internal use.
@@ -114,8 +119,9 @@ pub struct UpdateManga {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     pub content_rating: Option<ContentRating>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
-    pub chapter_numbers_reset_on_new_volume: bool,
+    pub chapter_numbers_reset_on_new_volume: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     pub tags: Option<Vec<Uuid>>,
@@ -140,7 +146,7 @@ mod tests {
     use time::OffsetDateTime;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{header, method, path_regex};
+    use wiremock::matchers::{body_json, header, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::v5::AuthTokens;
@@ -555,4 +561,128 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn update_manga_only_sends_the_fields_that_were_set() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client: HttpClient = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_id = Uuid::new_v4();
+        let datetime = MangaDexDateTime::new(&OffsetDateTime::now_utc());
+
+        let expected_body = json!({
+            "status": "completed",
+            "lastChapter": "42",
+            "version": 3
+        });
+        let response_body = json!({
+            "result": "ok",
+            "response": "entity",
+            "data": {
+                "id": manga_id,
+                "type": "manga",
+                "attributes": {
+                    "title": {
+                        "en": "Test Manga"
+                    },
+                    "altTitles": [],
+                    "description": {},
+                    "isLocked": false,
+                    "links": null,
+                    "originalLanguage": "ja",
+                    "lastVolume": null,
+                    "lastChapter": "42",
+                    "publicationDemographic": null,
+                    "status": "completed",
+                    "year": null,
+                    "contentRating": "safe",
+                    "chapterNumbersResetOnNewVolume": false,
+                    "availableTranslatedLanguages": ["en"],
+                    "tags": [],
+                    "state": "published",
+                    "createdAt": datetime.to_string(),
+                    "updatedAt": datetime.to_string(),
+                    "version": 4
+                },
+                "relationships": []
+            }
+        });
+
+        Mock::given(method("PUT"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .and(body_json(expected_body))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .manga()
+            .update()
+            .manga_id(manga_id)
+            .status(MangaStatus::Completed)
+            .last_chapter(Some("42".to_string()))
+            .version(3_u32)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.data.id, manga_id);
+        assert_eq!(res.data.attributes.version, 4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_manga_with_an_outdated_version_is_a_conflict() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client: HttpClient = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let error_body = json!({
+            "result": "error",
+            "errors": [{
+                "id": Uuid::new_v4(),
+                "status": 409,
+                "title": "Conflict",
+                "detail": "Version mismatch",
+                "context": null
+            }]
+        });
+
+        Mock::given(method("PUT"))
+            .and(path_regex(r"/manga/[0-9a-fA-F-]+"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(error_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .manga()
+            .update()
+            .manga_id(Uuid::new_v4())
+            .status(MangaStatus::Completed)
+            .version(1_u32)
+            .build()?
+            .send()
+            .await;
+
+        assert!(res.is_err_and(|e| e.is_conflict()));
+
+        Ok(())
+    }
 }
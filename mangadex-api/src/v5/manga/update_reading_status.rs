@@ -43,7 +43,11 @@ use mangadex_api_types::ReadingStatus;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into), pattern = "owned")]
+#[builder(
+    setter(into),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct UpdateMangaReadingStatus {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -47,6 +47,15 @@ impl RatingBuilder {
         GetYourMangaRatingsBuilder::default().http_client(self.http_client.clone())
     }
 
+    /// Get the ratings of the authenticated user for a list of manga.
+    ///
+    /// Alias to [`get_your_manga_ratings()`](RatingBuilder::get_your_manga_ratings).
+    ///
+    /// <https://api.mangadex.org/swagger.html#/Rating/get-rating>
+    pub fn list(&self) -> GetYourMangaRatingsBuilder {
+        self.get_your_manga_ratings()
+    }
+
     /// Create or update a Manga rating for the authenticated user.
     ///
     /// <https://api.mangadex.org/swagger.html#/Rating/post-rating-manga-id>
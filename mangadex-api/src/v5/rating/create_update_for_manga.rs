@@ -46,7 +46,7 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::NoData;
-use mangadex_api_types::error::Result;
+use mangadex_api_types::error::{Error, Result};
 
 #[cfg_attr(
     feature = "deserializable-endpoint",
@@ -54,7 +54,14 @@ use mangadex_api_types::error::Result;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 pub struct CreateUpdateMangaRating {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -68,31 +75,28 @@ pub struct CreateUpdateMangaRating {
 
     /// `[ 1 .. 10 ]`.
     ///
-    /// Numbers below `1` will be set at `1` and numbers above `10` will be set at `10`.
+    /// Building the request fails with [`Error::OutOfRange`] for numbers outside of this range.
     pub rating: u8,
 }
 
-impl CreateUpdateMangaRating {
-    pub async fn send(&mut self) -> Result<NoData> {
-        if self.rating < 1 {
-            self.rating = 1;
-        } else if self.rating > 10 {
-            self.rating = 10;
+impl CreateUpdateMangaRatingBuilder {
+    fn validate(&self) -> Result<()> {
+        if let Some(rating) = self.rating {
+            if !(1..=10).contains(&rating) {
+                return Err(Error::OutOfRange(format!(
+                    "rating must be between 1 and 10, got {rating}"
+                )));
+            }
         }
 
-        #[cfg(not(feature = "multi-thread"))]
-        let res = self.http_client.try_borrow()?.send_request(self).await??;
-        #[cfg(feature = "multi-thread")]
-        let res = self.http_client.lock().await.send_request(self).await??;
-
-        Ok(res)
+        Ok(())
     }
 }
 
 endpoint! {
     POST ("/rating/{}", manga_id),
     #[body auth] CreateUpdateMangaRating,
-    #[no_send] Result<NoData>
+    #[flatten_result] Result<NoData>
 }
 
 #[cfg(test)]
@@ -149,88 +153,46 @@ mod tests {
         Ok(())
     }
 
-    #[tokio::test]
-    async fn create_update_manga_rating_sets_rating_below_min_to_1() -> anyhow::Result<()> {
-        let mock_server = MockServer::start().await;
-        let http_client = HttpClient::builder()
-            .base_url(Url::parse(&mock_server.uri())?)
-            .auth_tokens(AuthTokens {
-                session: "sessiontoken".to_string(),
-                refresh: "refreshtoken".to_string(),
-            })
-            .build()?;
-        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+    #[test]
+    fn create_update_manga_rating_rejects_rating_below_1() {
+        let mangadex_client = MangaDexClient::default();
 
-        let expected_body = json!({
-            "rating": 1
-        });
-
-        let manga_id = Uuid::new_v4();
-        let response_body = json!({
-            "result": "ok",
-        });
-
-        Mock::given(method("POST"))
-            .and(path_regex(r"/rating/[0-9a-fA-F-]+"))
-            .and(header("Content-Type", "application/json"))
-            .and(body_json(expected_body))
-            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
-            .expect(1)
-            .mount(&mock_server)
-            .await;
-
-        let _res = mangadex_client
+        let res = mangadex_client
             .rating()
             .upsert_for_manga()
-            .manga_id(manga_id)
+            .manga_id(Uuid::new_v4())
             .rating(0)
-            .build()?
-            .send()
-            .await?;
+            .build();
 
-        Ok(())
+        assert!(matches!(res, Err(Error::OutOfRange(_))));
     }
 
-    #[tokio::test]
-    async fn create_update_manga_rating_sets_rating_above_max_to_10() -> anyhow::Result<()> {
-        let mock_server = MockServer::start().await;
-        let http_client = HttpClient::builder()
-            .base_url(Url::parse(&mock_server.uri())?)
-            .auth_tokens(AuthTokens {
-                session: "sessiontoken".to_string(),
-                refresh: "refreshtoken".to_string(),
-            })
-            .build()?;
-        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+    #[test]
+    fn create_update_manga_rating_rejects_rating_above_10() {
+        let mangadex_client = MangaDexClient::default();
 
-        let expected_body = json!({
-            "rating": 10
-        });
+        let res = mangadex_client
+            .rating()
+            .upsert_for_manga()
+            .manga_id(Uuid::new_v4())
+            .rating(11)
+            .build();
 
-        let manga_id = Uuid::new_v4();
-        let response_body = json!({
-            "result": "ok",
-        });
+        assert!(matches!(res, Err(Error::OutOfRange(_))));
+    }
 
-        Mock::given(method("POST"))
-            .and(path_regex(r"/rating/[0-9a-fA-F-]+"))
-            .and(header("Content-Type", "application/json"))
-            .and(body_json(expected_body))
-            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
-            .expect(1)
-            .mount(&mock_server)
-            .await;
+    #[test]
+    fn create_update_manga_rating_accepts_rating_of_10() {
+        let mangadex_client = MangaDexClient::default();
 
-        let _res = mangadex_client
+        let res = mangadex_client
             .rating()
             .upsert_for_manga()
-            .manga_id(manga_id)
-            .rating(11)
-            .build()?
-            .send()
-            .await?;
+            .manga_id(Uuid::new_v4())
+            .rating(10)
+            .build();
 
-        Ok(())
+        assert!(res.is_ok());
     }
 
     #[tokio::test]
@@ -45,6 +45,10 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::RatingsResponse;
+use mangadex_api_types::error::{Error, Result};
+
+/// Maximum number of manga IDs that can be sent in a single request.
+const MAX_MANGA_IDS: usize = 100;
 
 #[cfg_attr(
     feature = "deserializable-endpoint",
@@ -52,7 +56,15 @@ use mangadex_api_schema::v5::RatingsResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 pub struct GetYourMangaRatings {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -61,10 +73,36 @@ pub struct GetYourMangaRatings {
     #[cfg_attr(feature = "deserializable-endpoint", getset(set = "pub", get = "pub"))]
     pub(crate) http_client: HttpClientRef,
 
+    /// Manga to get the ratings of.
+    ///
+    /// A maximum of 100 manga IDs can be requested at once.
     #[builder(setter(each = "manga_id"))]
     pub manga: Vec<Uuid>,
 }
 
+impl GetYourMangaRatingsBuilder {
+    /// Set the manga to get the ratings of, replacing any previously added.
+    pub fn manga_ids(self, manga_ids: Vec<Uuid>) -> Self {
+        self.manga(manga_ids)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(manga) = &self.manga {
+            if manga.len() > MAX_MANGA_IDS {
+                return Err(Error::BuilderError {
+                    field: "manga".to_string(),
+                    message: format!(
+                        "must contain at most {MAX_MANGA_IDS} manga IDs, got {}",
+                        manga.len()
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
 endpoint! {
     GET "/rating",
     #[query auth] GetYourMangaRatings,
@@ -76,7 +114,7 @@ mod tests {
     use serde_json::json;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::v5::AuthTokens;
@@ -125,6 +163,72 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn list_ratings_sends_the_manga_ids_and_deserializes_the_map() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_id_1 = Uuid::new_v4();
+        let manga_id_2 = Uuid::new_v4();
+        let response_body = json!({
+            "result": "ok",
+            "ratings": {
+                manga_id_1.to_string(): {
+                    "rating": 7,
+                    "createdAt": "2021-12-27T08:47:37+00:00"
+                },
+                manga_id_2.to_string(): {
+                    "rating": 10,
+                    "createdAt": "2022-01-03T10:00:00+00:00"
+                }
+            }
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/rating"))
+            .and(query_param("manga[0]", manga_id_1.to_string()))
+            .and(query_param("manga[1]", manga_id_2.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .rating()
+            .list()
+            .manga_ids(vec![manga_id_1, manga_id_2])
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.ratings.len(), 2);
+        assert_eq!(res.ratings[&manga_id_1].rating, 7);
+        assert_eq!(
+            res.ratings[&manga_id_1].created_at.to_string(),
+            "2021-12-27T08:47:37+00:00"
+        );
+        assert_eq!(res.ratings[&manga_id_2].rating, 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_ratings_rejects_more_than_100_manga_ids() {
+        let mangadex_client = MangaDexClient::default();
+
+        let manga_ids = (0..101).map(|_| Uuid::new_v4()).collect();
+        let res = mangadex_client.rating().list().manga_ids(manga_ids).build();
+
+        assert!(res.is_err());
+    }
+
     #[tokio::test]
     async fn your_manga_ratings_requires_auth() -> anyhow::Result<()> {
         let mock_server = MockServer::start().await;
@@ -5,6 +5,8 @@
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod create;
 #[cfg(not(feature = "deserializable-endpoint"))]
+mod create_many;
+#[cfg(not(feature = "deserializable-endpoint"))]
 pub(crate) mod list;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod list_reports_by_user;
@@ -12,11 +14,15 @@ mod list_reports_by_user;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod create;
 #[cfg(feature = "deserializable-endpoint")]
+pub mod create_many;
+#[cfg(feature = "deserializable-endpoint")]
 pub mod list;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod list_reports_by_user;
 
 use crate::v5::report::create::CreateReportBuilder;
+use crate::v5::report::create_many::CreateManyReportsBuilder;
+pub use crate::v5::report::create_many::ReportInput;
 use crate::v5::report::list::ListReasonsBuilder;
 use crate::v5::report::list_reports_by_user::ListReportsByUserBuilder;
 use crate::HttpClientRef;
@@ -53,4 +59,13 @@ impl ReportBuilder {
     pub fn create(&self) -> CreateReportBuilder {
         CreateReportBuilder::default().http_client(self.http_client.clone())
     }
+
+    /// Create several reports, sending a few requests at a time.
+    ///
+    /// <https://api.mangadex.org/swagger.html#/Report/post-report>
+    pub fn create_many(&self, reports: Vec<ReportInput>) -> CreateManyReportsBuilder {
+        CreateManyReportsBuilder::default()
+            .http_client(self.http_client.clone())
+            .reports(reports)
+    }
 }
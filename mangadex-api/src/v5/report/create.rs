@@ -56,7 +56,11 @@ use mangadex_api_types::ReportCategory;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct CreateReport {
     #[doc(hidden)]
     #[serde(skip)]
@@ -77,11 +81,16 @@ pub struct CreateReport {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     pub details: Option<String>,
+    /// Key sent in the `Idempotency-Key` header so that the request can be retried
+    /// without creating a duplicate.
+    #[serde(skip)]
+    #[builder(default)]
+    pub idempotency_key: Option<Uuid>,
 }
 
 endpoint! {
     POST "/report",
-    #[body auth] CreateReport,
+    #[body auth idempotency_key] CreateReport,
     #[discard_result] Result<NoData>
 }
 
@@ -143,4 +152,134 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn create_report_reuses_the_idempotency_key_when_retried() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let idempotency_key = Uuid::new_v4();
+
+        Mock::given(method("POST"))
+            .and(path("/report"))
+            .and(header(
+                "Idempotency-Key",
+                idempotency_key.to_string().as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/report"))
+            .and(header(
+                "Idempotency-Key",
+                idempotency_key.to_string().as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": "ok"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request = mangadex_client
+            .report()
+            .create()
+            .category(ReportCategory::Manga)
+            .reason(Uuid::new_v4())
+            .object_id(Uuid::new_v4())
+            .idempotency_key(idempotency_key)
+            .build()?;
+
+        assert!(request.send().await.is_err());
+        request.send().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_report_to_http_request_builds_the_request_without_sending_it(
+    ) -> anyhow::Result<()> {
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse("https://api.mangadex.org")?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let reason_id = Uuid::new_v4();
+        let manga_id = Uuid::new_v4();
+
+        let request = mangadex_client
+            .report()
+            .create()
+            .category(ReportCategory::Manga)
+            .reason(reason_id)
+            .object_id(manga_id)
+            .build()?
+            .to_http_request()
+            .await?;
+
+        assert_eq!(request.method(), http::Method::POST);
+        assert_eq!(request.uri(), "https://api.mangadex.org/report");
+        assert_eq!(request.headers()["Authorization"], "Bearer sessiontoken");
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(request.body())?,
+            json!({
+                "category": "manga",
+                "reason": reason_id,
+                "objectId": manga_id,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_report_to_curl_redacts_the_bearer_token() -> anyhow::Result<()> {
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse("https://api.mangadex.org")?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let reason_id = Uuid::new_v4();
+        let manga_id = Uuid::new_v4();
+
+        let request = mangadex_client
+            .report()
+            .create()
+            .category(ReportCategory::Manga)
+            .reason(reason_id)
+            .object_id(manga_id)
+            .details("It's a duplicate")
+            .build()?;
+
+        let curl = request.to_curl().await?;
+
+        assert!(curl.starts_with("curl -X POST 'https://api.mangadex.org/report'"));
+        assert!(curl.contains("-H 'authorization: Bearer <redacted>'"));
+        assert!(!curl.contains("sessiontoken"));
+        assert!(curl.contains(&format!(
+            r#"--data '{{"category":"manga","reason":"{reason_id}","objectId":"{manga_id}","details":"It'\''s a duplicate"}}'"#
+        )));
+
+        let curl = request.to_curl_with_secrets().await?;
+
+        assert!(curl.contains("-H 'authorization: Bearer sessiontoken'"));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,229 @@
+//! Builder for creating several reports at once.
+//!
+//! This sends a `POST /report` request per report, a few at a time.
+//!
+//! <https://api.mangadex.org/swagger.html#/Report/post-report>
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::v5::ReportInput;
+//! use mangadex_api::MangaDexClient;
+//! use mangadex_api_types::ReportCategory;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let reason_id = Uuid::new_v4();
+//! let reports = vec![
+//!     ReportInput::new(ReportCategory::Manga, reason_id, Uuid::new_v4()),
+//!     ReportInput::new(ReportCategory::Manga, reason_id, Uuid::new_v4()),
+//! ];
+//!
+//! let results = client
+//!     .report()
+//!     .create_many(reports)
+//!     .concurrency(2usize)
+//!     .build()?
+//!     .send()
+//!     .await;
+//!
+//! for (index, result) in results.iter().enumerate() {
+//!     if let Err(e) = result {
+//!         println!("report {index} failed: {e}");
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use derive_builder::Builder;
+use futures::StreamExt;
+use uuid::Uuid;
+
+use crate::v5::report::create::CreateReport;
+use crate::HttpClientRef;
+use mangadex_api_types::error::{Error, Result};
+use mangadex_api_types::ReportCategory;
+
+/// A report to create with [`CreateManyReports`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportInput {
+    pub category: ReportCategory,
+    /// The report reason ID for sub-categorization.
+    pub reason: Uuid,
+    /// The ID from the category type.
+    pub object_id: Uuid,
+    /// Optional notes about why this is being reported.
+    pub details: Option<String>,
+}
+
+impl ReportInput {
+    pub fn new(category: ReportCategory, reason: Uuid, object_id: Uuid) -> Self {
+        Self {
+            category,
+            reason,
+            object_id,
+            details: None,
+        }
+    }
+
+    /// Add notes about why this is being reported.
+    pub fn details<T: Into<String>>(mut self, details: T) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
+pub struct CreateManyReports {
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    pub reports: Vec<ReportInput>,
+    /// Maximum number of requests in flight at once.
+    #[builder(default = "4")]
+    pub concurrency: usize,
+}
+
+impl CreateManyReportsBuilder {
+    fn validate(&self) -> Result<()> {
+        if self.concurrency == Some(0) {
+            return Err(Error::BuilderError {
+                field: "concurrency".to_string(),
+                message: "must be at least 1".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl CreateManyReports {
+    /// Send the requests.
+    ///
+    /// The results are in the same order as the reports, so that the failed reports can be
+    /// retried individually.
+    pub async fn send(&self) -> Vec<Result<()>> {
+        futures::stream::iter(self.reports.iter().cloned())
+            .map(|report| async move {
+                CreateReport {
+                    http_client: self.http_client.clone(),
+                    category: report.category,
+                    reason: report.reason,
+                    object_id: report.object_id,
+                    details: report.details,
+                    idempotency_key: None,
+                }
+                .send()
+                .await
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::ReportInput;
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::error::Error;
+    use mangadex_api_types::ReportCategory;
+
+    #[tokio::test]
+    async fn create_many_reports_sends_every_report_and_returns_each_result() -> anyhow::Result<()>
+    {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let reason_id = Uuid::new_v4();
+        let rejected_id = Uuid::new_v4();
+        let reports = vec![
+            ReportInput::new(ReportCategory::Manga, reason_id, Uuid::new_v4()),
+            ReportInput::new(ReportCategory::Manga, reason_id, rejected_id).details("duplicate"),
+            ReportInput::new(ReportCategory::Manga, reason_id, Uuid::new_v4()),
+        ];
+
+        Mock::given(method("POST"))
+            .and(path("/report"))
+            .and(body_partial_json(json!({"objectId": rejected_id})))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "result": "error",
+                "errors": [
+                    {
+                        "id": Uuid::new_v4(),
+                        "status": 400,
+                        "title": "Bad Request",
+                        "detail": "Already reported"
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/report"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": "ok"})))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let results = mangadex_client
+            .report()
+            .create_many(reports)
+            .concurrency(2usize)
+            .build()?
+            .send()
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(Error::Api(_))));
+        assert!(results[2].is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_many_reports_requires_a_concurrency_of_at_least_one() {
+        let mangadex_client = MangaDexClient::default();
+
+        let res = mangadex_client
+            .report()
+            .create_many(Vec::new())
+            .concurrency(0usize)
+            .build();
+
+        assert!(matches!(
+            res,
+            Err(Error::BuilderError { field, .. }) if field == "concurrency"
+        ));
+    }
+}
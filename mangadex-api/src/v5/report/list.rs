@@ -40,7 +40,11 @@ use mangadex_api_types::ReportCategory;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct ListReasons {
     #[doc(hidden)]
     #[serde(skip)]
@@ -37,7 +37,12 @@ use mangadex_api_types::{ReportCategory, ReportSortOrder, ReportStatus, Referenc
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct ListReportsByUser {
     #[doc(hidden)]
     #[serde(skip)]
@@ -60,6 +65,10 @@ endpoint! {
     #[flatten_result] UserReportsListResponse
 }
 
+paginated! {
+    ListReportsByUser => mangadex_api_schema::v5::UserReportsObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -40,7 +40,11 @@ use mangadex_api_types::ReferenceExpansionResource;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetGroup {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -59,7 +63,8 @@ pub struct GetGroup {
 endpoint! {
     GET ("/group/{}", group_id),
     #[query] GetGroup,
-    #[flatten_result] GroupResponse
+    #[flatten_result] GroupResponse,
+    #[not_found("scanlation group", group_id)]
 }
 
 #[cfg(test)]
@@ -38,7 +38,12 @@ use mangadex_api_types::{GroupSortOrder, Language, ReferenceExpansionResource};
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct ListGroup {
     #[doc(hidden)]
@@ -67,6 +72,10 @@ endpoint! {
     #[flatten_result] GroupListResponse
 }
 
+paginated! {
+    ListGroup => mangadex_api_schema::v5::GroupObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
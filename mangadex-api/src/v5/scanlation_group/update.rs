@@ -52,7 +52,11 @@ use mangadex_api_types::{Language, MangaDexDuration};
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct UpdateGroup {
     /// This should never be set manually as this is only for internal use.
@@ -5,7 +5,7 @@
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod find_manga;
 #[cfg(not(feature = "deserializable-endpoint"))]
-mod get_manga;
+pub(crate) mod get_manga;
 
 #[cfg(feature = "deserializable-endpoint")]
 pub mod find_manga;
@@ -13,6 +13,8 @@ mod delete_images;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod get_session;
 #[cfg(not(feature = "deserializable-endpoint"))]
+mod session_guard;
+#[cfg(not(feature = "deserializable-endpoint"))]
 mod start_edit_chapter_session;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod start_session;
@@ -30,6 +32,8 @@ pub mod delete_images;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod get_session;
 #[cfg(feature = "deserializable-endpoint")]
+pub mod session_guard;
+#[cfg(feature = "deserializable-endpoint")]
 pub mod start_edit_chapter_session;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod start_session;
@@ -42,8 +46,13 @@ use crate::v5::upload::commit_session::CommitUploadSessionBuilder;
 use crate::v5::upload::delete_image::DeleteImageBuilder;
 use crate::v5::upload::delete_images::DeleteImagesBuilder;
 use crate::v5::upload::get_session::GetUploadSessionBuilder;
+pub use crate::v5::upload::session_guard::{
+    AbandonSessionFuture, AbandonSessionSpawner, UploadSessionGuard,
+};
 use crate::v5::upload::start_edit_chapter_session::StartEditChapterSessionBuilder;
 use crate::v5::upload::start_session::StartUploadSessionBuilder;
+#[cfg(feature = "utils")]
+use crate::v5::upload::upload_images::UploadImageStreamBuilder;
 use crate::v5::upload::upload_images::UploadImagesBuilder;
 use crate::HttpClientRef;
 
@@ -76,6 +85,9 @@ impl UploadBuilder {
     /// Start an upload session.
     ///
     /// <https://api.mangadex.org/swagger.html#/Upload/begin-upload-session>
+    ///
+    /// Use `begin_session()` instead of `send()` on the built request to get an
+    /// [`UploadSessionGuard`] for committing or abandoning the session.
     pub fn start_session(&self) -> StartUploadSessionBuilder {
         StartUploadSessionBuilder::default().http_client(self.http_client.clone())
     }
@@ -96,6 +108,15 @@ impl UploadBuilder {
         UploadImagesBuilder::default().http_client(self.http_client.clone())
     }
 
+    /// Upload the images of a stream to the upload session, sending as many requests as needed
+    /// to respect the limit of images per request.
+    ///
+    /// <https://api.mangadex.org/swagger.html#/Upload/put-upload-session-file>
+    #[cfg(feature = "utils")]
+    pub fn upload_image_stream(&self) -> UploadImageStreamBuilder {
+        UploadImageStreamBuilder::default().http_client(self.http_client.clone())
+    }
+
     /// Abandon an ongoing upload session.
     ///
     /// <https://api.mangadex.org/swagger.html#/Upload/abandon-upload-session>
@@ -41,14 +41,19 @@
 //! # }
 //! ```
 
+use derive_builder::UninitializedFieldError;
 use mangadex_api_schema::v5::ChapterObject;
+use reqwest::StatusCode;
 use serde::Serialize;
 use url::Url;
 use uuid::Uuid;
 
-use crate::HttpClientRef;
+use crate::{HttpClient, HttpClientRef};
 use mangadex_api_types::error::{Error, Result};
-use mangadex_api_types::{Language, MangaDexDateTime};
+use mangadex_api_types::{Clock, Language, MangaDexDateTime, SystemClock};
+
+/// How far in the future MangaDex lets a chapter release be scheduled.
+pub const MAX_PUBLISH_DELAY: time::Duration = time::Duration::weeks(2);
 
 #[cfg_attr(
     feature = "deserializable-endpoint",
@@ -71,6 +76,9 @@ pub struct CommitUploadSession {
     ///
     /// Any uploaded files that are not included in this list will be deleted.
     pub page_order: Vec<Uuid>,
+    /// Send the commit a second time if MangaDex answers with a `409 Conflict`.
+    #[serde(skip)]
+    pub retry_on_conflict: bool,
 }
 
 #[cfg_attr(feature = "deserializable-endpoint", derive(serde::Deserialize))]
@@ -119,6 +127,7 @@ pub struct CommitUploadSessionBuilder {
     /// Nullable
     pub external_url: Option<Url>,
     pub publish_at: Option<MangaDexDateTime>,
+    pub retry_on_conflict: bool,
 }
 
 impl CommitUploadSessionBuilder {
@@ -184,25 +193,60 @@ impl CommitUploadSessionBuilder {
     /// Nullable
     ///
     /// This should not be used if chapter has images uploaded to MangaDex.
+    /// A chapter with an external URL can be committed without any pages.
     pub fn external_url(mut self, external_url: Option<Url>) -> Self {
         self.external_url = external_url;
         self
     }
 
-    /// Specify the date and time the chapter was originally published at.
+    /// Schedule the release of the chapter at the given date and time.
+    ///
+    /// It must be in the future, at most [`MAX_PUBLISH_DELAY`] from now, which is checked when
+    /// building the request.
     pub fn publish_at<DT: Into<MangaDexDateTime>>(mut self, publish_at: DT) -> Self {
         self.publish_at = Some(publish_at.into());
         self
     }
 
+    /// Retry the commit once if it fails with a `409 Conflict`, e.g. when another edit raced it.
+    ///
+    /// This is disabled by default as blindly sending a commit again isn't always what you want.
+    pub fn retry_on_conflict(mut self, retry_on_conflict: bool) -> Self {
+        self.retry_on_conflict = retry_on_conflict;
+        self
+    }
+
     /// Validate the field values. Use this before building.
-    fn validate(&self) -> std::result::Result<(), String> {
-        if self.session_id.is_none() {
-            return Err("session_id cannot be None".to_string());
+    fn validate(&self) -> Result<()> {
+        // Chapters hosted off-site are committed with an external URL and no pages.
+        if self.page_order.is_empty() && self.external_url.is_none() {
+            return Err(Error::BuilderError {
+                field: "page_order".to_string(),
+                message: "must be set when external_url isn't".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check that the scheduled release is in the window MangaDex allows at the time of `clock`.
+    fn validate_publish_at(&self, clock: impl Clock) -> Result<()> {
+        let Some(publish_at) = &self.publish_at else {
+            return Ok(());
+        };
+        let now = clock.now();
+
+        if publish_at.as_ref() <= now.as_ref() {
+            return Err(Error::InvalidRequest(format!(
+                "publish_at {publish_at} is not in the future"
+            )));
         }
 
-        if self.translated_language.is_none() {
-            return Err("translated_language cannot be None".to_string());
+        if *publish_at.as_ref() > *now.as_ref() + MAX_PUBLISH_DELAY {
+            return Err(Error::InvalidRequest(format!(
+                "publish_at {publish_at} is more than {} days away",
+                MAX_PUBLISH_DELAY.whole_days()
+            )));
         }
 
         Ok(())
@@ -210,12 +254,20 @@ impl CommitUploadSessionBuilder {
 
     /// Finalize the changes to the request struct and return the new struct.
     pub fn build(self) -> Result<CommitUploadSession> {
-        if let Err(error) = self.validate() {
-            return Err(Error::RequestBuilderError(error));
-        }
+        self.build_with_clock(SystemClock)
+    }
 
-        let session_id = self.session_id.unwrap();
-        let translated_language = self.translated_language.unwrap();
+    /// Finalize the changes to the request struct, checking `publish_at` against the time of
+    /// `clock` instead of the system time.
+    pub fn build_with_clock(self, clock: impl Clock) -> Result<CommitUploadSession> {
+        let session_id = self
+            .session_id
+            .ok_or_else(|| UninitializedFieldError::new("session_id"))?;
+        let translated_language = self
+            .translated_language
+            .ok_or_else(|| UninitializedFieldError::new("translated_language"))?;
+        self.validate()?;
+        self.validate_publish_at(clock)?;
 
         Ok(CommitUploadSession {
             http_client: self.http_client,
@@ -230,14 +282,34 @@ impl CommitUploadSessionBuilder {
                 publish_at: self.publish_at,
             },
             page_order: self.page_order,
+            retry_on_conflict: self.retry_on_conflict,
         })
     }
 }
 
+impl CommitUploadSession {
+    /// Send the request.
+    pub async fn send(&self) -> Result<ChapterObject> {
+        #[cfg(not(feature = "multi-thread"))]
+        let request = self.http_client.try_borrow()?.prepare_request(self)?;
+        #[cfg(feature = "multi-thread")]
+        let request = self.http_client.lock().await.prepare_request(self)?;
+
+        // The body is JSON, so the request can always be cloned.
+        let retry = request.try_clone().filter(|_| self.retry_on_conflict);
+        let mut res = request.send().await?;
+        if let (Some(retry), StatusCode::CONFLICT) = (retry, res.status()) {
+            res = retry.send().await?;
+        }
+
+        HttpClient::parse_response(res).await
+    }
+}
+
 endpoint! {
     PUT ("/upload/{}/commit", session_id),
     #[body auth] CommitUploadSession,
-    ChapterObject
+    #[no_send] ChapterObject
 }
 
 #[cfg(test)]
@@ -245,15 +317,16 @@ mod tests {
     use fake::faker::name::en::Name;
     use fake::Fake;
     use serde_json::json;
-    use time::OffsetDateTime;
+    use time::{Duration, OffsetDateTime};
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{header, method, path_regex};
+    use wiremock::matchers::{body_json, header, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::v5::AuthTokens;
     use crate::{HttpClient, MangaDexClient};
-    use mangadex_api_types::{Language, MangaDexDateTime, RelationshipType};
+    use mangadex_api_types::error::Error;
+    use mangadex_api_types::{FixedClock, Language, MangaDexDateTime, RelationshipType};
 
     #[tokio::test]
     async fn commit_upload_session_fires_a_request_to_base_url() -> anyhow::Result<()> {
@@ -349,4 +422,213 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn commit_upload_session_retries_once_on_conflict() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let session_id = Uuid::new_v4();
+        let chapter_id = Uuid::new_v4();
+
+        let datetime = MangaDexDateTime::new(&OffsetDateTime::now_utc());
+
+        let conflict_body = json!({
+            "result": "error",
+            "errors": [{
+                "id": Uuid::new_v4().to_string(),
+                "status": 409,
+                "title": "Conflict",
+                "detail": "The version is out of date."
+            }]
+        });
+        let response_body = json!({
+            "id": chapter_id,
+            "type": "chapter",
+            "attributes": {
+                "title": "Chapter title",
+                "volume": "1",
+                "chapter": "1",
+                "pages": 4,
+                "translatedLanguage": "en",
+                "version": 2,
+                "createdAt": datetime.to_string(),
+                "updatedAt": datetime.to_string(),
+                "publishAt": datetime.to_string(),
+                "readableAt": datetime.to_string(),
+            },
+            "relationships": [],
+        });
+
+        Mock::given(method("PUT"))
+            .and(path_regex(r"/upload/[0-9a-fA-F-]+/commit"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(conflict_body))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path_regex(r"/upload/[0-9a-fA-F-]+/commit"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .upload()
+            .commit_session()
+            .session_id(session_id)
+            .translated_language(Language::English)
+            .add_page(Uuid::new_v4())
+            .retry_on_conflict(true)
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.id, chapter_id);
+        assert_eq!(res.attributes.version, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn commit_upload_session_accepts_an_external_url_without_pages() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let chapter_id = Uuid::new_v4();
+        let external_url = Url::parse("https://example.org/chapter/1")?;
+        let datetime = MangaDexDateTime::new(&OffsetDateTime::now_utc());
+
+        let expected_body = json!({
+            "chapterDraft": {
+                "volume": null,
+                "chapter": "1",
+                "title": null,
+                "translatedLanguage": "en",
+                "externalUrl": external_url
+            },
+            "pageOrder": []
+        });
+        let response_body = json!({
+            "id": chapter_id,
+            "type": "chapter",
+            "attributes": {
+                "title": null,
+                "volume": null,
+                "chapter": "1",
+                "pages": 0,
+                "translatedLanguage": "en",
+                "externalUrl": external_url,
+                "version": 1,
+                "createdAt": datetime.to_string(),
+                "updatedAt": datetime.to_string(),
+                "publishAt": datetime.to_string(),
+                "readableAt": datetime.to_string(),
+            },
+            "relationships": [],
+        });
+
+        Mock::given(method("PUT"))
+            .and(path_regex(r"/upload/[0-9a-fA-F-]+/commit"))
+            .and(body_json(expected_body))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .upload()
+            .commit_session()
+            .session_id(Uuid::new_v4())
+            .chapter(Some("1".to_string()))
+            .translated_language(Language::English)
+            .external_url(Some(external_url.clone()))
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.attributes.external_url, Some(external_url));
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_upload_session_requires_pages_or_an_external_url() {
+        let mangadex_client = MangaDexClient::default();
+
+        let res = mangadex_client
+            .upload()
+            .commit_session()
+            .session_id(Uuid::new_v4())
+            .translated_language(Language::English)
+            .build();
+
+        assert!(matches!(
+            res,
+            Err(Error::BuilderError { field, .. }) if field == "page_order"
+        ));
+    }
+
+    fn scheduled_commit(publish_at: OffsetDateTime) -> super::CommitUploadSessionBuilder {
+        MangaDexClient::default()
+            .upload()
+            .commit_session()
+            .session_id(Uuid::new_v4())
+            .translated_language(Language::English)
+            .add_page(Uuid::new_v4())
+            .publish_at(publish_at)
+    }
+
+    #[test]
+    fn commit_upload_session_rejects_a_publish_at_in_the_past() {
+        let now = OffsetDateTime::now_utc();
+
+        let res = scheduled_commit(now - Duration::hours(1))
+            .build_with_clock(FixedClock::new(now.into()));
+
+        assert!(matches!(res, Err(Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn commit_upload_session_rejects_a_publish_at_beyond_two_weeks() {
+        let now = OffsetDateTime::now_utc();
+
+        let res = scheduled_commit(now + Duration::weeks(2) + Duration::minutes(1))
+            .build_with_clock(FixedClock::new(now.into()));
+
+        assert!(matches!(res, Err(Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn commit_upload_session_accepts_a_publish_at_in_the_allowed_window() -> anyhow::Result<()> {
+        let now = OffsetDateTime::now_utc();
+        let publish_at = now + Duration::days(3);
+
+        let commit = scheduled_commit(publish_at).build_with_clock(FixedClock::new(now.into()))?;
+
+        assert_eq!(
+            commit
+                .chapter_draft
+                .publish_at
+                .map(|publish_at| publish_at.to_string()),
+            Some(MangaDexDateTime::new(&publish_at).to_string())
+        );
+
+        Ok(())
+    }
 }
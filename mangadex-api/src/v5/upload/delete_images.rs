@@ -51,7 +51,11 @@ use crate::HttpClientRef;
 #[cfg_attr(feature = "deserializable-endpoint", derive(serde::Deserialize, getset::Getters, getset::Setters))]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct DeleteImages{
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -92,13 +96,12 @@ impl Endpoint for DeleteImages {
 impl DeleteImages {
     pub async fn send(&self) -> Result<NoData> {
         #[cfg(not(feature = "multi-thread"))]
-        {
-            self.http_client.try_borrow()?.send_request(self).await?
-        }
+        let request = self.http_client.try_borrow()?.prepare_request(self)?;
         #[cfg(feature = "multi-thread")]
-        {
-            self.http_client.lock().await.send_request(self).await?
-        }
+        let request = self.http_client.lock().await.prepare_request(self)?;
+        let (res, _) = request.send_with_headers::<Result<NoData>>().await?;
+
+        res
     }
 }
 
@@ -0,0 +1,339 @@
+//! Guard over an upload session started with [`StartUploadSession::begin_session()`].
+//!
+//! MangaDex only allows one upload session per user at a time, so a session that is neither
+//! committed nor abandoned blocks the next upload until it expires. [`UploadSessionGuard`] keeps
+//! the session ID around and is consumed by either [`commit()`](UploadSessionGuard::commit) or
+//! [`abandon()`](UploadSessionGuard::abandon).
+//!
+//! Rust has no async `Drop`, so dropping the guard does **not** clean up the session on its own.
+//! Call one of the two methods explicitly, or opt into
+//! [`spawn_abandon_on_drop()`](UploadSessionGuard::spawn_abandon_on_drop) to hand the abandon
+//! request to your async runtime when the guard is dropped.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use uuid::Uuid;
+//!
+//! use mangadex_api::MangaDexClient;
+//! use mangadex_api_types::Language;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let client = MangaDexClient::default();
+//!
+//! let session = client
+//!     .upload()
+//!     .start_session()
+//!     .add_group_id(Uuid::new_v4())
+//!     .manga_id(Uuid::new_v4())
+//!     .build()?
+//!     .begin_session()
+//!     .await?;
+//!
+//! let page_id = Uuid::new_v4();
+//! if page_id.is_nil() {
+//!     session.abandon().await?;
+//! } else {
+//!     let chapter = session
+//!         .commit()
+//!         .translated_language(Language::English)
+//!         .add_page(page_id)
+//!         .build()?
+//!         .send()
+//!         .await?;
+//!
+//!     println!("committed: {:?}", chapter);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+
+use mangadex_api_schema::v5::UploadSessionResponse;
+use uuid::Uuid;
+
+use crate::v5::upload::abandon_session::AbandonUploadSession;
+use crate::v5::upload::commit_session::CommitUploadSessionBuilder;
+use crate::v5::upload::start_session::StartUploadSession;
+use crate::HttpClientRef;
+use mangadex_api_types::error::Result;
+
+/// Request abandoning an upload session, handed to the spawner of
+/// [`UploadSessionGuard::spawn_abandon_on_drop()`].
+#[cfg(not(feature = "multi-thread"))]
+pub type AbandonSessionFuture = Pin<Box<dyn Future<Output = Result<()>>>>;
+/// Request abandoning an upload session, handed to the spawner of
+/// [`UploadSessionGuard::spawn_abandon_on_drop()`].
+#[cfg(feature = "multi-thread")]
+pub type AbandonSessionFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+
+/// Function running an [`AbandonSessionFuture`] in the background, such as `tokio::spawn`.
+#[cfg(not(feature = "multi-thread"))]
+pub type AbandonSessionSpawner = Box<dyn FnOnce(AbandonSessionFuture)>;
+/// Function running an [`AbandonSessionFuture`] in the background, such as `tokio::spawn`.
+#[cfg(feature = "multi-thread")]
+pub type AbandonSessionSpawner = Box<dyn FnOnce(AbandonSessionFuture) + Send>;
+
+/// An upload session that still has to be committed or abandoned.
+///
+/// Dropping the guard leaves the session open on MangaDex unless
+/// [`spawn_abandon_on_drop()`](Self::spawn_abandon_on_drop) was used.
+#[must_use = "the upload session stays open until it is committed or abandoned"]
+pub struct UploadSessionGuard {
+    http_client: HttpClientRef,
+    session: UploadSessionResponse,
+    abandon_on_drop: Option<AbandonSessionSpawner>,
+}
+
+impl std::fmt::Debug for UploadSessionGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadSessionGuard")
+            .field("session", &self.session)
+            .field("abandon_on_drop", &self.abandon_on_drop.is_some())
+            .finish()
+    }
+}
+
+impl UploadSessionGuard {
+    /// Get the upload session ID.
+    pub fn session_id(&self) -> Uuid {
+        self.session.id
+    }
+
+    /// Get the upload session returned when it was started.
+    pub fn session(&self) -> &UploadSessionResponse {
+        &self.session
+    }
+
+    /// Abandon the upload session when the guard is dropped without being committed or abandoned.
+    ///
+    /// `spawn` is given the abandon request and has to run it in the background, e.g.
+    /// `Box::new(|fut| { tokio::spawn(fut); })` or, without the `multi-thread` feature,
+    /// `Box::new(|fut| { tokio::task::spawn_local(fut); })`. Errors from the request are lost.
+    pub fn spawn_abandon_on_drop(mut self, spawn: AbandonSessionSpawner) -> Self {
+        self.abandon_on_drop = Some(spawn);
+        self
+    }
+
+    /// Get a builder committing the upload session, with the session ID already set.
+    pub fn commit(mut self) -> CommitUploadSessionBuilder {
+        self.abandon_on_drop = None;
+
+        CommitUploadSessionBuilder::new(self.http_client.clone()).session_id(self.session.id)
+    }
+
+    /// Abandon the upload session.
+    ///
+    /// <https://api.mangadex.org/swagger.html#/Upload/abandon-upload-session>
+    pub async fn abandon(mut self) -> Result<()> {
+        self.abandon_on_drop = None;
+
+        abandon_session(self.http_client.clone(), self.session.id).await
+    }
+}
+
+impl Drop for UploadSessionGuard {
+    fn drop(&mut self) {
+        if let Some(spawn) = self.abandon_on_drop.take() {
+            spawn(abandon_session(self.http_client.clone(), self.session.id));
+        }
+    }
+}
+
+fn abandon_session(http_client: HttpClientRef, session_id: Uuid) -> AbandonSessionFuture {
+    Box::pin(async move {
+        AbandonUploadSession {
+            http_client,
+            session_id,
+        }
+        .send()
+        .await
+    })
+}
+
+impl StartUploadSession {
+    /// Start the upload session and get a guard to commit or abandon it with.
+    pub async fn begin_session(&self) -> Result<UploadSessionGuard> {
+        let session = self.send().await?;
+
+        Ok(UploadSessionGuard {
+            http_client: self.http_client.clone(),
+            session,
+            abandon_on_drop: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::{Language, MangaDexDateTime};
+
+    async fn setup(session_id: Uuid) -> anyhow::Result<(MockServer, MangaDexClient)> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+
+        let datetime = MangaDexDateTime::new(&OffsetDateTime::now_utc());
+        let response_body = json!({
+            "id": session_id,
+            "type": "upload_session",
+            "attributes": {
+                "isCommitted": false,
+                "isProcessed": false,
+                "isDeleted": false,
+                "version": 1,
+                "createdAt": datetime.to_string(),
+                "updatedAt": datetime.to_string(),
+            },
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/upload/begin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Ok((
+            mock_server,
+            MangaDexClient::new_with_http_client(http_client),
+        ))
+    }
+
+    fn abandon_mock(session_id: Uuid, expected: u64) -> Mock {
+        Mock::given(method("DELETE"))
+            .and(path(format!("/upload/{}", session_id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": "ok"})))
+            .expect(expected)
+    }
+
+    #[tokio::test]
+    async fn upload_session_guard_abandons_the_session() -> anyhow::Result<()> {
+        let session_id = Uuid::new_v4();
+        let (mock_server, mangadex_client) = setup(session_id).await?;
+        abandon_mock(session_id, 1).mount(&mock_server).await;
+
+        let guard = mangadex_client
+            .upload()
+            .start_session()
+            .add_group_id(Uuid::new_v4())
+            .manga_id(Uuid::new_v4())
+            .build()?
+            .begin_session()
+            .await?;
+
+        assert_eq!(guard.session_id(), session_id);
+        guard.abandon().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_session_guard_commits_the_session() -> anyhow::Result<()> {
+        let session_id = Uuid::new_v4();
+        let (mock_server, mangadex_client) = setup(session_id).await?;
+        abandon_mock(session_id, 0).mount(&mock_server).await;
+
+        let chapter_id = Uuid::new_v4();
+        let datetime = MangaDexDateTime::new(&OffsetDateTime::now_utc());
+        let response_body = json!({
+            "id": chapter_id,
+            "type": "chapter",
+            "attributes": {
+                "title": "Chapter title",
+                "volume": null,
+                "chapter": "1",
+                "pages": 1,
+                "translatedLanguage": "en",
+                "uploader": Uuid::new_v4(),
+                "version": 1,
+                "createdAt": datetime.to_string(),
+                "updatedAt": datetime.to_string(),
+                "publishAt": datetime.to_string(),
+                "readableAt": datetime.to_string(),
+            },
+            "relationships": [],
+        });
+        Mock::given(method("PUT"))
+            .and(path_regex(r"^/upload/[0-9a-fA-F-]+/commit$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let guard = mangadex_client
+            .upload()
+            .start_session()
+            .add_group_id(Uuid::new_v4())
+            .manga_id(Uuid::new_v4())
+            .build()?
+            .begin_session()
+            .await?
+            .spawn_abandon_on_drop(Box::new(|_| {
+                panic!("a committed session must not be abandoned")
+            }));
+
+        let res = guard
+            .commit()
+            .translated_language(Language::English)
+            .add_page(Uuid::new_v4())
+            .build()?
+            .send()
+            .await?;
+
+        assert_eq!(res.id, chapter_id);
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "multi-thread"))]
+    #[tokio::test]
+    async fn upload_session_guard_spawns_the_abandon_request_on_drop() -> anyhow::Result<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use super::AbandonSessionFuture;
+
+        let session_id = Uuid::new_v4();
+        let (mock_server, mangadex_client) = setup(session_id).await?;
+        abandon_mock(session_id, 1).mount(&mock_server).await;
+
+        let spawned: Rc<RefCell<Vec<AbandonSessionFuture>>> = Rc::default();
+        let spawner = spawned.clone();
+
+        let guard = mangadex_client
+            .upload()
+            .start_session()
+            .add_group_id(Uuid::new_v4())
+            .manga_id(Uuid::new_v4())
+            .build()?
+            .begin_session()
+            .await?
+            .spawn_abandon_on_drop(Box::new(move |fut| spawner.borrow_mut().push(fut)));
+        drop(guard);
+
+        let fut = spawned
+            .borrow_mut()
+            .pop()
+            .expect("the abandon request was not spawned");
+        fut.await?;
+
+        Ok(())
+    }
+}
@@ -4,6 +4,9 @@
 //!
 //! Currently, there is a maximum of 10 files per request.
 //!
+//! With the `utils` feature, [`UploadImageStream`] can be used to upload the files of a stream,
+//! split into as many requests as needed to respect that limit.
+//!
 //! ```rust
 //! use uuid::Uuid;
 //!
@@ -43,6 +46,8 @@
 use std::borrow::Cow;
 
 use derive_builder::Builder;
+#[cfg(feature = "utils")]
+use mangadex_api_schema::v5::UploadSessionFileDataObject;
 use mangadex_api_schema::v5::UploadSessionFileResponse;
 use mangadex_api_schema::Endpoint;
 use reqwest::multipart::{Form, Part};
@@ -51,6 +56,10 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 
+/// Maximum number of files that can be uploaded in a single request.
+#[cfg(feature = "utils")]
+pub const MAX_FILES_PER_REQUEST: usize = 10;
+
 /// Upload images to the upload session.
 ///
 /// This requires authentication.
@@ -62,7 +71,14 @@ use crate::HttpClientRef;
 )]
 #[derive(Debug, Builder, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(
+        validate = "Self::validate",
+        error = "mangadex_api_types::error::Error"
+    )
+)]
 pub struct UploadImages {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -79,6 +95,19 @@ pub struct UploadImages {
     pub files: Vec<Cow<'static, [u8]>>,
 }
 
+impl UploadImagesBuilder {
+    fn validate(&self) -> mangadex_api_types::error::Result<()> {
+        if self.files.as_ref().is_some_and(Vec::is_empty) {
+            return Err(mangadex_api_types::error::Error::BuilderError {
+                field: "files".to_string(),
+                message: "must contain at least one file".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 // TODO: Come up with a way to generalize multipart form data for the `Endpoint` trait.
 impl Endpoint for UploadImages {
     type Query = ();
@@ -112,14 +141,128 @@ impl Endpoint for UploadImages {
 impl UploadImages {
     pub async fn send(&self) -> UploadSessionFileResponse {
         #[cfg(not(feature = "multi-thread"))]
-        let res = self.http_client.try_borrow()?.send_request(self).await?;
+        let request = self.http_client.try_borrow()?.prepare_request(self)?;
         #[cfg(feature = "multi-thread")]
-        let res = self.http_client.lock().await.send_request(self).await?;
+        let request = self.http_client.lock().await.prepare_request(self)?;
+        let (res, _) = request.send_with_headers().await?;
 
         res
     }
 }
 
+/// Upload the files of a stream to the upload session, split into requests of at most
+/// [`MAX_FILES_PER_REQUEST`] files.
+///
+/// This requires authentication.
+///
+/// Makes requests to `POST /upload/{id}`.
+#[cfg(feature = "utils")]
+#[derive(Debug, Builder, Clone)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
+pub struct UploadImageStream {
+    /// This should never be set manually as this is only for internal use.
+    #[doc(hidden)]
+    #[builder(pattern = "immutable")]
+    pub(crate) http_client: HttpClientRef,
+
+    pub session_id: Uuid,
+}
+
+#[cfg(feature = "utils")]
+impl UploadImageStream {
+    /// Upload the files yielded by `files`.
+    ///
+    /// The stream is consumed one request at a time so only a single batch of files is held in
+    /// memory.
+    ///
+    /// Each item is a `(file name, image bytes)` pair.
+    /// The responses of every request are returned in order.
+    pub async fn send<S>(
+        &self,
+        files: S,
+    ) -> mangadex_api_types::error::Result<Vec<UploadSessionFileDataObject>>
+    where
+        S: tokio_stream::Stream<Item = (String, bytes::Bytes)>,
+    {
+        use tokio_stream::StreamExt;
+
+        tokio::pin!(files);
+
+        let mut responses = Vec::new();
+        loop {
+            let mut batch = Vec::with_capacity(MAX_FILES_PER_REQUEST);
+            while batch.len() < MAX_FILES_PER_REQUEST {
+                match files.next().await {
+                    Some(file) => batch.push(file),
+                    None => break,
+                }
+            }
+            if batch.is_empty() {
+                break;
+            }
+
+            let request = UploadImageBatch {
+                session_id: self.session_id,
+                files: batch,
+            };
+
+            #[cfg(not(feature = "multi-thread"))]
+            let request = self.http_client.try_borrow()?.prepare_request(&request)?;
+            #[cfg(feature = "multi-thread")]
+            let request = self.http_client.lock().await.prepare_request(&request)?;
+            let (res, _) = request
+                .send_with_headers::<UploadSessionFileResponse>()
+                .await?;
+            let res = res?;
+
+            responses.push(res);
+        }
+
+        Ok(responses)
+    }
+}
+
+/// A single batch of named files sent by [`UploadImageStream::send()`].
+#[cfg(feature = "utils")]
+struct UploadImageBatch {
+    session_id: Uuid,
+    files: Vec<(String, bytes::Bytes)>,
+}
+
+#[cfg(feature = "utils")]
+impl Endpoint for UploadImageBatch {
+    type Query = ();
+    type Body = ();
+    type Response = UploadSessionFileResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("/upload/{}", self.session_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+
+    fn multipart(&self) -> Option<Form> {
+        let mut form = Form::new();
+
+        for (file_name, bytes) in &self.files {
+            let part = Part::stream(bytes.clone()).file_name(file_name.clone());
+            form = form.part("file", part);
+        }
+
+        Some(form)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::filesystem::en::MimeType;
@@ -130,6 +273,8 @@ mod tests {
     use wiremock::matchers::{header, header_exists, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    use mangadex_api_types::error::Error;
+
     use crate::v5::AuthTokens;
     use crate::{HttpClient, MangaDexClient};
 
@@ -189,4 +334,82 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn upload_images_requires_at_least_one_file() {
+        let mangadex_client = MangaDexClient::default();
+
+        let missing = mangadex_client
+            .upload()
+            .upload_images()
+            .session_id(Uuid::new_v4())
+            .build();
+        let empty = mangadex_client
+            .upload()
+            .upload_images()
+            .session_id(Uuid::new_v4())
+            .files(Vec::new())
+            .build();
+
+        assert!(matches!(missing, Err(Error::BuilderError { field, .. }) if field == "files"));
+        assert!(matches!(empty, Err(Error::BuilderError { field, .. }) if field == "files"));
+    }
+
+    #[cfg(feature = "utils")]
+    #[tokio::test]
+    async fn upload_image_stream_splits_the_files_into_batches_of_ten() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client: HttpClient = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let session_id = Uuid::new_v4();
+        let response_body = json!({
+            "result": "ok",
+            "errors": [],
+            "data": [],
+        });
+
+        Mock::given(method("POST"))
+            .and(path_regex("/upload/[0-9a-fA-F-]+"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(response_body))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let files = tokio_stream::iter(
+            (1..=25).map(|page| (format!("p{page:02}.jpg"), bytes::Bytes::from(vec![page]))),
+        );
+
+        let res = mangadex_client
+            .upload()
+            .upload_image_stream()
+            .session_id(session_id)
+            .build()?
+            .send(files)
+            .await?;
+
+        assert_eq!(res.len(), 3);
+
+        let batch_sizes: Vec<_> = mock_server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .map(|request| {
+                String::from_utf8_lossy(&request.body)
+                    .matches("name=\"file\"")
+                    .count()
+            })
+            .collect();
+        assert_eq!(batch_sizes, [10, 10, 5]);
+
+        Ok(())
+    }
 }
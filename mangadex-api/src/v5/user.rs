@@ -24,6 +24,8 @@ mod followed_users;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod get;
 #[cfg(not(feature = "deserializable-endpoint"))]
+mod history;
+#[cfg(not(feature = "deserializable-endpoint"))]
 mod is_following_custom_list;
 #[cfg(not(feature = "deserializable-endpoint"))]
 mod is_following_group;
@@ -66,6 +68,8 @@ pub mod followed_users;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod get;
 #[cfg(feature = "deserializable-endpoint")]
+pub mod history;
+#[cfg(feature = "deserializable-endpoint")]
 pub mod is_following_custom_list;
 #[cfg(feature = "deserializable-endpoint")]
 pub mod is_following_group;
@@ -97,6 +101,7 @@ use crate::v5::user::followed_manga::FollowedMangaBuilder;
 use crate::v5::user::followed_manga_feed::GetFollowedMangaFeedBuilder;
 use crate::v5::user::followed_users::FollowedUsersBuilder;
 use crate::v5::user::get::GetUserBuilder;
+use crate::v5::user::history::GetReadingHistoryBuilder;
 use crate::v5::user::is_following_custom_list::IsFollowingCustomListBuilder;
 use crate::v5::user::is_following_group::IsFollowingGroupBuilder;
 use crate::v5::user::is_following_manga::IsFollowingMangaBuilder;
@@ -211,6 +216,13 @@ impl UserBuilder {
         GetMyUserDetailsBuilder::default().http_client(self.http_client.clone())
     }
 
+    /// Get the chapters the logged-in user read recently.
+    ///
+    /// <https://api.mangadex.org/swagger.html#/User/get-reading-history>
+    pub fn history(&self) -> GetReadingHistoryBuilder {
+        GetReadingHistoryBuilder::default().http_client(self.http_client.clone())
+    }
+
     /// Check if the logged-in user follows a given group.
     ///
     /// <https://api.mangadex.org/swagger.html#/Follows/get-user-follows-group-id>
@@ -33,6 +33,7 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::CustomListListResponse;
+use mangadex_api_types::ReferenceExpansionResource;
 
 #[cfg_attr(
     feature = "deserializable-endpoint",
@@ -40,7 +41,11 @@ use mangadex_api_schema::v5::CustomListListResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct UserCustomLists {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -58,6 +63,10 @@ pub struct UserCustomLists {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     offset: Option<u32>,
+    /// Expand the relationships of the lists, such as [`ReferenceExpansionResource::User`] for
+    /// their creator.
+    #[builder(setter(each = "include"), default)]
+    pub includes: Vec<ReferenceExpansionResource>,
 }
 
 endpoint! {
@@ -66,6 +75,10 @@ endpoint! {
     #[flatten_result] CustomListListResponse
 }
 
+paginated! {
+    UserCustomLists => mangadex_api_schema::v5::CustomListObject
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::name::en::Name;
@@ -47,7 +47,12 @@ use mangadex_api_schema::v5::CustomListListResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned", default)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    default,
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetFollowedCustomLists {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -74,6 +79,10 @@ endpoint! {
     #[flatten_result] CustomListListResponse
 }
 
+paginated! {
+    GetFollowedCustomLists => mangadex_api_schema::v5::CustomListObject
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::name::en::Name;
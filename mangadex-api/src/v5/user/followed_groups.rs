@@ -48,7 +48,12 @@ use mangadex_api_types::ReferenceExpansionResource;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned", default)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    default,
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct FollowedGroups {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -71,6 +76,10 @@ endpoint! {
     #[flatten_result] GroupListResponse
 }
 
+paginated! {
+    FollowedGroups => mangadex_api_schema::v5::GroupObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -39,6 +39,8 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::ChapterListResponse;
+use mangadex_api_types::error::Result;
+use mangadex_api_types::mangadex_datetime::serialize_query_datetime;
 use mangadex_api_types::{
     ContentRating, IncludeFutureUpdates, Language, MangaDexDateTime, MangaFeedSortOrder,
     ReferenceExpansionResource, IncludeFuturePages, IncludeFuturePublishAt, IncludeExternalUrl,
@@ -50,7 +52,12 @@ use mangadex_api_types::{
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned", default)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    default,
+    build_fn(validate = "Self::validate", error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct GetFollowedMangaFeed {
     /// This should never be set manually as this is only for internal use.
@@ -86,13 +93,22 @@ pub struct GetFollowedMangaFeed {
     /// Default: `IncludeFutureUpdates::Include` (1)
     pub include_future_updates: Option<IncludeFutureUpdates>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_query_datetime"
+    )]
     pub created_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_query_datetime"
+    )]
     pub updated_at_since: Option<MangaDexDateTime>,
     /// DateTime string with following format: `YYYY-MM-DDTHH:MM:SS`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_query_datetime"
+    )]
     pub publish_at_since: Option<MangaDexDateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<MangaFeedSortOrder>,
@@ -106,12 +122,22 @@ pub struct GetFollowedMangaFeed {
     pub include_external_url : Option<IncludeExternalUrl>,
 }
 
+impl GetFollowedMangaFeedBuilder {
+    fn validate(&self) -> Result<()> {
+        Language::validate_known("translated_language", self.translated_language.as_deref().unwrap_or_default())
+    }
+}
+
 endpoint! {
     GET "/user/follows/manga/feed",
-    #[query auth] GetFollowedMangaFeed,
+    #[query auth content_rating] GetFollowedMangaFeed,
     #[flatten_result] ChapterListResponse
 }
 
+paginated! {
+    GetFollowedMangaFeed => mangadex_api_schema::v5::ChapterObject
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::name::en::Name;
@@ -120,12 +146,13 @@ mod tests {
     use time::OffsetDateTime;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{header, method, path_regex};
+    use wiremock::matchers::{header, method, path_regex, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::v5::AuthTokens;
     use crate::{HttpClient, MangaDexClient};
-    use mangadex_api_types::MangaDexDateTime;
+    use mangadex_api_schema::v5::RelatedAttributes;
+    use mangadex_api_types::{IncludeFutureUpdates, MangaDexDateTime, ReferenceExpansionResource};
 
     #[tokio::test]
     async fn get_followed_manga_feed_fires_a_request_to_base_url() -> anyhow::Result<()> {
@@ -191,4 +218,137 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn get_followed_manga_feed_expands_the_manga_of_each_chapter() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client: HttpClient = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let chapter_id = Uuid::new_v4();
+        let manga_id = Uuid::new_v4();
+        let group_id = Uuid::new_v4();
+        let datetime = MangaDexDateTime::new(&OffsetDateTime::now_utc());
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [
+                {
+                    "id": chapter_id,
+                    "type": "chapter",
+                    "attributes": {
+                        "title": "",
+                        "volume": "1",
+                        "chapter": "1",
+                        "pages": 4,
+                        "translatedLanguage": "en",
+                        "uploader": Uuid::new_v4(),
+                        "version": 1,
+                        "createdAt": datetime.to_string(),
+                        "updatedAt": datetime.to_string(),
+                        "publishAt": datetime.to_string(),
+                        "readableAt": datetime.to_string(),
+                    },
+                    "relationships": [
+                        {
+                            "id": manga_id,
+                            "type": "manga",
+                            "attributes": {
+                                "title": {
+                                    "en": "Test Manga"
+                                },
+                                "altTitles": [],
+                                "description": [],
+                                "isLocked": false,
+                                "links": null,
+                                "originalLanguage": "ja",
+                                "lastVolume": null,
+                                "lastChapter": null,
+                                "publicationDemographic": null,
+                                "status": "ongoing",
+                                "year": null,
+                                "contentRating": "safe",
+                                "chapterNumbersResetOnNewVolume": false,
+                                "availableTranslatedLanguages": [],
+                                "tags": [],
+                                "state": "published",
+                                "createdAt": datetime.to_string(),
+                                "updatedAt": datetime.to_string(),
+                                "version": 1
+                            }
+                        },
+                        {
+                            "id": group_id,
+                            "type": "scanlation_group"
+                        }
+                    ],
+                },
+            ],
+            "limit": 1,
+            "offset": 0,
+            "total": 1
+        });
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/user/follows/manga/feed"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .and(query_param("includes[0]", "manga"))
+            .and(query_param("includes[1]", "scanlation_group"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client
+            .user()
+            .followed_manga_feed()
+            .include(ReferenceExpansionResource::Manga)
+            .include(ReferenceExpansionResource::ScanlationGroup)
+            .build()?
+            .send()
+            .await?;
+
+        let manga = res.data[0]
+            .relationships
+            .manga()
+            .expect("the chapter has a manga relationship");
+        assert_eq!(manga.id, manga_id);
+        let Some(RelatedAttributes::Manga(attributes)) = &manga.attributes else {
+            panic!("expected expanded manga attributes, got {:?}", manga.attributes);
+        };
+        assert_eq!(attributes.title.get_or_fallback(&[]), Some("Test Manga"));
+        assert_eq!(
+            res.data[0].relationships.scanlation_group().map(|group| group.id),
+            Some(group_id)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_followed_manga_feed_encodes_the_include_flags_as_numbers() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let manga_feed = mangadex_client
+            .user()
+            .followed_manga_feed()
+            .include_future_updates(IncludeFutureUpdates::Include)
+            .include_empty_pages(true)
+            .build()?;
+
+        assert_query_eq!(
+            manga_feed,
+            [("includeFutureUpdates", "1"), ("includeEmptyPages", "1")]
+        );
+
+        Ok(())
+    }
 }
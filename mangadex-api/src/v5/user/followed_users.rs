@@ -47,7 +47,12 @@ use mangadex_api_schema::v5::UserListResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned", default)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    default,
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct FollowedUsers {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -68,6 +73,10 @@ endpoint! {
     #[flatten_result] UserListResponse
 }
 
+paginated! {
+    FollowedUsers => mangadex_api_schema::v5::UserObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
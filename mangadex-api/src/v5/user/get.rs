@@ -49,7 +49,11 @@ use mangadex_api_schema::v5::UserResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct GetUser {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -65,7 +69,8 @@ pub struct GetUser {
 endpoint! {
     GET ("/user/{}", user_id),
     #[query] GetUser,
-    #[flatten_result] UserResponse
+    #[flatten_result] UserResponse,
+    #[not_found("user", user_id)]
 }
 
 #[cfg(test)]
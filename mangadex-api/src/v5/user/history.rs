@@ -0,0 +1,157 @@
+//! Builder for the logged-in user's reading history endpoint.
+//!
+//! <https://api.mangadex.org/swagger.html#/User/get-reading-history>
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mangadex_api_schema::v5::AuthTokens;
+//! use mangadex_api::{HttpClient, MangaDexClient};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let http_client = HttpClient::builder()
+//!     .auth_tokens(AuthTokens {
+//!         session: "sessiontoken".to_string(),
+//!         refresh: "refreshtoken".to_string(),
+//!     })
+//!     .build()?;
+//! let client = MangaDexClient::new_with_http_client(http_client);
+//!
+//! let res = client
+//!     .user()
+//!     .history()
+//!     .build()?
+//!     .send()
+//!     .await?;
+//!
+//! for entry in res.data {
+//!     println!("read {} at {}", entry.chapter_id, entry.read_date);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::HttpClientRef;
+use mangadex_api_schema::v5::ReadingHistoryResponse;
+
+/// Get the chapters the logged-in user read recently.
+///
+/// This requires authentication.
+///
+/// Makes a request to `GET /user/history`.
+#[cfg_attr(
+    feature = "deserializable-endpoint",
+    derive(serde::Deserialize, getset::Getters, getset::Setters)
+)]
+#[derive(Debug, Serialize, Clone, Builder)]
+#[serde(rename_all = "camelCase")]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
+pub struct GetReadingHistory {
+    /// This should never be set manually as this is only for internal use.
+    #[doc(hidden)]
+    #[serde(skip)]
+    #[builder(pattern = "immutable")]
+    #[cfg_attr(feature = "deserializable-endpoint", getset(set = "pub", get = "pub"))]
+    pub(crate) http_client: HttpClientRef,
+}
+
+endpoint! {
+    GET "/user/history",
+    #[query auth] GetReadingHistory,
+    #[flatten_result] ReadingHistoryResponse
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use url::Url;
+    use uuid::Uuid;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::v5::AuthTokens;
+    use crate::{HttpClient, MangaDexClient};
+    use mangadex_api_types::error::Error;
+
+    #[tokio::test]
+    async fn get_reading_history_fires_a_request_to_base_url() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let first_chapter_id = Uuid::new_v4();
+        let second_chapter_id = Uuid::new_v4();
+        let response_body = json!({
+            "result": "ok",
+            "data": [
+                {
+                    "chapterId": first_chapter_id,
+                    "readDate": "2023-08-02T13:45:12+00:00"
+                },
+                {
+                    "chapterId": second_chapter_id,
+                    "readDate": "2023-08-01T09:00:00+00:00"
+                }
+            ]
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/user/history"))
+            .and(header("Authorization", "Bearer sessiontoken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client.user().history().build()?.send().await?;
+
+        assert_eq!(res.data.len(), 2);
+        assert_eq!(res.data[0].chapter_id, first_chapter_id);
+        assert_eq!(
+            res.data[0].read_date.to_string(),
+            "2023-08-02T13:45:12+00:00"
+        );
+        assert_eq!(res.data[1].chapter_id, second_chapter_id);
+        assert_eq!(
+            res.data[1].read_date.to_string(),
+            "2023-08-01T09:00:00+00:00"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_reading_history_requires_auth() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        Mock::given(method("GET"))
+            .and(path("/user/history"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let res = mangadex_client.user().history().build()?.send().await;
+
+        assert!(matches!(res, Err(Error::MissingTokens)));
+
+        Ok(())
+    }
+}
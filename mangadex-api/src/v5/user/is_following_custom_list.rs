@@ -42,7 +42,7 @@ use mangadex_api_schema::{FromResponse, NoData};
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::HttpClientRef;
+use crate::{HttpClient, HttpClientRef};
 use mangadex_api_types::error::{Error, Result};
 
 /// Check if the logged-in user follows a custom list.
@@ -54,7 +54,10 @@ use mangadex_api_types::error::{Error, Result};
 )]
 #[derive(Debug, Builder, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option))]
+#[builder(
+    setter(into, strip_option),
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct IsFollowingCustomList {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -69,30 +72,25 @@ pub struct IsFollowingCustomList {
 impl IsFollowingCustomList {
     pub async fn send(&mut self) -> Result<IsFollowingResponse> {
         #[cfg(not(feature = "multi-thread"))]
-        let res = self
-            .http_client
-            .try_borrow()?
-            .send_request_without_deserializing(self)
-            .await?;
+        let request = self.http_client.try_borrow()?.prepare_request(self)?;
         #[cfg(feature = "multi-thread")]
-        let res = self
-            .http_client
-            .lock()
-            .await
-            .send_request_without_deserializing(self)
-            .await?;
+        let request = self.http_client.lock().await.prepare_request(self)?;
+        let res = request.send().await?;
 
         match res.status() {
             reqwest::StatusCode::OK => Ok(IsFollowingResponse { is_following: true }),
             reqwest::StatusCode::NOT_FOUND => {
-                let result = res
-                    .json::<<Result<NoData> as FromResponse>::Response>()
-                    .await?;
+                let result =
+                    HttpClient::parse_response::<<Result<NoData> as FromResponse>::Response>(res)
+                        .await?;
                 match result.into_result() {
                     Ok(_) => Ok(IsFollowingResponse {
                         is_following: false,
                     }),
-                    Err(err) => Err(Error::Api(err)),
+                    Err(mut err) => {
+                        err.status_code = Some(reqwest::StatusCode::NOT_FOUND.as_u16());
+                        Err(Error::Api(err))
+                    }
                 }
             }
             other_status => Err(Error::ServerError(other_status.as_u16(), res.text().await?)),
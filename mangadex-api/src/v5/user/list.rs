@@ -42,7 +42,7 @@ use uuid::Uuid;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::UserListResponse;
-use mangadex_api_types::UserSortOrder;
+use mangadex_api_types::{OrderDirection, UserSortOrder};
 
 #[cfg_attr(
     feature = "deserializable-endpoint",
@@ -50,7 +50,12 @@ use mangadex_api_types::UserSortOrder;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), default, pattern = "owned")]
+#[builder(
+    setter(into, strip_option),
+    default,
+    pattern = "owned",
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 #[non_exhaustive]
 pub struct ListUser {
     #[doc(hidden)]
@@ -72,18 +77,34 @@ pub struct ListUser {
     pub order: Option<UserSortOrder>,
 }
 
+impl ListUserBuilder {
+    /// Order the users by username, from A to Z.
+    pub fn order_by_username_asc(self) -> Self {
+        self.order(UserSortOrder::Username(OrderDirection::Ascending))
+    }
+
+    /// Order the users by username, from Z to A.
+    pub fn order_by_username_desc(self) -> Self {
+        self.order(UserSortOrder::Username(OrderDirection::Descending))
+    }
+}
+
 endpoint! {
     GET "/user",
     #[query auth] ListUser,
     #[flatten_result] UserListResponse
 }
 
+paginated! {
+    ListUser => mangadex_api_schema::v5::UserObject
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
     use url::Url;
     use uuid::Uuid;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::v5::AuthTokens;
@@ -212,4 +233,320 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn list_user_with_offset_returns_a_modified_clone() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 10,
+            "total": 15
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(query_param("limit", "10"))
+            .and(query_param("offset", "10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let first_page = mangadex_client
+            .user()
+            .list()
+            .limit(10u32)
+            .offset(0u32)
+            .build()?;
+
+        let next_page = first_page.clone().with_offset(10);
+
+        assert_eq!(first_page.offset, Some(0));
+        assert_eq!(next_page.offset, Some(10));
+        assert_eq!(next_page.limit, Some(10));
+
+        let res = next_page.send().await?;
+
+        assert_eq!(res.offset, 10);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_user_order_by_username_asc_sends_the_order_query() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(query_param("order[username]", "asc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        mangadex_client
+            .user()
+            .list()
+            .order_by_username_asc()
+            .build()?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_user_collect_all_pages_until_the_total_is_reached() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let user = |username: &str| {
+            json!({
+                "id": Uuid::new_v4(),
+                "type": "user",
+                "attributes": {
+                    "username": username,
+                    "roles": ["ROLE_MEMBER"],
+                    "version": 1
+                },
+                "relationships": []
+            })
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [user("first"), user("second")],
+                "limit": 2,
+                "offset": 0,
+                "total": 3
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(query_param("offset", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [user("third")],
+                "limit": 2,
+                "offset": 2,
+                "total": 3
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let users = mangadex_client
+            .user()
+            .list()
+            .limit(2u32)
+            .offset(0u32)
+            .build()?
+            .collect_all()
+            .await?;
+
+        let usernames: Vec<&str> = users
+            .iter()
+            .map(|user| user.attributes.username.as_str())
+            .collect();
+        assert_eq!(usernames, vec!["first", "second", "third"]);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn list_user_pages_report_the_largest_total_from_the_first_page() -> anyhow::Result<()> {
+        use futures::StreamExt;
+
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let user = |username: &str| {
+            json!({
+                "id": Uuid::new_v4(),
+                "type": "user",
+                "attributes": {
+                    "username": username,
+                    "roles": ["ROLE_MEMBER"],
+                    "version": 1
+                },
+                "relationships": []
+            })
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [user("first"), user("second")],
+                "limit": 2,
+                "offset": 0,
+                "total": 3
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        // A user was removed between the requests, so the total went down.
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(query_param("offset", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": "ok",
+                "response": "collection",
+                "data": [user("third")],
+                "limit": 2,
+                "offset": 2,
+                "total": 2
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let list_user = mangadex_client
+            .user()
+            .list()
+            .limit(2u32)
+            .offset(0u32)
+            .build()?;
+        let pages = list_user.pages();
+        futures::pin_mut!(pages);
+
+        let first_page = pages.next().await.expect("the first page")?;
+        assert_eq!(first_page.total, 3);
+        assert_eq!(first_page.data.len(), 2);
+        assert_eq!(
+            mock_server
+                .received_requests()
+                .await
+                .unwrap_or_default()
+                .len(),
+            1
+        );
+
+        let second_page = pages.next().await.expect("the second page")?;
+        assert_eq!(second_page.total, 3);
+        assert_eq!(second_page.data.len(), 1);
+        assert!(pages.next().await.is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "multi-thread")]
+    #[tokio::test]
+    async fn list_user_can_be_sent_from_a_spawned_task() -> anyhow::Result<()> {
+        let mock_server = MockServer::start().await;
+        let http_client = HttpClient::builder()
+            .base_url(Url::parse(&mock_server.uri())?)
+            .auth_tokens(AuthTokens {
+                session: "sessiontoken".to_string(),
+                refresh: "refreshtoken".to_string(),
+            })
+            .build()?;
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let response_body = json!({
+            "result": "ok",
+            "response": "collection",
+            "data": [],
+            "limit": 10,
+            "offset": 0,
+            "total": 0
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(query_param("username", "myusername"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // The username is owned, so the request doesn't borrow anything from this scope.
+        let username = String::from("myusername");
+        let request = mangadex_client.user().list().username(username).build()?;
+
+        let res = tokio::spawn(async move { request.send().await }).await??;
+
+        assert_eq!(res.total, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_user_encodes_user_ids_as_an_indexed_array() -> anyhow::Result<()> {
+        let http_client = HttpClient::default();
+        let mangadex_client = MangaDexClient::new_with_http_client(http_client);
+
+        let user_ids = [Uuid::new_v4(), Uuid::new_v4()];
+        let list_user = mangadex_client
+            .user()
+            .list()
+            .add_user_id(user_ids[0])
+            .add_user_id(user_ids[1])
+            .limit(10u32)
+            .build()?;
+
+        assert_query_eq!(
+            list_user,
+            [
+                ("limit", "10"),
+                ("ids[0]", user_ids[0].to_string()),
+                ("ids[1]", user_ids[1].to_string()),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -40,6 +40,7 @@ use serde::Serialize;
 
 use crate::HttpClientRef;
 use mangadex_api_schema::v5::CustomListListResponse;
+use mangadex_api_types::ReferenceExpansionResource;
 
 #[cfg_attr(
     feature = "deserializable-endpoint",
@@ -47,7 +48,12 @@ use mangadex_api_schema::v5::CustomListListResponse;
 )]
 #[derive(Debug, Serialize, Clone, Builder, Default)]
 #[serde(rename_all = "camelCase")]
-#[builder(setter(into, strip_option), pattern = "owned", default)]
+#[builder(
+    setter(into, strip_option),
+    pattern = "owned",
+    default,
+    build_fn(error = "mangadex_api_types::error::Error")
+)]
 pub struct MyCustomLists {
     /// This should never be set manually as this is only for internal use.
     #[doc(hidden)]
@@ -60,6 +66,10 @@ pub struct MyCustomLists {
     pub limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
+    /// Expand the relationships of the lists, such as [`ReferenceExpansionResource::User`] for
+    /// their creator.
+    #[builder(setter(each = "include"))]
+    pub includes: Vec<ReferenceExpansionResource>,
 }
 
 endpoint! {
@@ -68,6 +78,10 @@ endpoint! {
     #[flatten_result] CustomListListResponse
 }
 
+paginated! {
+    MyCustomLists => mangadex_api_schema::v5::CustomListObject
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::name::en::Name;
@@ -0,0 +1,25 @@
+//! Make sure the endpoint namespaces can be compiled on their own.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn compiles_with_only_the_manga_feature() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let status = Command::new(env!("CARGO"))
+        .current_dir(manifest_dir)
+        .args([
+            "check",
+            "--lib",
+            "--no-default-features",
+            "--features",
+            "manga",
+        ])
+        .arg("--target-dir")
+        .arg(manifest_dir.join("../target/feature-check"))
+        .status()
+        .expect("failed to run cargo");
+
+    assert!(status.success());
+}
@@ -0,0 +1,73 @@
+//! Stub MangaDex responses for code that uses the client, as a downstream crate would.
+
+use mangadex_api::testing::MockMangaDexClient;
+use mangadex_api::MangaDexClient;
+use mangadex_api_types::error::Error;
+use serde_json::json;
+use uuid::Uuid;
+
+/// The code under test: get the usernames of the users matching a search.
+async fn usernames(client: &MangaDexClient, search: &str) -> Result<Vec<String>, Error> {
+    let users = client
+        .user()
+        .list()
+        .username(search)
+        .build()?
+        .send()
+        .await?;
+
+    Ok(users
+        .data
+        .into_iter()
+        .map(|user| user.attributes.username)
+        .collect())
+}
+
+fn user(username: &str) -> serde_json::Value {
+    json!({
+        "id": Uuid::new_v4(),
+        "type": "user",
+        "attributes": {
+            "username": username,
+            "roles": ["ROLE_MEMBER"],
+            "version": 1
+        },
+        "relationships": []
+    })
+}
+
+#[tokio::test]
+async fn stubbed_user_list_is_returned_to_the_consumer() -> anyhow::Result<()> {
+    let mock = MockMangaDexClient::start().await?;
+    mock.mock_user_list(json!({
+        "result": "ok",
+        "response": "collection",
+        "data": [user("alice"), user("alicia")],
+        "limit": 10,
+        "offset": 0,
+        "total": 2
+    }))
+    .await;
+
+    let names = usernames(mock.client(), "ali").await?;
+
+    assert_eq!(names, vec!["alice".to_string(), "alicia".to_string()]);
+
+    let requests = mock.server().received_requests().await.unwrap_or_default();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url.query(), Some("username=ali"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stubbed_errors_are_returned_to_the_consumer() -> anyhow::Result<()> {
+    let mock = MockMangaDexClient::start().await?;
+    mock.mock_error("GET", "^/user$", 403, "Forbidden").await;
+
+    let res = usernames(mock.client(), "ali").await;
+
+    assert!(matches!(res, Err(Error::Api(_))));
+
+    Ok(())
+}